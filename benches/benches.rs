@@ -209,6 +209,76 @@ fn comparison_benches(c: &mut Criterion) {
     );
 }
 
+fn equality_benches(c: &mut Criterion) {
+    let mut group = c.benchmark_group("PartialEq::eq");
+    group.bench_function(
+        "01: GermanStr, 10 chars, equal",
+        |b| b.iter_batched_ref(
+            || {
+                let s = gen_random_string(10);
+                (GermanStr::new(&s).unwrap(), GermanStr::new(&s).unwrap())
+            },
+            |(a, b)| a == b,
+            criterion::BatchSize::SmallInput,
+        )
+    );
+    group.bench_function(
+        "02: GermanStr, 10 chars, same prefix",
+        |b| b.iter_batched_ref(
+            || (GermanStr::new(gen_empty_string(10) + "a").unwrap(), GermanStr::new(gen_empty_string(10) + "b").unwrap()),
+            |(a, b)| a == b,
+            criterion::BatchSize::SmallInput,
+        )
+    );
+    group.bench_function(
+        "03: GermanStr, random lengths up to 12 chars",
+        |b| b.iter_batched_ref(
+            || {
+                let mut rng = rand::thread_rng();
+                (GermanStr::new(gen_empty_string(rng.gen_range(0..=12))).unwrap(), GermanStr::new(gen_empty_string(rng.gen_range(0..=12))).unwrap())
+            },
+            |(a, b)| a == b,
+            criterion::BatchSize::SmallInput,
+        )
+    );
+    group.bench_function(
+        "04: String, 10 chars, equal",
+        |b| b.iter_batched_ref(
+            || {
+                let s = gen_random_string(10);
+                (s.clone(), s)
+            },
+            |(a, b)| a == b,
+            criterion::BatchSize::SmallInput,
+        )
+    );
+    group.bench_function(
+        "05: SmolStr, 10 chars, equal",
+        |b| b.iter_batched_ref(
+            || {
+                let s = gen_random_string(10);
+                (SmolStr::new(&s), SmolStr::new(&s))
+            },
+            |(a, b)| a == b,
+            criterion::BatchSize::SmallInput,
+        )
+    );
+}
+
+fn char_count_benches(c: &mut Criterion) {
+    let mut group = c.benchmark_group("char_count");
+    let s = "texte accentué, 日本語 ".repeat(100);
+    let german = GermanStr::new(&s).unwrap();
+    group.bench_function(
+        "01: GermanStr::char_count, 2.7KB",
+        |b| b.iter(|| black_box(&german).char_count())
+    );
+    group.bench_function(
+        "02: str::chars().count(), 2.7KB",
+        |b| b.iter(|| black_box(&s).chars().count())
+    );
+}
+
 fn gen_random_string(len: usize) -> String {
     let mut char_gen = rand::thread_rng().sample_iter(Alphanumeric);
     let mut vec = Vec::new();
@@ -225,5 +295,5 @@ fn gen_empty_string(len: usize) -> String {
 }
 
 
-criterion_group!(benches, comparison_benches);
+criterion_group!(benches, comparison_benches, equality_benches, char_count_benches);
 criterion_main!(benches);
@@ -0,0 +1,127 @@
+use core::borrow::Borrow;
+use core::fmt;
+use core::ops::Deref;
+use core::slice;
+
+use crate::{GermanStr, InitError};
+
+/// A `GermanStr` aligned to 16 bytes.
+///
+/// It has the same size as a `GermanStr`, which is only aligned to 8
+/// bytes. In arrays of `AlignedGermanStr`, every string can be loaded with
+/// a single aligned 16-byte SIMD load, and never straddles a cache line,
+/// which helps vectorized kernels scanning columns of strings.
+/// ```
+/// use german_str::{AlignedGermanStr, GermanStr};
+///
+/// let column: Vec<AlignedGermanStr> = ["short", "a string longer than 12 bytes"]
+///     .into_iter()
+///     .map(|s| AlignedGermanStr::new(s).unwrap())
+///     .collect();
+/// assert_eq!(column.as_ptr() as usize % 16, 0);
+/// assert_eq!(AlignedGermanStr::as_german_strs(&column), ["short", "a string longer than 12 bytes"]);
+/// ```
+#[derive(Default, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(C, align(16))]
+pub struct AlignedGermanStr(GermanStr);
+
+impl AlignedGermanStr {
+    #[inline]
+    pub fn new(src: &str) -> Result<Self, InitError> {
+        GermanStr::new(src).map(AlignedGermanStr)
+    }
+
+    #[inline(always)]
+    pub fn into_inner(self) -> GermanStr {
+        self.0
+    }
+
+    #[inline(always)]
+    pub fn as_german_str(&self) -> &GermanStr {
+        &self.0
+    }
+
+    #[inline(always)]
+    /// Views a slice of `AlignedGermanStr` as a slice of `GermanStr`, which
+    /// have the same size and a lower alignment.
+    pub fn as_german_strs(strings: &[AlignedGermanStr]) -> &[GermanStr] {
+        unsafe {
+            // Safety: AlignedGermanStr is repr(C) over a single GermanStr,
+            // and is 16 bytes long like it: the slices have the same layout,
+            // except for the alignment which is stricter.
+            slice::from_raw_parts(strings.as_ptr().cast::<GermanStr>(), strings.len())
+        }
+    }
+}
+
+impl From<GermanStr> for AlignedGermanStr {
+    #[inline(always)]
+    fn from(german: GermanStr) -> Self {
+        AlignedGermanStr(german)
+    }
+}
+
+impl From<AlignedGermanStr> for GermanStr {
+    #[inline(always)]
+    fn from(aligned: AlignedGermanStr) -> Self {
+        aligned.0
+    }
+}
+
+impl Deref for AlignedGermanStr {
+    type Target = GermanStr;
+
+    #[inline(always)]
+    fn deref(&self) -> &GermanStr {
+        &self.0
+    }
+}
+
+impl AsRef<str> for AlignedGermanStr {
+    #[inline(always)]
+    fn as_ref(&self) -> &str {
+        self.0.as_str()
+    }
+}
+
+impl Borrow<str> for AlignedGermanStr {
+    #[inline(always)]
+    fn borrow(&self) -> &str {
+        self.0.as_str()
+    }
+}
+
+impl PartialEq<str> for AlignedGermanStr {
+    #[inline]
+    fn eq(&self, other: &str) -> bool {
+        self.0 == *other
+    }
+}
+
+impl PartialEq<&str> for AlignedGermanStr {
+    #[inline]
+    fn eq(&self, other: &&str) -> bool {
+        self.0 == **other
+    }
+}
+
+impl PartialEq<GermanStr> for AlignedGermanStr {
+    #[inline]
+    fn eq(&self, other: &GermanStr) -> bool {
+        self.0 == *other
+    }
+}
+
+impl fmt::Debug for AlignedGermanStr {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Debug::fmt(&self.0, f)
+    }
+}
+
+impl fmt::Display for AlignedGermanStr {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(&self.0, f)
+    }
+}
@@ -0,0 +1,51 @@
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use arbitrary::{Arbitrary, Unstructured};
+
+use crate::{fill_to_len, GermanStr, MAX_INLINE_BYTES, MAX_LEN};
+
+/// The buffer of the strings generated with a shared buffer.
+const SHARED: &str = "a static buffer, shared by the strings slicing it: é, ß, ✓, 中文, 🦀.";
+
+/// Besides any string, this generates strings exercising the edges of
+/// the representation, so that fuzzers reach them quickly:
+/// * strings whose length is 0, 4, or around `MAX_INLINE_BYTES`.
+/// * strings with a multi-byte char that starts in the 4 bytes prefix
+///   and ends after it.
+/// * strings referencing a shared buffer, which is static so that they
+///   don't need to be freed.
+impl<'a> Arbitrary<'a> for GermanStr {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self, arbitrary::Error> {
+        let german = match u.int_in_range(0..=3)? {
+            0 => GermanStr::new(<&str>::arbitrary(u)?),
+            1 => {
+                let len = *u.choose(&[0, 4, MAX_INLINE_BYTES - 1, MAX_INLINE_BYTES, MAX_INLINE_BYTES + 1])?;
+                let chars = u.arbitrary_iter::<char>()?.take(len).collect::<Result<Vec<char>, _>>()?;
+                GermanStr::new(fill_to_len(&chars, len))
+            }
+            2 => {
+                // ASCII chars are turned into 2 bytes chars.
+                let c = char::arbitrary(u)?;
+                let c = if c.is_ascii() { char::from_u32(0x80 + c as u32).unwrap() } else { c };
+                let ascii = u.int_in_range(5 - c.len_utf8()..=3)?;
+                let mut s = String::from("aaa");
+                s.truncate(ascii);
+                s.push(c);
+                s.push_str(<&str>::arbitrary(u)?);
+                GermanStr::new(s)
+            }
+            _ => {
+                let floor = |i: usize| (0..=i).rev().find(|&i| SHARED.is_char_boundary(i)).unwrap();
+                let start = floor(u.int_in_range(0..=SHARED.len())?);
+                let end = floor(u.int_in_range(start..=SHARED.len())?);
+                Ok(GermanStr::from_static(&SHARED[start..end]))
+            }
+        };
+        Ok(german.expect("BUG in arbitrary implementation of GermanStr. Please report it at github.com/ostnam/german-str/issues"))
+    }
+
+    fn size_hint(_: usize) -> (usize, Option<usize>) {
+        (0, Some(MAX_LEN))
+    }
+}
@@ -0,0 +1,35 @@
+use arcstr::ArcStr;
+
+use crate::{GermanStr, InitError};
+
+impl TryFrom<ArcStr> for GermanStr {
+    type Error = InitError;
+
+    #[inline]
+    fn try_from(s: ArcStr) -> Result<GermanStr, InitError> {
+        GermanStr::new(s.as_str())
+    }
+}
+
+impl TryFrom<&ArcStr> for GermanStr {
+    type Error = InitError;
+
+    #[inline]
+    fn try_from(s: &ArcStr) -> Result<GermanStr, InitError> {
+        GermanStr::new(s.as_str())
+    }
+}
+
+impl From<GermanStr> for ArcStr {
+    #[inline]
+    fn from(s: GermanStr) -> ArcStr {
+        ArcStr::from(s.as_str())
+    }
+}
+
+impl From<&GermanStr> for ArcStr {
+    #[inline]
+    fn from(s: &GermanStr) -> ArcStr {
+        ArcStr::from(s.as_str())
+    }
+}
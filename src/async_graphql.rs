@@ -0,0 +1,31 @@
+use async_graphql::{InputValueError, InputValueResult, Scalar, ScalarType, Value};
+
+use crate::GermanStr;
+
+// `GermanStr`s are registered as the built-in `String` scalar, so they
+// can be used in place of `String` in resolvers and input objects without
+// changing the schema. The doc comment is the description of the scalar,
+// which must match the one of `String`.
+
+/// The `String` scalar type represents textual data, represented as UTF-8
+/// character sequences. The String type is most often used by GraphQL to
+/// represent free-form human-readable text.
+#[Scalar(name = "String")]
+impl ScalarType for GermanStr {
+    fn parse(value: Value) -> InputValueResult<GermanStr> {
+        match value {
+            Value::String(s) => GermanStr::try_from(s).map_err(InputValueError::custom),
+            _ => Err(InputValueError::expected_type(value)),
+        }
+    }
+
+    #[inline]
+    fn is_valid(value: &Value) -> bool {
+        matches!(value, Value::String(_))
+    }
+
+    #[inline]
+    fn to_value(&self) -> Value {
+        Value::String(self.as_str().into())
+    }
+}
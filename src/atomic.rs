@@ -0,0 +1,208 @@
+use alloc::boxed::Box;
+use core::fmt;
+use core::mem;
+use core::ptr;
+use core::sync::atomic::{AtomicPtr, Ordering};
+
+use portable_atomic::AtomicU128;
+
+use crate::{GermanStr, SharedGermanStr};
+
+/// A `GermanStr` which can be replaced atomically, without locking.
+///
+/// The 16 bytes of the string are swapped with 128-bit compare-and-swap
+/// instructions (`cmpxchg16b` on x86_64, LSE on aarch64) when they are
+/// available, or with a global lock otherwise (see `portable_atomic`).
+///
+/// Values returned by `load` borrow the cell, and can be read while the
+/// string is replaced by another thread. For this to be safe, the cell owns
+/// the heap buffers of every string stored in it: the buffers of replaced
+/// strings are only freed when the cell is dropped, or by `reclaim`.
+/// ```
+/// use german_str::{AtomicGermanStr, GermanStr};
+///
+/// let label = AtomicGermanStr::new(GermanStr::new("initial label, not inlined").unwrap());
+/// std::thread::scope(|s| {
+///     s.spawn(|| label.store(GermanStr::new("updated label, not inlined").unwrap()));
+///     let current = label.load();
+///     assert!(current == "initial label, not inlined" || current == "updated label, not inlined");
+/// });
+/// assert_eq!(label.load(), "updated label, not inlined");
+/// ```
+pub struct AtomicGermanStr {
+    /// Bits of a `GermanStr` which never owns its heap buffer: it is either
+    /// inlined, or a shared view of a buffer owned by `owners`.
+    repr: AtomicU128,
+
+    /// Lock-free stack of the strings owning the buffers which were stored
+    /// in `repr`. Only pushed to, except through `&mut self`.
+    owners: AtomicPtr<Owner>,
+}
+
+struct Owner {
+    german: GermanStr,
+    next: *mut Owner,
+}
+
+impl AtomicGermanStr {
+    #[inline]
+    pub fn new(german: GermanStr) -> Self {
+        let cell = AtomicGermanStr {
+            repr: AtomicU128::new(to_bits(GermanStr::new_inline(""))),
+            owners: AtomicPtr::new(ptr::null_mut()),
+        };
+        cell.store(german);
+        cell
+    }
+
+    #[inline]
+    /// Returns the current string, which shares its heap buffer with the
+    /// cell.
+    pub fn load(&self) -> SharedGermanStr<'_> {
+        SharedGermanStr::new(unsafe {
+            // Safety: repr always contains the bits of a valid GermanStr
+            // which doesn't own its buffer, so forging copies of it is fine.
+            // The buffer is owned by self.owners, which outlives the result.
+            from_bits(self.repr.load(Ordering::Acquire))
+        })
+    }
+
+    #[inline]
+    /// Replaces the current string by `german`.
+    pub fn store(&self, german: GermanStr) {
+        let view = self.adopt(german);
+        self.repr.store(to_bits(view), Ordering::Release);
+    }
+
+    /// Replaces the current string by `new` if it is bit-identical to
+    /// `current` (see `GermanStr::ptr_eq`), which should have been returned
+    /// by `load`. Otherwise, `new` is returned back.
+    pub fn compare_exchange(&self, current: &SharedGermanStr<'_>, new: GermanStr) -> Result<(), GermanStr> {
+        let view = unsafe {
+            // Safety: new is moved to self.owners before being dropped.
+            new.shared_view()
+        };
+        let current = to_bits(unsafe {
+            // Safety: the copy isn't dropped, see to_bits.
+            current.shared_view()
+        });
+        match self.repr.compare_exchange(current, to_bits(view), Ordering::AcqRel, Ordering::Acquire) {
+            Ok(_) => {
+                self.adopt(new);
+                Ok(())
+            }
+            Err(_) => Err(new),
+        }
+    }
+
+    /// Frees the heap buffers of the strings which were replaced.
+    ///
+    /// This requires a mutable reference, which ensures that no value
+    /// returned by `load` is alive.
+    pub fn reclaim(&mut self) {
+        let current = self.load().heap_ptr();
+        let mut kept = ptr::null_mut();
+        let mut node = mem::replace(self.owners.get_mut(), ptr::null_mut());
+        while !node.is_null() {
+            let mut owner = unsafe {
+                // Safety: every node was allocated by `adopt`, and is only
+                // freed here or on drop, through a mutable reference.
+                Box::from_raw(node)
+            };
+            node = owner.next;
+            if owner.german.heap_ptr() == current {
+                owner.next = kept;
+                kept = Box::into_raw(owner);
+            }
+        }
+        *self.owners.get_mut() = kept;
+    }
+
+    /// Moves the owned buffer of `german` to `self.owners`, and returns a
+    /// view of it.
+    fn adopt(&self, german: GermanStr) -> GermanStr {
+        let view = unsafe {
+            // Safety: german is moved to self.owners, which outlives the
+            // views stored in self.repr.
+            german.shared_view()
+        };
+        if german.is_inlined() || german.has_shared_buffer() {
+            return view;
+        }
+        let node = Box::into_raw(Box::new(Owner {
+            german,
+            next: self.owners.load(Ordering::Relaxed),
+        }));
+        loop {
+            let next = unsafe {
+                // Safety: node isn't published yet.
+                (*node).next
+            };
+            match self.owners.compare_exchange_weak(next, node, Ordering::Release, Ordering::Relaxed) {
+                Ok(_) => return view,
+                Err(head) => unsafe {
+                    // Safety: same as above.
+                    (*node).next = head;
+                },
+            }
+        }
+    }
+}
+
+impl Default for AtomicGermanStr {
+    #[inline]
+    fn default() -> Self {
+        AtomicGermanStr::new(GermanStr::default())
+    }
+}
+
+impl Drop for AtomicGermanStr {
+    fn drop(&mut self) {
+        let mut node = *self.owners.get_mut();
+        while !node.is_null() {
+            let owner = unsafe {
+                // Safety: see `reclaim`.
+                Box::from_raw(node)
+            };
+            node = owner.next;
+        }
+    }
+}
+
+impl fmt::Debug for AtomicGermanStr {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Debug::fmt(&*self.load(), f)
+    }
+}
+
+#[inline(always)]
+/// Converts a `GermanStr` which doesn't own its buffer to its bits.
+///
+/// The provenance of the heap pointer is exposed, since it can't be stored
+/// in an integer: this is the only place where the crate doesn't follow
+/// strict provenance.
+fn to_bits(german: GermanStr) -> u128 {
+    let german = mem::ManuallyDrop::new(german);
+    if let Some(ptr) = german.heap_tagged() {
+        ptr.expose_provenance();
+    }
+    unsafe {
+        // Safety: GermanStr is 16 bytes long, without padding.
+        mem::transmute_copy(&*german)
+    }
+}
+
+#[inline(always)]
+/// # Safety
+/// `bits` must have been returned by `to_bits`.
+unsafe fn from_bits(bits: u128) -> GermanStr {
+    let mut german: GermanStr = unsafe { mem::transmute(bits) };
+    if let Some(ptr) = german.heap_tagged() {
+        german.last8.ptr = unsafe {
+            // Safety: the provenance was exposed by to_bits.
+            ptr.with_exposed_provenance()
+        };
+    }
+    german
+}
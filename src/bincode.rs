@@ -0,0 +1,51 @@
+use alloc::vec;
+
+use bincode::de::read::Reader;
+use bincode::de::Decoder;
+use bincode::enc::Encoder;
+use bincode::error::{DecodeError, EncodeError};
+use bincode::{Decode, Encode};
+
+use crate::{GermanStr, InitError, MAX_INLINE_BYTES, MAX_LEN};
+
+// `GermanStr`s are encoded like `String`s: their length as a u64,
+// followed by their bytes.
+
+impl Encode for GermanStr {
+    #[inline]
+    fn encode<E: Encoder>(&self, encoder: &mut E) -> Result<(), EncodeError> {
+        self.as_bytes().encode(encoder)
+    }
+}
+
+impl<Context> Decode<Context> for GermanStr {
+    /// Strings of at most `MAX_INLINE_BYTES` are decoded without any
+    /// allocation, longer ones are read into their heap buffer.
+    fn decode<D: Decoder<Context = Context>>(decoder: &mut D) -> Result<GermanStr, DecodeError> {
+        let len = u64::decode(decoder)?;
+        let len = usize::try_from(len).map_err(|_| DecodeError::OutsideUsizeRange(len))?;
+        if len > MAX_LEN {
+            return Err(decode_error(InitError::TooLong));
+        }
+        decoder.claim_bytes_read(len)?;
+        if len <= MAX_INLINE_BYTES {
+            let mut bytes = [0; MAX_INLINE_BYTES];
+            decoder.reader().read(&mut bytes[..len])?;
+            let src = crate::str_from_utf8(&bytes[..len]).map_err(decode_error)?;
+            return Ok(GermanStr::new_inline(src));
+        }
+        let mut bytes = vec![0; len];
+        decoder.reader().read(&mut bytes)?;
+        GermanStr::from_utf8(bytes).map_err(|e| decode_error(e.init_error()))
+    }
+}
+
+bincode::impl_borrow_decode!(GermanStr);
+
+#[cold]
+fn decode_error(error: InitError) -> DecodeError {
+    match error {
+        InitError::InvalidUtf8(inner) => DecodeError::Utf8 { inner },
+        _ => DecodeError::Other("string longer than MAX_LEN bytes"),
+    }
+}
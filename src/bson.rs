@@ -0,0 +1,41 @@
+use alloc::string::String;
+
+use bson::{Bson, RawBsonRef};
+
+use crate::GermanStr;
+
+impl From<GermanStr> for Bson {
+    #[inline]
+    fn from(s: GermanStr) -> Bson {
+        Bson::String(String::from(s))
+    }
+}
+
+/// Gives the value back if it isn't a string, or if it is longer than
+/// `MAX_LEN`.
+impl TryFrom<Bson> for GermanStr {
+    type Error = Bson;
+
+    #[inline]
+    fn try_from(value: Bson) -> Result<GermanStr, Bson> {
+        match &value {
+            Bson::String(s) => GermanStr::new(s).map_err(|_| value),
+            _ => Err(value),
+        }
+    }
+}
+
+/// Reads strings straight from raw BSON documents, without the
+/// intermediate `String` of `Bson`. Gives the value back if it isn't a
+/// string, or if it is longer than `MAX_LEN`.
+impl<'a> TryFrom<RawBsonRef<'a>> for GermanStr {
+    type Error = RawBsonRef<'a>;
+
+    #[inline]
+    fn try_from(value: RawBsonRef<'a>) -> Result<GermanStr, RawBsonRef<'a>> {
+        match value {
+            RawBsonRef::String(s) => GermanStr::new(s).map_err(|_| value),
+            _ => Err(value),
+        }
+    }
+}
@@ -0,0 +1,64 @@
+use alloc::boxed::Box;
+use alloc::vec::Vec;
+use core::slice;
+
+use bytes::Bytes;
+
+use crate::{GermanStr, InitError, MAX_LEN};
+
+/// Validates the UTF-8 of `bytes`, and reuses its buffer for long strings.
+/// If `bytes` is the only reference to its buffer, the buffer is moved
+/// into the `GermanStr`. Otherwise, the `GermanStr` references it, and
+/// keeps `bytes` alive until it is dropped: its buffer can't be mutated
+/// through `GermanStr::with_mut`.
+impl TryFrom<Bytes> for GermanStr {
+    type Error = InitError;
+
+    fn try_from(bytes: Bytes) -> Result<GermanStr, InitError> {
+        let s = crate::str_from_utf8(&bytes)?;
+        if s.len() <= crate::MAX_INLINE_BYTES {
+            return Ok(GermanStr::new_inline(s));
+        }
+        if s.len() > MAX_LEN {
+            return Err(InitError::TooLong);
+        }
+        match bytes.try_into_mut() {
+            Ok(unique) => {
+                let buf = Vec::from(unique).into_boxed_slice();
+                Ok(unsafe {
+                    // Safety: buf was checked to be valid UTF-8 and its len is in bounds.
+                    GermanStr::from_boxed_bytes_unchecked(buf)
+                })
+            }
+            Err(shared) => Ok(unsafe {
+                // Safety: the buffer of a Bytes is immutable, and is valid
+                // until every Bytes referencing it is dropped. Its UTF-8 and
+                // its len were checked above.
+                let s = core::str::from_utf8_unchecked(slice::from_raw_parts(shared.as_ptr(), shared.len()));
+                GermanStr::with_buffer_owner(s, Box::new(shared))
+            }),
+        }
+    }
+}
+
+/// Owned heap buffers are moved into the `Bytes` without copying,
+/// inlined, shared, over-aligned and foreign `GermanStr`s are copied.
+impl From<GermanStr> for Bytes {
+    fn from(s: GermanStr) -> Bytes {
+        // Over-aligned buffers must be freed with their alignment, and
+        // foreign buffers with their destructor.
+        let movable = !s.has_shared_buffer() && !s.has_foreign_buffer() && s.heap_align() == Some(1);
+        let Some(ptr) = s.heap_ptr().filter(|_| movable) else {
+            return Bytes::copy_from_slice(s.as_bytes());
+        };
+        let len = s.len();
+        core::mem::forget(s);
+        let buf = unsafe {
+            // Safety: owned buffers are allocated with
+            // Layout::array::<u8>(len), like a Box<[u8]> of len bytes,
+            // and s was forgotten so the buffer won't be freed twice.
+            Box::from_raw(core::ptr::slice_from_raw_parts_mut(ptr.as_ptr(), len))
+        };
+        Bytes::from(buf)
+    }
+}
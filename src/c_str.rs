@@ -0,0 +1,202 @@
+use alloc::ffi::CString;
+use alloc::vec::Vec;
+use core::borrow::Borrow;
+use core::ffi::{c_char, CStr};
+use core::fmt;
+use core::ops::Deref;
+
+use crate::{GermanStr, InitError, MAX_INLINE_BYTES, MAX_LEN};
+
+/// Same as `GermanStr`, but contains a nul-terminated C string.
+///
+/// The nul terminator is stored with the string, so `as_ptr()` can be
+/// passed to C functions without copying the string into a `CString`.
+/// Strings of up to 11 bytes (12 with the terminator) are inlined.
+/// ```
+/// use german_str::GermanCStr;
+///
+/// let name = GermanCStr::new("ls").unwrap();
+/// assert!(name.is_inlined());
+/// assert_eq!(name.to_bytes_with_nul(), b"ls\0");
+/// let ptr = name.as_ptr(); // can be passed to C
+/// ```
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct GermanCStr {
+    /// Contains the bytes of the string, followed by a nul byte. It can't
+    /// contain other nul bytes.
+    /// Since the terminator is the smallest byte, and is stored at the end
+    /// of both strings, comparing it byte-wise is consistent with `CStr`.
+    inner: GermanStr,
+}
+
+impl GermanCStr {
+    /// Creates a `GermanCStr` containing `src`, followed by a nul byte.
+    ///
+    /// Fails with `InitError::InteriorNul` if `src` contains a nul byte.
+    pub fn new(src: impl AsRef<[u8]>) -> Result<Self, InitError> {
+        let src = src.as_ref();
+        if let Some(position) = src.iter().position(|&b| b == 0) {
+            return Err(InitError::InteriorNul(position));
+        }
+        let len = src.len() + 1;
+        if len > MAX_LEN {
+            return Err(InitError::TooLong);
+        }
+        let inner = if len <= MAX_INLINE_BYTES {
+            let mut buf = [0; MAX_INLINE_BYTES];
+            buf[..src.len()].copy_from_slice(src);
+            GermanStr::from_raw_bytes(&buf[..len])?
+        } else {
+            let mut buf = Vec::with_capacity(len);
+            buf.extend_from_slice(src);
+            buf.push(0);
+            unsafe {
+                // Safety: the len was checked, and the GermanStr is only
+                // accessed as raw bytes.
+                GermanStr::from_boxed_bytes_unchecked(buf.into_boxed_slice())
+            }
+        };
+        Ok(GermanCStr { inner })
+    }
+
+    #[inline]
+    /// Creates a `GermanCStr` containing a copy of `src`.
+    pub fn from_c_str(src: &CStr) -> Result<Self, InitError> {
+        Ok(GermanCStr {
+            inner: GermanStr::from_raw_bytes(src.to_bytes_with_nul())?,
+        })
+    }
+
+    #[inline(always)]
+    pub fn as_c_str(&self) -> &CStr {
+        unsafe {
+            // Safety: inner is nul-terminated, and contains no other nul byte.
+            CStr::from_bytes_with_nul_unchecked(self.inner.raw_bytes())
+        }
+    }
+
+    #[inline(always)]
+    /// Returns a pointer to the nul-terminated string, which is valid as
+    /// long as `self` isn't moved or dropped.
+    ///
+    /// Inlined strings are stored in `self`: moving it invalidates the
+    /// pointer, even if the string isn't modified.
+    pub fn as_ptr(&self) -> *const c_char {
+        self.inner.raw_bytes().as_ptr().cast()
+    }
+
+    #[inline(always)]
+    /// Returns the number of bytes of the string, excluding the terminator.
+    pub fn count_bytes(&self) -> usize {
+        self.inner.len() - 1
+    }
+
+    #[inline(always)]
+    pub fn is_empty(&self) -> bool {
+        self.count_bytes() == 0
+    }
+
+    #[inline(always)]
+    pub fn is_inlined(&self) -> bool {
+        self.inner.is_inlined()
+    }
+
+    #[inline(always)]
+    pub fn is_heap_allocated(&self) -> bool {
+        self.inner.is_heap_allocated()
+    }
+}
+
+impl Default for GermanCStr {
+    #[inline(always)]
+    fn default() -> Self {
+        GermanCStr {
+            inner: GermanStr::new_inline("\0"),
+        }
+    }
+}
+
+impl Deref for GermanCStr {
+    type Target = CStr;
+
+    #[inline(always)]
+    fn deref(&self) -> &CStr {
+        self.as_c_str()
+    }
+}
+
+impl AsRef<CStr> for GermanCStr {
+    #[inline(always)]
+    fn as_ref(&self) -> &CStr {
+        self.as_c_str()
+    }
+}
+
+impl Borrow<CStr> for GermanCStr {
+    #[inline(always)]
+    fn borrow(&self) -> &CStr {
+        self.as_c_str()
+    }
+}
+
+impl core::hash::Hash for GermanCStr {
+    #[inline(always)]
+    fn hash<H: core::hash::Hasher>(&self, hasher: &mut H) {
+        self.as_c_str().hash(hasher);
+    }
+}
+
+impl TryFrom<&CStr> for GermanCStr {
+    type Error = InitError;
+
+    #[inline]
+    fn try_from(s: &CStr) -> Result<Self, Self::Error> {
+        GermanCStr::from_c_str(s)
+    }
+}
+
+impl TryFrom<&str> for GermanCStr {
+    type Error = InitError;
+
+    #[inline]
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        GermanCStr::new(s)
+    }
+}
+
+impl TryFrom<&GermanStr> for GermanCStr {
+    type Error = InitError;
+
+    #[inline]
+    fn try_from(s: &GermanStr) -> Result<Self, Self::Error> {
+        GermanCStr::new(s.as_bytes())
+    }
+}
+
+impl From<GermanCStr> for CString {
+    #[inline]
+    fn from(s: GermanCStr) -> Self {
+        CString::from(s.as_c_str())
+    }
+}
+
+impl PartialEq<CStr> for GermanCStr {
+    #[inline(always)]
+    fn eq(&self, other: &CStr) -> bool {
+        self.as_c_str() == other
+    }
+}
+
+impl PartialEq<&CStr> for GermanCStr {
+    #[inline(always)]
+    fn eq(&self, other: &&CStr) -> bool {
+        self.as_c_str() == *other
+    }
+}
+
+impl fmt::Debug for GermanCStr {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Debug::fmt(self.as_c_str(), f)
+    }
+}
@@ -0,0 +1,76 @@
+use alloc::sync::Arc;
+use core::fmt;
+use std::sync::{PoisonError, RwLock};
+
+use crate::GermanStr;
+
+/// A shared slot containing a `GermanStr`, which can be replaced while
+/// other threads read it, like `arc_swap::ArcSwap`.
+///
+/// `load` returns a reference-counted snapshot, which stays valid after the
+/// string is replaced. The lock is only held to copy or replace the `Arc`,
+/// never while the string is read: unlike `AtomicGermanStr`, this doesn't
+/// require 128-bit atomics, and replaced strings are freed as soon as their
+/// last snapshot is dropped.
+/// ```
+/// use german_str::{GermanStr, GermanStrCell};
+///
+/// let flag = GermanStrCell::new(GermanStr::new("disabled").unwrap());
+/// let snapshot = flag.load();
+/// flag.store(GermanStr::new("enabled").unwrap());
+/// assert_eq!(*snapshot, "disabled");
+/// assert_eq!(*flag.load(), "enabled");
+/// ```
+#[derive(Default)]
+pub struct GermanStrCell {
+    current: RwLock<Arc<GermanStr>>,
+}
+
+impl GermanStrCell {
+    #[inline]
+    pub fn new(german: GermanStr) -> Self {
+        GermanStrCell {
+            current: RwLock::new(Arc::new(german)),
+        }
+    }
+
+    #[inline]
+    /// Returns a snapshot of the current string.
+    pub fn load(&self) -> Arc<GermanStr> {
+        // The lock can't be poisoned: nothing panics while it is held.
+        Arc::clone(&self.current.read().unwrap_or_else(PoisonError::into_inner))
+    }
+
+    #[inline]
+    /// Replaces the current string by `german`.
+    pub fn store(&self, german: GermanStr) {
+        drop(self.swap(german));
+    }
+
+    #[inline]
+    /// Replaces the current string by `german`, and returns the previous one.
+    pub fn swap(&self, german: GermanStr) -> Arc<GermanStr> {
+        let new = Arc::new(german);
+        let mut current = self.current.write().unwrap_or_else(PoisonError::into_inner);
+        core::mem::replace(&mut *current, new)
+    }
+
+    #[inline]
+    pub fn into_inner(self) -> Arc<GermanStr> {
+        self.current.into_inner().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+impl From<GermanStr> for GermanStrCell {
+    #[inline]
+    fn from(german: GermanStr) -> Self {
+        GermanStrCell::new(german)
+    }
+}
+
+impl fmt::Debug for GermanStrCell {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Debug::fmt(&*self.load(), f)
+    }
+}
@@ -0,0 +1,181 @@
+use alloc::boxed::Box;
+use alloc::vec::Vec;
+use core::fmt;
+use core::ops::Deref;
+use core::ptr::NonNull;
+
+use crate::{GermanStr, InitError, MAX_INLINE_BYTES, MAX_LEN};
+
+/// Copies the owned heap buffers of `strings` into a single allocation, and
+/// turns the strings into shared views of their copy: their old buffers are
+/// freed.
+///
+/// After heavy churn, this replaces many small allocations scattered in the
+/// heap by one contiguous buffer. Inlined strings, strings whose buffer is
+/// already shared, and buffers allocated by `GermanStr::new_aligned` are
+/// left as is.
+///
+/// The strings are owned by the returned `CompactedBuffer`, which derefs to
+/// them, and frees the buffer when it is dropped.
+/// ```
+/// use german_str::{compact, GermanStr};
+///
+/// let column: Vec<GermanStr> = ["a string longer than 12 bytes", "short", "another long string"]
+///     .into_iter()
+///     .map(|s| GermanStr::new(s).unwrap())
+///     .collect();
+/// let column = compact(column);
+/// assert_eq!(column.buffer_len(), 29 + 19);
+/// assert!(column[0].has_shared_buffer());
+/// assert_eq!(*column, ["a string longer than 12 bytes", "short", "another long string"]);
+/// ```
+pub fn compact(mut strings: Vec<GermanStr>) -> CompactedBuffer {
+    let compacted = |s: &GermanStr| s.is_heap_allocated() && !s.has_shared_buffer() && s.heap_align() == Some(1);
+    let data = concat(strings.iter().filter(|s| compacted(s)).map(GermanStr::as_str));
+    let mut views = Views {
+        data: data.cast(),
+        start: 0,
+    };
+    for s in strings.iter_mut().filter(|s| compacted(s)) {
+        *s = unsafe {
+            // Safety: the views are owned by the returned buffer, which
+            // drops them before freeing the data.
+            views.next(s.len())
+        };
+    }
+    CompactedBuffer { strings, data }
+}
+
+impl GermanStr {
+    /// Creates a `GermanStr` for each string of `srcs`, copying the bytes of
+    /// all of those that can't be inlined into a single allocation, which
+    /// they share.
+    ///
+    /// This replaces one allocation per string by a single one, for example
+    /// when ingesting a batch of parsed CSV or JSON values.
+    ///
+    /// The strings are owned by the returned `BufferOwner`, which derefs to
+    /// them, and frees the allocation when it is dropped.
+    /// ```
+    /// use german_str::GermanStr;
+    ///
+    /// let row = GermanStr::new_bulk(&["id", "a string longer than 12 bytes", "another long string"]).unwrap();
+    /// assert_eq!(*row, ["id", "a string longer than 12 bytes", "another long string"]);
+    /// assert_eq!(row.buffer_len(), 29 + 19);
+    /// ```
+    pub fn new_bulk<S: AsRef<str>>(srcs: &[S]) -> Result<BufferOwner, InitError> {
+        if srcs.iter().any(|src| src.as_ref().len() > MAX_LEN) {
+            return Err(InitError::TooLong);
+        }
+        let data = concat(srcs.iter().map(AsRef::as_ref).filter(|src| src.len() > MAX_INLINE_BYTES));
+        let mut views = Views {
+            data: data.cast(),
+            start: 0,
+        };
+        let strings = srcs
+            .iter()
+            .map(|src| {
+                let src = src.as_ref();
+                if src.len() <= MAX_INLINE_BYTES {
+                    return GermanStr::new_inline(src);
+                }
+                unsafe {
+                    // Safety: same as in `compact`.
+                    views.next(src.len())
+                }
+            })
+            .collect();
+        Ok(CompactedBuffer { strings, data })
+    }
+}
+
+/// Strings whose heap buffers were copied into a single allocation, created
+/// by `compact` or `GermanStr::new_bulk`.
+///
+/// It derefs to the strings, which borrow it: cloning one of them copies
+/// its buffer. The allocation is freed when it is dropped.
+pub struct CompactedBuffer {
+    /// Inlined, or shared views of `data`, except for the strings `compact`
+    /// left as is.
+    strings: Vec<GermanStr>,
+    /// Allocated as a `Box<[u8]>`.
+    data: NonNull<[u8]>,
+}
+
+/// The buffer shared by the strings created by `GermanStr::new_bulk`.
+pub type BufferOwner = CompactedBuffer;
+
+// Safety: the buffer is never mutated, and is only freed on drop.
+unsafe impl Send for CompactedBuffer {}
+unsafe impl Sync for CompactedBuffer {}
+
+impl CompactedBuffer {
+    #[inline]
+    /// Returns the number of bytes in the shared allocation.
+    pub fn buffer_len(&self) -> usize {
+        self.data.len()
+    }
+}
+
+impl Deref for CompactedBuffer {
+    type Target = [GermanStr];
+
+    #[inline(always)]
+    fn deref(&self) -> &[GermanStr] {
+        &self.strings
+    }
+}
+
+impl Drop for CompactedBuffer {
+    fn drop(&mut self) {
+        // The views must not outlive the buffer.
+        self.strings.clear();
+        drop(unsafe {
+            // Safety: self.data was leaked from a Box<[u8]> in `concat`,
+            // and isn't referenced anymore.
+            Box::from_raw(self.data.as_ptr())
+        });
+    }
+}
+
+impl fmt::Debug for CompactedBuffer {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_list().entries(self.strings.iter()).finish()
+    }
+}
+
+/// Allocates a buffer containing the concatenation of `strings`.
+fn concat<'a>(strings: impl Iterator<Item = &'a str> + Clone) -> NonNull<[u8]> {
+    let mut data = Vec::with_capacity(strings.clone().map(str::len).sum());
+    for s in strings {
+        data.extend_from_slice(s.as_bytes());
+    }
+    NonNull::from(Box::leak(data.into_boxed_slice()))
+}
+
+/// A cursor creating shared views of the strings concatenated in a buffer,
+/// in order.
+struct Views {
+    data: NonNull<u8>,
+    start: usize,
+}
+
+impl Views {
+    #[inline]
+    /// Returns a shared view of the next `len` bytes of the buffer.
+    ///
+    /// # Safety
+    /// * the next `len` bytes must be a string concatenated by `concat`,
+    ///   and valid UTF-8.
+    /// * the view must not be used after the buffer is freed.
+    unsafe fn next(&mut self, len: usize) -> GermanStr {
+        let bytes = NonNull::slice_from_raw_parts(unsafe { self.data.add(self.start) }, len);
+        self.start += len;
+        unsafe {
+            // Safety: the buffer is never mutated, and the caller checks
+            // the rest.
+            GermanStr::new_shared(core::str::from_utf8_unchecked(bytes.as_ref()))
+        }
+    }
+}
@@ -0,0 +1,35 @@
+use compact_str::CompactString;
+
+use crate::{GermanStr, InitError};
+
+impl TryFrom<CompactString> for GermanStr {
+    type Error = InitError;
+
+    #[inline]
+    fn try_from(s: CompactString) -> Result<GermanStr, InitError> {
+        GermanStr::new(s.as_str())
+    }
+}
+
+impl TryFrom<&CompactString> for GermanStr {
+    type Error = InitError;
+
+    #[inline]
+    fn try_from(s: &CompactString) -> Result<GermanStr, InitError> {
+        GermanStr::new(s.as_str())
+    }
+}
+
+impl From<GermanStr> for CompactString {
+    #[inline]
+    fn from(s: GermanStr) -> CompactString {
+        CompactString::new(s.as_str())
+    }
+}
+
+impl From<&GermanStr> for CompactString {
+    #[inline]
+    fn from(s: &GermanStr) -> CompactString {
+        CompactString::new(s.as_str())
+    }
+}
@@ -0,0 +1,188 @@
+use core::borrow::Borrow;
+use core::cmp;
+use core::fmt;
+use core::ops::Deref;
+
+use crate::{GermanStr, InitError};
+
+/// Either a borrowed `&str` or an owned `GermanStr`.
+///
+/// Like `Cow<'a, str>`, this lets an API return borrowed data when it can,
+/// and only create a `GermanStr` when ownership is actually needed.
+#[derive(Clone)]
+pub enum GermanCow<'a> {
+    Borrowed(&'a str),
+    Owned(GermanStr),
+}
+
+impl<'a> GermanCow<'a> {
+    #[inline(always)]
+    pub fn as_str(&self) -> &str {
+        match self {
+            GermanCow::Borrowed(s) => s,
+            GermanCow::Owned(s) => s.as_str(),
+        }
+    }
+
+    #[inline(always)]
+    pub const fn is_borrowed(&self) -> bool {
+        matches!(self, GermanCow::Borrowed(_))
+    }
+
+    #[inline(always)]
+    pub const fn is_owned(&self) -> bool {
+        !self.is_borrowed()
+    }
+
+    #[inline]
+    /// Returns the owned `GermanStr`, creating it if `self` is borrowed.
+    pub fn into_owned(self) -> Result<GermanStr, InitError> {
+        match self {
+            GermanCow::Borrowed(s) => GermanStr::new(s),
+            GermanCow::Owned(s) => Ok(s),
+        }
+    }
+
+    #[inline]
+    /// Makes `self` owned if it isn't already, and returns the `GermanStr`.
+    pub fn to_owned_mut(&mut self) -> Result<&mut GermanStr, InitError> {
+        if let GermanCow::Borrowed(s) = *self {
+            *self = GermanCow::Owned(GermanStr::new(s)?);
+        }
+        match self {
+            GermanCow::Owned(s) => Ok(s),
+            GermanCow::Borrowed(_) => unreachable!(),
+        }
+    }
+}
+
+impl Default for GermanCow<'_> {
+    #[inline(always)]
+    fn default() -> Self {
+        GermanCow::Borrowed("")
+    }
+}
+
+impl Deref for GermanCow<'_> {
+    type Target = str;
+
+    #[inline(always)]
+    fn deref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl AsRef<str> for GermanCow<'_> {
+    #[inline(always)]
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl Borrow<str> for GermanCow<'_> {
+    #[inline(always)]
+    fn borrow(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl<'a> From<&'a str> for GermanCow<'a> {
+    #[inline(always)]
+    fn from(s: &'a str) -> Self {
+        GermanCow::Borrowed(s)
+    }
+}
+
+impl From<GermanStr> for GermanCow<'_> {
+    #[inline(always)]
+    fn from(s: GermanStr) -> Self {
+        GermanCow::Owned(s)
+    }
+}
+
+impl<'a> From<&'a GermanStr> for GermanCow<'a> {
+    #[inline(always)]
+    fn from(s: &'a GermanStr) -> Self {
+        GermanCow::Borrowed(s.as_str())
+    }
+}
+
+impl TryFrom<GermanCow<'_>> for GermanStr {
+    type Error = InitError;
+
+    #[inline]
+    fn try_from(s: GermanCow<'_>) -> Result<Self, Self::Error> {
+        s.into_owned()
+    }
+}
+
+impl PartialEq for GermanCow<'_> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (GermanCow::Owned(lhs), GermanCow::Owned(rhs)) => lhs == rhs,
+            _ => self.as_str() == other.as_str(),
+        }
+    }
+}
+
+impl Eq for GermanCow<'_> {}
+
+impl Ord for GermanCow<'_> {
+    #[inline]
+    fn cmp(&self, other: &Self) -> cmp::Ordering {
+        match (self, other) {
+            (GermanCow::Owned(lhs), GermanCow::Owned(rhs)) => lhs.cmp(rhs),
+            _ => self.as_str().cmp(other.as_str()),
+        }
+    }
+}
+
+impl PartialOrd for GermanCow<'_> {
+    #[inline(always)]
+    fn partial_cmp(&self, other: &Self) -> Option<cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl core::hash::Hash for GermanCow<'_> {
+    #[inline(always)]
+    fn hash<H: core::hash::Hasher>(&self, hasher: &mut H) {
+        self.as_str().hash(hasher);
+    }
+}
+
+impl PartialEq<str> for GermanCow<'_> {
+    #[inline(always)]
+    fn eq(&self, other: &str) -> bool {
+        self.as_str() == other
+    }
+}
+
+impl PartialEq<&str> for GermanCow<'_> {
+    #[inline(always)]
+    fn eq(&self, other: &&str) -> bool {
+        self.as_str() == *other
+    }
+}
+
+impl PartialEq<GermanStr> for GermanCow<'_> {
+    #[inline(always)]
+    fn eq(&self, other: &GermanStr) -> bool {
+        other == self.as_str()
+    }
+}
+
+impl fmt::Debug for GermanCow<'_> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Debug::fmt(self.as_str(), f)
+    }
+}
+
+impl fmt::Display for GermanCow<'_> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(self.as_str(), f)
+    }
+}
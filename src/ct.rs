@@ -0,0 +1,115 @@
+use core::fmt;
+use core::ops::Deref;
+
+use subtle::{Choice, ConstantTimeEq};
+
+use crate::GermanStr;
+
+/// Compares the strings in time depending only on their lengths: unlike
+/// `==`, which stops at the first different byte (or right away if the
+/// lengths or prefixes differ), every byte of the longest string is read.
+/// ```
+/// use german_str::GermanStr;
+/// use subtle::ConstantTimeEq;
+///
+/// let expected = GermanStr::new("sk_live_0123456789abcdef").unwrap();
+/// let provided = GermanStr::new("sk_live_0123456789abcdeF").unwrap();
+/// assert!(!bool::from(expected.ct_eq(&provided)));
+/// ```
+impl ConstantTimeEq for GermanStr {
+    #[inline]
+    fn ct_eq(&self, other: &GermanStr) -> Choice {
+        ct_eq_bytes(self.as_bytes(), other.as_bytes())
+    }
+}
+
+/// A `GermanStr` holding a secret, such as an API key or a token, which is
+/// always compared in constant time (see `ConstantTimeEq for GermanStr`).
+/// ```
+/// use german_str::{CtGermanStr, GermanStr};
+///
+/// let api_key = CtGermanStr::from(GermanStr::new("sk_live_0123456789abcdef").unwrap());
+/// assert!(api_key == CtGermanStr::new("sk_live_0123456789abcdef").unwrap());
+/// assert!(api_key != CtGermanStr::new("sk_live_0").unwrap());
+/// ```
+#[derive(Default, Clone)]
+pub struct CtGermanStr(GermanStr);
+
+impl CtGermanStr {
+    #[inline]
+    pub fn new(src: &str) -> Result<Self, crate::InitError> {
+        GermanStr::new(src).map(CtGermanStr)
+    }
+
+    #[inline]
+    pub fn into_inner(self) -> GermanStr {
+        self.0
+    }
+}
+
+impl From<GermanStr> for CtGermanStr {
+    #[inline]
+    fn from(german: GermanStr) -> Self {
+        CtGermanStr(german)
+    }
+}
+
+impl Deref for CtGermanStr {
+    type Target = GermanStr;
+
+    #[inline(always)]
+    fn deref(&self) -> &GermanStr {
+        &self.0
+    }
+}
+
+impl ConstantTimeEq for CtGermanStr {
+    #[inline]
+    fn ct_eq(&self, other: &CtGermanStr) -> Choice {
+        self.0.ct_eq(&other.0)
+    }
+}
+
+impl PartialEq for CtGermanStr {
+    #[inline]
+    fn eq(&self, other: &CtGermanStr) -> bool {
+        self.ct_eq(other).into()
+    }
+}
+
+impl Eq for CtGermanStr {}
+
+impl PartialEq<str> for CtGermanStr {
+    #[inline]
+    fn eq(&self, other: &str) -> bool {
+        ct_eq_bytes(self.as_bytes(), other.as_bytes()).into()
+    }
+}
+
+impl PartialEq<&str> for CtGermanStr {
+    #[inline]
+    fn eq(&self, other: &&str) -> bool {
+        ct_eq_bytes(self.as_bytes(), other.as_bytes()).into()
+    }
+}
+
+impl fmt::Debug for CtGermanStr {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_tuple("CtGermanStr").field(&self.0).finish()
+    }
+}
+
+#[inline]
+/// Compares every byte of the longest slice, missing bytes of the shortest
+/// one being replaced by 0, instead of returning early when the lengths
+/// differ like `<[u8]>::ct_eq`.
+fn ct_eq_bytes(a: &[u8], b: &[u8]) -> Choice {
+    let mut eq = (a.len() as u64).ct_eq(&(b.len() as u64));
+    for i in 0..a.len().max(b.len()) {
+        let x = a.get(i).copied().unwrap_or(0);
+        let y = b.get(i).copied().unwrap_or(0);
+        eq &= x.ct_eq(&y);
+    }
+    eq
+}
@@ -0,0 +1,30 @@
+use crate::GermanStr;
+
+/// Lets `cxx::bridge`s pass `GermanStr`s by value, as the
+/// `german_str::GermanStr` C++ type, which must be defined as:
+/// ```cpp
+/// #include <cstdint>
+///
+/// namespace german_str {
+/// struct GermanStr final {
+///   // The bitwise NOT of the length of the string.
+///   std::uint32_t len_complement;
+///   // The first 4 bytes of the string, padded with 0s.
+///   std::uint8_t prefix[4];
+///   // If the string is at most 12 bytes long, its bytes after the
+///   // prefix, padded with 0s. Otherwise, the address of the whole
+///   // string, sign-extended from its lower 60 bits, and 4 flags in
+///   // its highest bits.
+///   std::uint8_t last8[8];
+/// };
+/// static_assert(sizeof(GermanStr) == 16, "GermanStr is 16 bytes");
+/// }
+/// ```
+///
+/// The C++ struct is trivially movable and destructible, but the heap
+/// buffers of long strings are owned by Rust: C++ code must hand the
+/// values it receives back to Rust to drop them, or their buffer leaks.
+unsafe impl ::cxx::ExternType for GermanStr {
+    type Id = ::cxx::type_id!("german_str::GermanStr");
+    type Kind = ::cxx::kind::Trivial;
+}
@@ -0,0 +1,24 @@
+use deepsize::{Context, DeepSizeOf};
+
+use crate::{GermanStr, GermanStrVec};
+
+/// Shared buffers aren't counted, to avoid counting them once per
+/// string: they are counted by their owner.
+impl DeepSizeOf for GermanStr {
+    #[inline]
+    fn deep_size_of_children(&self, _: &mut Context) -> usize {
+        if self.has_shared_buffer() {
+            0
+        } else {
+            self.heap_bytes()
+        }
+    }
+}
+
+/// Counts the vector of strings, and the buffer shared by them.
+impl DeepSizeOf for GermanStrVec {
+    #[inline]
+    fn deep_size_of_children(&self, _: &mut Context) -> usize {
+        self.strings.capacity() * core::mem::size_of::<GermanStr>() + self.data.capacity()
+    }
+}
@@ -0,0 +1,12 @@
+use defmt::{Format, Formatter};
+
+use crate::GermanStr;
+
+/// Logs the string like a `str`: with `defmt`, only the bytes of the
+/// string are sent, and it is formatted by the host.
+impl Format for GermanStr {
+    #[inline]
+    fn format(&self, fmt: Formatter) {
+        self.as_str().format(fmt)
+    }
+}
@@ -0,0 +1,187 @@
+use alloc::collections::BTreeMap;
+use alloc::vec::Vec;
+use core::fmt;
+
+use crate::{GermanStr, InitError};
+
+/// A dictionary-encoded column of `GermanStr`s.
+///
+/// Each distinct string is stored once, and rows are stored as `u32` codes
+/// indexing the distinct values, in order of first appearance. This saves
+/// memory for columns with few distinct values, and lets rows be grouped or
+/// compared through their codes.
+#[derive(Default)]
+pub struct GermanStrDict {
+    /// Maps every value to its code. The keys are shared views of `values`,
+    /// which own the buffers: declared first so that it is dropped first.
+    codes_by_value: BTreeMap<GermanStr, u32>,
+
+    /// `values[code]` is the string encoded by `code`.
+    values: Vec<GermanStr>,
+
+    /// One code per row.
+    codes: Vec<u32>,
+}
+
+impl GermanStrDict {
+    #[inline]
+    pub const fn new() -> Self {
+        GermanStrDict {
+            codes_by_value: BTreeMap::new(),
+            values: Vec::new(),
+            codes: Vec::new(),
+        }
+    }
+
+    #[inline]
+    /// Appends a row containing `src`, and returns its code.
+    pub fn push(&mut self, src: &str) -> Result<u32, InitError> {
+        if let Some(code) = self.encode(src) {
+            self.codes.push(code);
+            return Ok(code);
+        }
+        Ok(self.push_new(GermanStr::new(src)?))
+    }
+
+    #[inline]
+    /// Appends a row containing `s`, and returns its code.
+    /// If `s` is already in the dictionary, it is dropped.
+    pub fn push_german_str(&mut self, s: GermanStr) -> u32 {
+        if let Some(code) = self.encode(&s) {
+            self.codes.push(code);
+            return code;
+        }
+        self.push_new(s)
+    }
+
+    fn push_new(&mut self, s: GermanStr) -> u32 {
+        let code = u32::try_from(self.values.len())
+            .expect("tried to insert more than u32::MAX distinct values in a GermanStrDict");
+        let view = unsafe {
+            // Safety: values are never removed, and are dropped after codes_by_value.
+            s.shared_view()
+        };
+        self.values.push(s);
+        self.codes_by_value.insert(view, code);
+        self.codes.push(code);
+        code
+    }
+
+    #[inline]
+    /// Returns the code of `src`, if it is in the dictionary.
+    pub fn encode(&self, src: &str) -> Option<u32> {
+        self.codes_by_value.get(src).copied()
+    }
+
+    #[inline]
+    /// Returns the string encoded by `code`.
+    pub fn decode(&self, code: u32) -> Option<&GermanStr> {
+        self.values.get(code as usize)
+    }
+
+    #[inline]
+    /// Returns the string of the `row`th row.
+    pub fn get(&self, row: usize) -> Option<&GermanStr> {
+        self.codes.get(row).map(|code| &self.values[*code as usize])
+    }
+
+    #[inline(always)]
+    /// Returns the code of every row.
+    pub fn codes(&self) -> &[u32] {
+        &self.codes
+    }
+
+    #[inline(always)]
+    /// Returns the distinct values, indexed by their code.
+    pub fn values(&self) -> &[GermanStr] {
+        &self.values
+    }
+
+    #[inline(always)]
+    /// Returns the number of rows.
+    pub fn len(&self) -> usize {
+        self.codes.len()
+    }
+
+    #[inline(always)]
+    pub fn is_empty(&self) -> bool {
+        self.codes.is_empty()
+    }
+
+    #[inline(always)]
+    /// Returns the number of distinct values.
+    pub fn cardinality(&self) -> usize {
+        self.values.len()
+    }
+
+    #[inline]
+    /// Returns an iterator over the rows.
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item = &GermanStr> + ExactSizeIterator {
+        self.codes.iter().map(|code| &self.values[*code as usize])
+    }
+
+    /// Returns the number of rows of each value, indexed by code.
+    pub fn counts(&self) -> Vec<usize> {
+        let mut counts = alloc::vec![0; self.values.len()];
+        for code in &self.codes {
+            counts[*code as usize] += 1;
+        }
+        counts
+    }
+
+    /// Returns the indices of the rows of each value, indexed by code.
+    pub fn groups(&self) -> Vec<Vec<usize>> {
+        let mut groups = alloc::vec![Vec::new(); self.values.len()];
+        for (row, code) in self.codes.iter().enumerate() {
+            groups[*code as usize].push(row);
+        }
+        groups
+    }
+
+    /// Decodes every row into a `Vec<GermanStr>`.
+    pub fn to_vec(&self) -> Vec<GermanStr> {
+        self.iter().cloned().collect()
+    }
+}
+
+impl Clone for GermanStrDict {
+    fn clone(&self) -> Self {
+        let mut clone = GermanStrDict::new();
+        for value in &self.values {
+            clone.push_new(value.clone());
+        }
+        clone.codes.clone_from(&self.codes);
+        clone
+    }
+}
+
+impl fmt::Debug for GermanStrDict {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl FromIterator<GermanStr> for GermanStrDict {
+    fn from_iter<I: IntoIterator<Item = GermanStr>>(iter: I) -> Self {
+        let mut dict = GermanStrDict::new();
+        for s in iter {
+            dict.push_german_str(s);
+        }
+        dict
+    }
+}
+
+impl From<Vec<GermanStr>> for GermanStrDict {
+    #[inline]
+    fn from(vec: Vec<GermanStr>) -> Self {
+        vec.into_iter().collect()
+    }
+}
+
+impl From<&GermanStrDict> for Vec<GermanStr> {
+    #[inline]
+    fn from(dict: &GermanStrDict) -> Self {
+        dict.to_vec()
+    }
+}
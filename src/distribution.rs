@@ -0,0 +1,63 @@
+use alloc::string::String;
+
+use rand::distributions::{Alphanumeric, DistString, Distribution, Standard};
+use rand::Rng;
+
+use crate::GermanStr;
+
+/// A distribution of random `GermanStr`s, whose number of chars is sampled
+/// from `len`, and whose chars are sampled from `chars`.
+///
+/// `chars` is typically `Alphanumeric`, or `Standard` for arbitrary UTF-8,
+/// and `len` any distribution of lengths, such as a `Uniform` range.
+///
+/// Sampling panics if a string would be longer than `MAX_LEN` bytes.
+/// ```
+/// use german_str::{GermanStr, GermanStrDistribution};
+/// use rand::distributions::{Distribution, Uniform};
+///
+/// let mut rng = rand::thread_rng();
+/// let words = GermanStrDistribution::alphanumeric(Uniform::new_inclusive(1, 20));
+/// let column: Vec<GermanStr> = words.sample_iter(&mut rng).take(100).collect();
+/// assert!(column.iter().all(|s| (1..=20).contains(&s.len())));
+///
+/// let utf8 = GermanStrDistribution::utf8(Uniform::new_inclusive(0, 12));
+/// assert!(utf8.sample(&mut rng).chars().count() <= 12);
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct GermanStrDistribution<C, L> {
+    chars: C,
+    len: L,
+}
+
+impl<C, L> GermanStrDistribution<C, L> {
+    #[inline]
+    pub const fn new(chars: C, len: L) -> Self {
+        GermanStrDistribution { chars, len }
+    }
+}
+
+impl<L> GermanStrDistribution<Alphanumeric, L> {
+    #[inline]
+    /// Returns a distribution of strings made of ASCII letters and digits.
+    pub const fn alphanumeric(len: L) -> Self {
+        GermanStrDistribution::new(Alphanumeric, len)
+    }
+}
+
+impl<L> GermanStrDistribution<Standard, L> {
+    #[inline]
+    /// Returns a distribution of strings made of any chars.
+    pub const fn utf8(len: L) -> Self {
+        GermanStrDistribution::new(Standard, len)
+    }
+}
+
+impl<C: DistString, L: Distribution<usize>> Distribution<GermanStr> for GermanStrDistribution<C, L> {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> GermanStr {
+        let len = self.len.sample(rng);
+        let mut string = String::new();
+        self.chars.append_string(rng, &mut string, len);
+        GermanStr::from_utf8(string.into_bytes()).expect("sampled strings are at most MAX_LEN bytes long")
+    }
+}
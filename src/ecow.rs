@@ -0,0 +1,35 @@
+use ecow::EcoString;
+
+use crate::{GermanStr, InitError};
+
+impl TryFrom<EcoString> for GermanStr {
+    type Error = InitError;
+
+    #[inline]
+    fn try_from(s: EcoString) -> Result<GermanStr, InitError> {
+        GermanStr::new(s.as_str())
+    }
+}
+
+impl TryFrom<&EcoString> for GermanStr {
+    type Error = InitError;
+
+    #[inline]
+    fn try_from(s: &EcoString) -> Result<GermanStr, InitError> {
+        GermanStr::new(s.as_str())
+    }
+}
+
+impl From<GermanStr> for EcoString {
+    #[inline]
+    fn from(s: GermanStr) -> EcoString {
+        EcoString::from(s.as_str())
+    }
+}
+
+impl From<&GermanStr> for EcoString {
+    #[inline]
+    fn from(s: &GermanStr) -> EcoString {
+        EcoString::from(s.as_str())
+    }
+}
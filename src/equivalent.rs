@@ -0,0 +1,57 @@
+//! `str` and `GermanStr` keys are already covered by the blanket impls of
+//! the `equivalent` crate, through `Borrow<str>`. The other string types of
+//! this crate hash and compare like `str`, so they can be used to query maps
+//! keyed by `GermanStr` too.
+//!
+//! `[u8]` can't be supported, since it doesn't hash like `str`.
+
+use core::cmp::Ordering;
+
+use equivalent::{Comparable, Equivalent};
+
+use crate::{GermanCow, GermanStr, GermanString, InlineGermanStr};
+
+impl Equivalent<GermanStr> for InlineGermanStr {
+    #[inline]
+    fn equivalent(&self, key: &GermanStr) -> bool {
+        self == key
+    }
+}
+
+impl Comparable<GermanStr> for InlineGermanStr {
+    #[inline]
+    fn compare(&self, key: &GermanStr) -> Ordering {
+        key.cmp_str(self.as_str()).reverse()
+    }
+}
+
+impl Equivalent<GermanStr> for GermanString {
+    #[inline]
+    fn equivalent(&self, key: &GermanStr) -> bool {
+        self == key
+    }
+}
+
+impl Comparable<GermanStr> for GermanString {
+    #[inline]
+    fn compare(&self, key: &GermanStr) -> Ordering {
+        key.cmp_str(self.as_str()).reverse()
+    }
+}
+
+impl Equivalent<GermanStr> for GermanCow<'_> {
+    #[inline]
+    fn equivalent(&self, key: &GermanStr) -> bool {
+        self == key
+    }
+}
+
+impl Comparable<GermanStr> for GermanCow<'_> {
+    #[inline]
+    fn compare(&self, key: &GermanStr) -> Ordering {
+        match self {
+            GermanCow::Owned(s) => s.cmp(key),
+            GermanCow::Borrowed(s) => key.cmp_str(s).reverse(),
+        }
+    }
+}
@@ -0,0 +1,173 @@
+#[cfg(feature = "bytes")]
+use alloc::boxed::Box;
+use alloc::collections::BTreeMap;
+use alloc::vec::Vec;
+use core::ptr::NonNull;
+use core::slice;
+use std::sync::Mutex;
+
+use crate::tagged::TaggedPtr;
+use crate::{ascii_bit, bytes_prefix, GermanStr, InitError, Last8, Len, FOREIGN_PTR, MAX_INLINE_BYTES, MAX_LEN, OWNED_PTR};
+
+/// Releases a foreign buffer, given its address and its len.
+pub type ForeignDrop = unsafe fn(NonNull<u8>, usize);
+
+/// Releases a foreign buffer.
+enum Destructor {
+    /// Called with the address and the len of the buffer, which is owned
+    /// by a single `GermanStr` and can be written to.
+    Fn(ForeignDrop),
+    /// Keeps the buffer alive until it is dropped. The buffer may also be
+    /// referenced by other owners, so it is read-only.
+    #[cfg(feature = "bytes")]
+    Owner(Box<dyn Send>),
+}
+
+/// The destructors of the live foreign buffers, by address: a `GermanStr`
+/// has no room to store them. A buffer kept alive by owners can be adopted
+/// several times, and has one destructor per adoption.
+static DESTRUCTORS: Mutex<BTreeMap<usize, Vec<Destructor>>> = Mutex::new(BTreeMap::new());
+
+impl GermanStr {
+    /// Creates a `GermanStr` which takes ownership of a buffer allocated by
+    /// foreign code, such as a C library, and releases it by calling
+    /// `drop_fn(ptr, len)` when it is dropped, instead of deallocating it
+    /// with the global allocator.
+    ///
+    /// If the string can be inlined, it is copied and `drop_fn` is called
+    /// right away. Clones allocate their own buffer, while shared clones
+    /// (see `GermanStr::leaky_shared_clone`) reference the foreign buffer,
+    /// and `GermanStr::free` releases it with `drop_fn`.
+    ///
+    /// Returns an error, without calling `drop_fn`, if the buffer isn't
+    /// valid UTF-8 or is longer than `MAX_LEN`: the caller keeps ownership
+    /// of the buffer.
+    ///
+    /// # Safety
+    /// * `ptr` must be valid for reads and writes of `len` bytes, and must
+    ///   not be accessed through other pointers until `drop_fn` is called.
+    /// * `drop_fn(ptr, len)` must be safe to call once, from any thread.
+    /// ```
+    /// use std::ptr::NonNull;
+    /// use german_str::GermanStr;
+    ///
+    /// // Stands for a buffer allocated by a C library, and its `free`.
+    /// let ptr = NonNull::from(Box::leak(Box::<[u8]>::from(*b"a string from a C library"))).cast::<u8>();
+    /// let foreign_free = |ptr: NonNull<u8>, len| unsafe {
+    ///     drop(Box::from_raw(std::ptr::slice_from_raw_parts_mut(ptr.as_ptr(), len)));
+    /// };
+    ///
+    /// let german = unsafe { GermanStr::from_foreign_buffer(ptr, 25, foreign_free) }.unwrap();
+    /// assert_eq!(german, "a string from a C library");
+    /// assert!(german.has_foreign_buffer());
+    /// drop(german); // Calls foreign_free.
+    /// ```
+    pub unsafe fn from_foreign_buffer(ptr: NonNull<u8>, len: usize, drop_fn: ForeignDrop) -> Result<GermanStr, InitError> {
+        if len > MAX_LEN {
+            return Err(InitError::TooLong);
+        }
+        let bytes = unsafe {
+            // Safety: the caller guarantees that ptr is valid for len bytes.
+            slice::from_raw_parts(ptr.as_ptr(), len)
+        };
+        let src = crate::str_from_utf8(bytes)?;
+        if len <= MAX_INLINE_BYTES {
+            let german = GermanStr::new_inline(src);
+            unsafe {
+                // Safety: the caller guarantees that drop_fn can be called,
+                // and src isn't used anymore.
+                drop_fn(ptr, len);
+            }
+            return Ok(german);
+        }
+
+        Ok(unsafe {
+            // Safety: the caller guarantees that the buffer is valid and
+            // owned by the returned string, and drop_fn releases it.
+            adopt(ptr, len, Destructor::Fn(drop_fn))
+        })
+    }
+
+    /// Creates a `GermanStr` referencing the buffer of `s`, which is kept
+    /// alive by `owner` until the string is dropped. The buffer is never
+    /// written to.
+    ///
+    /// # Safety
+    /// The buffer of `s` must stay valid and unmodified until `owner` is
+    /// dropped, and `s` must be longer than `MAX_INLINE_BYTES` and at most
+    /// `MAX_LEN` bytes long.
+    #[cfg(feature = "bytes")]
+    pub(crate) unsafe fn with_buffer_owner(s: &str, owner: Box<dyn Send>) -> GermanStr {
+        unsafe {
+            // Safety: guaranteed by the caller.
+            adopt(NonNull::from(s.as_bytes()).cast(), s.len(), Destructor::Owner(owner))
+        }
+    }
+}
+
+/// Creates a `GermanStr` owning the buffer of `len` bytes at `ptr`, which
+/// is released by `destructor`.
+///
+/// # Safety
+/// The buffer must be valid UTF-8 until `destructor` releases it, and
+/// longer than `MAX_INLINE_BYTES` and at most `MAX_LEN` bytes long.
+unsafe fn adopt(ptr: NonNull<u8>, len: usize, destructor: Destructor) -> GermanStr {
+    let bytes = unsafe {
+        // Safety: the caller guarantees that ptr is valid for len bytes.
+        slice::from_raw_parts(ptr.as_ptr(), len)
+    };
+    let prefix = bytes_prefix(bytes);
+    let ascii = ascii_bit(bytes);
+    DESTRUCTORS
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+        .entry(ptr.addr().get())
+        .or_default()
+        .push(destructor);
+    let tagged = TaggedPtr::new_stealing(ptr, OWNED_PTR | FOREIGN_PTR | ascii);
+    #[cfg(feature = "metrics")]
+    crate::metrics::record_alloc(len);
+    GermanStr {
+        len: Len::new(len),
+        prefix,
+        last8: Last8 { ptr: tagged },
+    }
+}
+
+/// Returns whether the foreign buffer at `ptr` can be written to by the
+/// `GermanStr` owning it, which is the case of the buffers adopted by
+/// `from_foreign_buffer`.
+pub(crate) fn is_writable(ptr: NonNull<u8>) -> bool {
+    DESTRUCTORS
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+        .get(&ptr.addr().get())
+        .is_some_and(|destructors| matches!(destructors.as_slice(), [Destructor::Fn(_)]))
+}
+
+/// Releases a foreign buffer with the destructor it was adopted with.
+///
+/// # Safety
+/// `ptr` must be a buffer of `len` bytes adopted by `from_foreign_buffer`
+/// or `with_buffer_owner`, which isn't used anymore by this adoption.
+pub(crate) unsafe fn release(ptr: NonNull<u8>, len: usize) {
+    let destructor = {
+        let mut destructors = DESTRUCTORS.lock().unwrap_or_else(|e| e.into_inner());
+        let addr = ptr.addr().get();
+        let adoptions = destructors.get_mut(&addr).expect("foreign buffers have a registered destructor");
+        let destructor = adoptions.pop().expect("foreign buffers have a registered destructor");
+        if adoptions.is_empty() {
+            destructors.remove(&addr);
+        }
+        destructor
+    };
+    match destructor {
+        Destructor::Fn(drop_fn) => unsafe {
+            // Safety: the buffer isn't used anymore, and is released once,
+            // since its destructor was removed.
+            drop_fn(ptr, len);
+        },
+        #[cfg(feature = "bytes")]
+        Destructor::Owner(owner) => drop(owner),
+    }
+}
@@ -0,0 +1,2079 @@
+use alloc::borrow::{Cow, ToOwned as _};
+use alloc::boxed::Box;
+use alloc::collections::BTreeSet;
+use alloc::slice;
+use alloc::string::String;
+use alloc::sync::Arc;
+use alloc::vec::Vec;
+use core::{cmp, fmt, mem, ptr};
+use core::alloc::Layout;
+use core::borrow::Borrow;
+use core::num::NonZeroU32;
+use core::ops::{Deref, Index};
+use core::ptr::NonNull;
+use core::slice::SliceIndex;
+use core::str::FromStr;
+
+use crate::tagged::TaggedPtr;
+use crate::{InitError, MAX_BUFFER_ALIGN, MAX_INLINE_BYTES, MAX_LEN};
+
+/// Stored in the highest stolen bit of the heap pointer, to indicate that it
+/// is an owned pointer and its heap allocation should be freed on drop.
+pub(crate) const OWNED_PTR: usize = 1 << (usize::BITS - 1);
+
+/// Stored in the highest stolen bit of the heap pointer, to indicate that it
+/// is a shared buffer and that the user is responsible for freeing it.
+///
+/// This is the value of the stolen bit of an unmodified pointer, which lets
+/// `GermanStr::from_static` build shared pointers in const contexts, where
+/// bits can't be stolen.
+const SHARED_PTR: usize = 0;
+
+/// Set in the second stolen bit of the heap pointer if the string is known
+/// to be ASCII, which is computed when the string is created.
+///
+/// Strings referencing external buffers (see `GermanStr::new_shared`) don't
+/// have it even if they are ASCII, since bits can't be stolen in const
+/// contexts.
+const ASCII_PTR: usize = 1 << (usize::BITS - 2);
+
+/// Set in the third stolen bit of the heap pointer if the buffer was
+/// allocated with an alignment of `MAX_BUFFER_ALIGN` instead of 1, which
+/// is needed to free it.
+const ALIGNED_PTR: usize = 1 << (usize::BITS - 3);
+
+/// Set in the fourth stolen bit of the heap pointer if the buffer was
+/// adopted by `GermanStr::from_foreign_buffer`, and must be released by its
+/// own destructor instead of being deallocated.
+pub(crate) const FOREIGN_PTR: usize = 1 << (usize::BITS - 4);
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[repr(transparent)]
+/// The length of a `GermanStr`, stored as its bitwise complement.
+/// This makes `u32::MAX` an impossible length, which the compiler uses as a
+/// niche: `Option<GermanStr>` is as large as a `GermanStr`.
+pub(crate) struct Len(NonZeroU32);
+
+impl Len {
+    #[inline(always)]
+    /// Panics if `len` > `MAX_LEN`.
+    pub(crate) const fn new(len: usize) -> Len {
+        assert!(len <= MAX_LEN);
+        match NonZeroU32::new(!(len as u32)) {
+            Some(complement) => Len(complement),
+            None => unreachable!(),
+        }
+    }
+
+    #[inline(always)]
+    pub(crate) const fn get(self) -> u32 {
+        !self.0.get()
+    }
+}
+
+/// A string type with the following properties:
+///
+/// * Immutable.
+/// * `size_of::<GermanStr>() == size_of::<Option<GermanStr>>() == 16`
+/// * Strings of 12 or less bytes are entirely located on the stack.
+/// * Fast comparisons.
+#[repr(C)]
+pub struct GermanStr {
+    /// Number of chars of the string.
+    /// Serves as a tag for the variant used by the `last8` field, based on
+    /// whether it is longer than `MAX_INLINE_BYTES` or not.
+    pub(crate) len: Len,
+
+    /// The first 4 bytes of the string. If it is shorter than 4 bytes, extra
+    /// bytes are set to 0.
+    ///
+    /// Since an UTF-8 char can consist of 1-4 bytes, this field can store
+    /// 1-4 chars, and potentially only part of the last char.
+    /// In every case, this array can still be used to speed up comparisons
+    /// because UTF-8 strings are ordered byte-wise.
+    pub(crate) prefix: [u8; 4],
+
+    /// If the string is longer than 12 bytes, is a pointer to
+    /// the chars on the heap.
+    /// By default, this pointer is unique and has ownership of the allocation,
+    /// but the heap buffer can be shared if `leaky_shared_clone` is called,
+    /// in which case you are then responsible for freeing it correctly.
+    /// The prefix is also included in the buffer.
+    ///
+    /// If the string fits in 12 bytes, is an `[u8; 8]`, with extra bytes
+    /// set to 0 (the first 4 bytes being stored in `self.prefix`).
+    pub(crate) last8: Last8,
+}
+
+#[derive(Copy, Clone)]
+/// Holds the last 8 bytes of a `GermanStr`.
+pub(crate) union Last8 {
+    /// Non-null pointer to u8 with 4 bits of virtual address space stolen.
+    pub(crate) ptr: TaggedPtr,
+
+    /// If the string is shorter than 12 bytes, extra bytes are set to 0.
+    pub(crate) buf: [u8; 8],
+}
+
+#[derive(Debug, Clone)]
+/// Error returned by `GermanStr::from_utf8`, which gives back the bytes
+/// that couldn't be converted.
+pub struct FromUtf8Error {
+    bytes: Vec<u8>,
+    error: InitError,
+}
+
+impl FromUtf8Error {
+    #[inline(always)]
+    /// Returns the bytes passed to `GermanStr::from_utf8`.
+    pub fn into_bytes(self) -> Vec<u8> {
+        self.bytes
+    }
+
+    #[inline(always)]
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.bytes
+    }
+
+    #[inline(always)]
+    /// Returns why the conversion failed.
+    pub fn init_error(&self) -> InitError {
+        self.error
+    }
+}
+
+impl GermanStr {
+    #[inline]
+    /// Main function to create a GermanStr.
+    ///
+    /// Aborts if the allocation of a heap buffer fails, see
+    /// `GermanStr::try_new` to handle that case.
+    pub fn new(src: impl AsRef<str>) -> Result<Self, InitError> {
+        let src = src.as_ref();
+        if src.len() > MAX_LEN {
+            return Err(InitError::TooLong);
+        }
+        if src.len() <= MAX_INLINE_BYTES {
+            return Ok(GermanStr::new_inline(src));
+        }
+        GermanStr::new_heap(src.as_bytes())
+            .or_else(|layout| alloc::alloc::handle_alloc_error(layout))
+    }
+
+    #[inline]
+    /// Same as `GermanStr::new`, but returns `InitError::AllocFailed` instead
+    /// of aborting if the allocation of a heap buffer fails.
+    pub fn try_new(src: impl AsRef<str>) -> Result<Self, InitError> {
+        let src = src.as_ref();
+        if src.len() > MAX_LEN {
+            return Err(InitError::TooLong);
+        }
+        if src.len() <= MAX_INLINE_BYTES {
+            return Ok(GermanStr::new_inline(src));
+        }
+        GermanStr::new_heap(src.as_bytes())
+            .map_err(|_| InitError::AllocFailed)
+    }
+
+    #[inline]
+    /// Same as `GermanStr::new`, but the heap buffer is aligned to at least
+    /// `ALIGN` bytes, so that SIMD kernels scanning it can use aligned loads.
+    /// Strings short enough to be inlined are still inlined.
+    ///
+    /// Only one bit of the pointer records that the buffer is over-aligned:
+    /// it is always allocated with an alignment of `MAX_BUFFER_ALIGN`,
+    /// whatever `ALIGN` is. Clones of the string are over-aligned too.
+    ///
+    /// `ALIGN` must be a power of two, at most `MAX_BUFFER_ALIGN`: other
+    /// values fail to compile.
+    /// ```
+    /// use german_str::GermanStr;
+    ///
+    /// let s = GermanStr::new_aligned::<32>("scanned with 32-byte SIMD loads").unwrap();
+    /// assert_eq!(s.heap_ptr().unwrap().as_ptr() as usize % 32, 0);
+    /// assert_eq!(s.clone().heap_ptr().unwrap().as_ptr() as usize % 32, 0);
+    /// ```
+    pub fn new_aligned<const ALIGN: usize>(src: impl AsRef<str>) -> Result<Self, InitError> {
+        const {
+            assert!(ALIGN.is_power_of_two() && ALIGN <= MAX_BUFFER_ALIGN);
+        }
+        let src = src.as_ref();
+        if src.len() > MAX_LEN {
+            return Err(InitError::TooLong);
+        }
+        if src.len() <= MAX_INLINE_BYTES {
+            return Ok(GermanStr::new_inline(src));
+        }
+        GermanStr::new_heap_with(src.as_bytes(), ALIGNED_PTR)
+            .or_else(|layout| alloc::alloc::handle_alloc_error(layout))
+    }
+
+    #[inline]
+    /// Copies `src` to a new owned heap buffer. Returns the layout that
+    /// couldn't be allocated on failure.
+    ///
+    /// `src.len()` must be in `MAX_INLINE_BYTES + 1..=MAX_LEN`.
+    fn new_heap(src: &[u8]) -> Result<Self, Layout> {
+        GermanStr::new_heap_with(src, 0)
+    }
+
+    #[inline]
+    /// Same as `GermanStr::new_heap`, allocating a buffer aligned to
+    /// `MAX_BUFFER_ALIGN` if `aligned` is `ALIGNED_PTR`.
+    fn new_heap_with(src: &[u8], aligned: usize) -> Result<Self, Layout> {
+        let len = Len::new(src.len());
+        let layout = buffer_layout(len.get(), aligned);
+        let ptr = unsafe {
+            // Safety: layout is not zero-sized (src.len() > MAX_INLINE_BYTES).
+            alloc::alloc::alloc(layout)
+        };
+        let Some(ptr) = NonNull::new(ptr) else {
+            return Err(layout);
+        };
+        unsafe {
+            // Safety:
+            //   1. We assume src is a valid object.
+            //   2. ptr is valid: it was checked for null and allocated
+            //      for src.len() bytes.
+            //   3. *_ u8 is always aligned.
+            //   4. The 2 regions can't overlap since they belong to different objects.
+            ptr::copy_nonoverlapping(
+                src.as_ptr(),
+                ptr.as_ptr(),
+                src.len(),
+            );
+        }
+        let tagged = TaggedPtr::new_stealing(ptr, OWNED_PTR | ascii_bit(src) | aligned);
+        #[cfg(feature = "metrics")]
+        crate::metrics::record_alloc(src.len());
+        Ok(GermanStr {
+            len,
+            prefix: bytes_prefix(src),
+            last8: Last8 { ptr: tagged },
+        })
+    }
+
+    #[inline]
+    /// Creates a `GermanStr` from UTF-8 bytes. If `bytes` is too long to be
+    /// inlined, its allocation is reused instead of being copied.
+    ///
+    /// On failure, the bytes are returned in the error.
+    pub fn from_utf8(bytes: Vec<u8>) -> Result<Self, FromUtf8Error> {
+        let error = match str_from_utf8(&bytes) {
+            Ok(src) if src.len() > MAX_LEN => InitError::TooLong,
+            Ok(src) if src.len() <= MAX_INLINE_BYTES => return Ok(GermanStr::new_inline(src)),
+            Ok(_) => return Ok(unsafe {
+                // Safety: bytes was checked to be valid UTF-8 and its len is in bounds.
+                GermanStr::from_boxed_bytes_unchecked(bytes.into_boxed_slice())
+            }),
+            Err(error) => error,
+        };
+        Err(FromUtf8Error { bytes, error })
+    }
+
+    #[inline]
+    /// Creates a `GermanStr` from a formatted number, which is always much
+    /// shorter than `MAX_LEN`.
+    fn from_int_str(src: &str) -> GermanStr {
+        if src.len() <= MAX_INLINE_BYTES {
+            GermanStr::new_inline(src)
+        } else {
+            unsafe {
+                // Safety: src is a valid str, and its len is in bounds.
+                GermanStr::from_boxed_bytes_unchecked(src.as_bytes().into())
+            }
+        }
+    }
+
+    #[inline]
+    /// Attempts to create a GermanStr entirely stored in the struct itself,
+    /// without heap allocations.
+    ///
+    /// Panics if `src.len()` > `MAX_INLINE_BYTES`.
+    pub const fn new_inline(src: &str) -> GermanStr {
+        GermanStr::new_inline_bytes(src.as_bytes())
+    }
+
+    #[inline]
+    /// Same as `GermanStr::new_inline`, without requiring UTF-8. See
+    /// `GermanStr::from_raw_bytes`.
+    const fn new_inline_bytes(src: &[u8]) -> GermanStr {
+        assert!(src.len() <= MAX_INLINE_BYTES);
+
+        let mut prefix = [0; 4];
+        let mut i = 0;
+        while i < src.len() && i < 4 {
+            prefix[i] = src[i];
+            i += 1;
+        }
+
+        let mut buf = [0; 8];
+        let mut i = 4;
+        while i < src.len() && i < MAX_INLINE_BYTES {
+            buf[i - 4] = src[i];
+            i += 1;
+        }
+
+        GermanStr {
+            len: Len::new(src.len()),
+            prefix,
+            last8: Last8 { buf },
+        }
+    }
+
+    #[inline]
+    /// Creates a `GermanStr` containing `src`, which doesn't have to be
+    /// valid UTF-8. It is used to implement the byte string types.
+    ///
+    /// The result must only be accessed through methods that don't assume
+    /// that the string is valid UTF-8: `raw_bytes`, `Clone`, `PartialEq`,
+    /// `Ord` and the prefix/suffix accessors.
+    pub(crate) fn from_raw_bytes(src: &[u8]) -> Result<GermanStr, InitError> {
+        if src.len() > MAX_LEN {
+            return Err(InitError::TooLong);
+        }
+        if src.len() <= MAX_INLINE_BYTES {
+            return Ok(GermanStr::new_inline_bytes(src));
+        }
+        GermanStr::new_heap(src)
+            .or_else(|layout| alloc::alloc::handle_alloc_error(layout))
+    }
+
+    #[inline]
+    /// Creates a `GermanStr` that doesn't allocate, and can be used in const
+    /// contexts.
+    ///
+    /// Strings longer than `MAX_INLINE_BYTES` reference `src` directly, as
+    /// a shared buffer (`GermanStr::has_shared_buffer` returns `true`). Such
+    /// strings must never be `free`d.
+    ///
+    /// Panics if `src.len()` > `MAX_LEN`.
+    pub const fn from_static(src: &'static str) -> GermanStr {
+        unsafe {
+            // Safety: src lives forever and can't be mutated.
+            GermanStr::new_shared(src)
+        }
+    }
+
+    #[inline]
+    /// Creates a `GermanStr` which references `src` as a shared buffer,
+    /// unless it can be inlined.
+    ///
+    /// Panics if `src.len()` > `MAX_LEN`.
+    ///
+    /// # Safety
+    /// The returned `GermanStr` and its shared clones must not be used after
+    /// `src` is freed or mutated, and must never be `free`d.
+    pub(crate) const unsafe fn new_shared(src: &str) -> GermanStr {
+        if src.len() <= MAX_INLINE_BYTES {
+            return GermanStr::new_inline(src);
+        }
+        assert!(src.len() <= MAX_LEN);
+
+        let bytes = src.as_bytes();
+        let ptr = unsafe {
+            // Safety: references are never null.
+            NonNull::new_unchecked(bytes.as_ptr().cast_mut())
+        };
+        // The stolen bit of an unmodified pointer is SHARED_PTR, so the
+        // buffer will never be freed nor mutated.
+        let tagged = TaggedPtr::new(ptr);
+        GermanStr {
+            len: Len::new(src.len()),
+            prefix: [bytes[0], bytes[1], bytes[2], bytes[3]],
+            last8: Last8 { ptr: tagged },
+        }
+    }
+
+    #[inline]
+    /// Creates a `GermanStr` that takes ownership of `buf`.
+    ///
+    /// # Safety
+    /// * `buf` must be valid UTF-8, unless the result is only accessed like
+    ///   the result of `GermanStr::from_raw_bytes`.
+    /// * `MAX_INLINE_BYTES < buf.len() <= MAX_LEN`.
+    pub(crate) unsafe fn from_boxed_bytes_unchecked(buf: Box<[u8]>) -> GermanStr {
+        let len = buf.len();
+        let prefix = bytes_prefix(&buf);
+        let ascii = ascii_bit(&buf);
+        // A Box<[u8]> is allocated with Layout::array::<u8>(len), which is
+        // the layout used to free owned buffers on drop.
+        let ptr = NonNull::from(Box::leak(buf)).cast::<u8>();
+        let tagged = TaggedPtr::new_stealing(ptr, OWNED_PTR | ascii);
+        #[cfg(feature = "metrics")]
+        crate::metrics::record_alloc(len);
+        GermanStr {
+            len: Len::new(len),
+            prefix,
+            last8: Last8 { ptr: tagged },
+        }
+    }
+
+    #[inline]
+    /// Same as `GermanStr::from_static`, but takes a static byte array.
+    ///
+    /// Panics if `bytes` isn't valid UTF-8: when called in a const context,
+    /// this is a compile-time error.
+    /// ```
+    /// use german_str::GermanStr;
+    ///
+    /// const GREETING: GermanStr = GermanStr::from_static_bytes(b"hello, long constant world");
+    /// assert_eq!(GREETING, "hello, long constant world");
+    /// ```
+    pub const fn from_static_bytes<const N: usize>(bytes: &'static [u8; N]) -> GermanStr {
+        match core::str::from_utf8(bytes) {
+            Ok(src) => GermanStr::from_static(src),
+            Err(_) => panic!("GermanStr::from_static_bytes called with invalid UTF-8"),
+        }
+    }
+
+    #[inline]
+    /// Same as `GermanStr::from_static_bytes`, without the UTF-8 check.
+    ///
+    /// # Safety
+    /// `bytes` must be valid UTF-8.
+    pub const unsafe fn from_static_bytes_unchecked<const N: usize>(bytes: &'static [u8; N]) -> GermanStr {
+        GermanStr::from_static(unsafe {
+            // Safety: the caller guarantees that bytes is valid UTF-8.
+            core::str::from_utf8_unchecked(bytes)
+        })
+    }
+
+    #[inline(always)]
+    /// Returns the pointer to the heap-allocated buffer, if the `GermanStr`
+    /// isn't inlined.
+    /// In the actual GermanStr, 4 bits of the pointer are stolen to store
+    /// whether the heap allocation is shared or owned, whether the string
+    /// is ASCII, whether the buffer is over-aligned, and whether it was
+    /// allocated by foreign code. Here, those bits are reset to their
+    /// default value before the pointer is returned.
+    /// `GermanStr::has_shared_buffer` can be used if you want to access the
+    /// first bit's value.
+    pub fn heap_ptr(&self) -> Option<NonNull<u8>> {
+        self.heap_tagged()
+            .map(|tagged| tagged.as_non_null())
+    }
+
+    #[inline(always)]
+    /// Safe accessor for `self.last8.ptr`.
+    pub(crate) fn heap_tagged(&self) -> Option<TaggedPtr> {
+        if self.len.get() as usize > MAX_INLINE_BYTES {
+            Some(unsafe {
+                    // Safety: self.len > MAX_INLINE_BYTES => self isn't inlined.
+                    self.last8.ptr
+            })
+        } else {
+            None
+        }
+    }
+
+
+    #[inline]
+    /// Returns the alignment the heap buffer was allocated with, or `None` if
+    /// the string is inlined: `MAX_BUFFER_ALIGN` for the buffers created by
+    /// `GermanStr::new_aligned` and their clones, 1 otherwise.
+    pub fn heap_align(&self) -> Option<usize> {
+        self.heap_tagged()
+            .map(|ptr| if ptr.stolen() & ALIGNED_PTR != 0 { MAX_BUFFER_ALIGN } else { 1 })
+    }
+
+    #[inline(always)]
+    /// Returns whether `self` is heap-allocated, and the buffer possibly
+    /// shared with other instances, as after calling `leaky_shared_clone`.
+    pub fn has_shared_buffer(&self) -> bool {
+        self.heap_tagged().is_some_and(|ptr| ptr.stolen() & OWNED_PTR == SHARED_PTR)
+    }
+
+    #[inline(always)]
+    /// Returns whether `self` references a buffer adopted by
+    /// `GermanStr::from_foreign_buffer`, which is released by its own
+    /// destructor, or a buffer kept alive by a `Bytes`. This is also the
+    /// case of its shared clones.
+    pub fn has_foreign_buffer(&self) -> bool {
+        self.heap_tagged().is_some_and(|ptr| ptr.stolen() & FOREIGN_PTR != 0)
+    }
+
+    #[inline]
+    /// Returns `true` if `self` and `other` are heap-allocated and use the
+    /// same buffer, or if they are inlined and bit-identical.
+    ///
+    /// This implies that they are equal, and is cheaper to check.
+    /// ```
+    /// use german_str::GermanStr;
+    ///
+    /// let mut long = GermanStr::new("longer than 12 bytes").unwrap();
+    /// let shared = long.leaky_shared_clone();
+    /// assert!(long.ptr_eq(&shared));
+    /// assert!(!long.ptr_eq(&long.clone()));
+    /// # unsafe { shared.free() };
+    /// # std::mem::forget(long);
+    /// ```
+    pub fn ptr_eq(&self, other: &GermanStr) -> bool {
+        match (self.heap_ptr(), other.heap_ptr()) {
+            (Some(lhs), Some(rhs)) => lhs == rhs && self.len == other.len,
+            (None, None) => self.as_words() == other.as_words(),
+            _ => false,
+        }
+    }
+
+    #[inline]
+    /// Returns how the string is stored.
+    /// ```
+    /// use german_str::{GermanStr, Storage};
+    ///
+    /// assert_eq!(GermanStr::new("short").unwrap().storage(), Storage::Inline);
+    /// let long = GermanStr::new("longer than 12 bytes").unwrap();
+    /// assert!(matches!(long.storage(), Storage::Heap { shared: false, len: 20, .. }));
+    /// ```
+    pub fn storage(&self) -> Storage {
+        match self.heap_tagged() {
+            None => Storage::Inline,
+            Some(ptr) => Storage::Heap {
+                shared: ptr.stolen() & OWNED_PTR == SHARED_PTR,
+                ptr: ptr.as_non_null(),
+                len: self.len(),
+            },
+        }
+    }
+
+    #[inline(always)]
+    /// Returns the size of the heap buffer referenced by `self`, whether it
+    /// is owned or shared: 0 if the string is inlined, its len otherwise.
+    pub const fn heap_bytes(&self) -> usize {
+        if self.is_heap_allocated() {
+            self.len()
+        } else {
+            0
+        }
+    }
+
+    #[inline]
+    /// Returns the number of bytes pinned by `self`: the size of the
+    /// `GermanStr` itself, plus its heap buffer. Shared buffers, which
+    /// aren't freed with `self`, are counted according to `shared`.
+    /// ```
+    /// use german_str::{GermanStr, SharedAccounting};
+    ///
+    /// let mut owner = GermanStr::new("longer than 12 bytes").unwrap();
+    /// let shared = owner.leaky_shared_clone();
+    /// assert_eq!(GermanStr::new("short").unwrap().allocated_size(SharedAccounting::Full), 16);
+    /// assert_eq!(shared.allocated_size(SharedAccounting::Full), 16 + 20);
+    /// assert_eq!(shared.allocated_size(SharedAccounting::Split(2)), 16 + 10);
+    /// assert_eq!(shared.allocated_size(SharedAccounting::Ignore), 16);
+    /// # unsafe { shared.free() };
+    /// ```
+    pub fn allocated_size(&self, shared: SharedAccounting) -> usize {
+        let heap_bytes = match shared {
+            _ if !self.has_shared_buffer() => self.heap_bytes(),
+            SharedAccounting::Ignore => 0,
+            SharedAccounting::Full => self.heap_bytes(),
+            SharedAccounting::Split(holders) => self.heap_bytes().div_ceil(holders.max(1)),
+        };
+        mem::size_of::<GermanStr>() + heap_bytes
+    }
+
+    #[inline]
+    /// Clones `self`, reusing the same heap-allocated buffer (unless `self`
+    /// is inlined).
+    ///
+    /// After calling this method, the heap buffer will not be freed when
+    /// `self` is `Drop`ped: you are responsible for manually managing
+    /// memory by calling `GermanStr::free` exactly once per heap buffer.
+    ///
+    /// Even after calling this method once, it should be called instead of
+    /// clone to make new copies that reuse the same buffer: calling `clone()`
+    /// will always create a new copy of the buffer.
+    ///
+    /// This can save memory and increase performance in the case where you
+    /// have many equal `GermanStr` longer than `MAX_INLINE_BYTES`.
+    /// `SharedBufferRegistry` provides the same savings without having to
+    /// free buffers manually.
+    pub fn leaky_shared_clone(&mut self) -> Self {
+        if self.is_heap_allocated() {
+            unsafe {
+                let flags = self.last8.ptr.stolen() & !OWNED_PTR;
+                self.last8.ptr = self.last8.ptr.steal(SHARED_PTR | flags);
+            }
+            #[cfg(feature = "metrics")]
+            crate::metrics::record_shared_reuse();
+        }
+        GermanStr {
+            len: self.len,
+            prefix: self.prefix,
+            last8: self.last8,
+        }
+    }
+
+    #[inline]
+    /// Returns a copy of `self` which shares its heap buffer, while `self`
+    /// keeps ownership of the buffer (if it had it).
+    ///
+    /// # Safety
+    /// The returned `GermanStr` must not be used after `self` is dropped.
+    pub(crate) unsafe fn shared_view(&self) -> Self {
+        let mut view = GermanStr {
+            len: self.len,
+            prefix: self.prefix,
+            last8: self.last8,
+        };
+        if view.is_heap_allocated() {
+            unsafe {
+                // Safety: view is heap-allocated.
+                let flags = view.last8.ptr.stolen() & !OWNED_PTR;
+                view.last8.ptr = view.last8.ptr.steal(SHARED_PTR | flags);
+            }
+            #[cfg(feature = "metrics")]
+            crate::metrics::record_shared_reuse();
+        }
+        view
+    }
+
+    /// Should be called to free the heap buffer of a shared `GermanStr`.
+    ///
+    /// # Safety
+    /// * `self` should be heap-allocated and not inlined (you can check with
+    ///   `GermanStr::is_heap_allocated`).
+    /// * `self` shouldn't have been created with `GermanStr::from_static`.
+    /// * You should only free each buffer once.
+    ///
+    /// However, `free()`ing a heap allocated but non-shared `GermanStr` is
+    /// safe and equivalent to dropping it.
+    ///
+    /// To avoid double frees, you can simply store a set of freed pointers,
+    /// or use a `SharedBufferRegistry` which does it for you.
+    /// ```no_run
+    /// use std::collections::BTreeSet;
+    /// # use german_str::GermanStr;
+    ///
+    /// let mut freed = BTreeSet::new();
+    /// # let german_str: Vec<GermanStr> = Vec::new();
+    /// for s in german_str {
+    ///     let Some(ptr) = s.heap_ptr() else {
+    ///         continue; // skip inlined GermanStr
+    ///     };
+    ///     if freed.insert(ptr) {
+    ///         unsafe {
+    ///             // Safety:
+    ///             // 1. s is heap-allocated or s.heap_ptr() would be None.
+    ///             // 2. If ptr had already been freed, insert()'ing it would've returned false.
+    ///             s.free();
+    ///         }
+    ///     } else {
+    ///         std::mem::forget(s);
+    ///     }
+    /// }
+    /// ```
+    pub unsafe fn free(mut self) {
+        unsafe {
+            // Safety:
+            // the caller is responsible for checking that `self` isn't inlined.
+            let flags = self.last8.ptr.stolen() & !OWNED_PTR;
+            self.last8.ptr = self.last8.ptr.steal(OWNED_PTR | flags);
+        }
+        core::mem::drop(self)
+    }
+
+    /// Consumes `self` and leaks its buffer, returning a `&'static str`,
+    /// like `String::leak`.
+    ///
+    /// Owned heap buffers are leaked as is. Inlined strings, and strings
+    /// with a shared buffer (which may be freed by its owner), are copied
+    /// into a new leaked allocation.
+    /// ```
+    /// use german_str::GermanStr;
+    ///
+    /// let config: &'static str = GermanStr::new("a configuration value").unwrap().leak();
+    /// assert_eq!(config, "a configuration value");
+    /// ```
+    pub fn leak(self) -> &'static str {
+        let Some(ptr) = self.heap_ptr().filter(|_| !self.has_shared_buffer()) else {
+            return Box::leak(Box::<str>::from(self.as_str()));
+        };
+        let len = self.len();
+        mem::forget(self);
+        unsafe {
+            // Safety: the buffer was owned by self, which was forgotten so
+            // the buffer will never be freed nor mutated.
+            core::str::from_utf8_unchecked(slice::from_raw_parts(ptr.as_ptr(), len))
+        }
+    }
+
+    #[inline]
+    /// Returns a slice containing the first 4 bytes of a `GermanStr`.
+    /// Can be used for comparisons and ordering as is.
+    /// Since an UTF-8 char can consist of 1-4 bytes, this slice can represent
+    /// anywhere from 1 to 4 chars, and potentially only part of the last char.
+    pub fn prefix_bytes_slice(&self) -> &[u8] {
+        let prefix_len = self.len().min(4);
+        &self.prefix[..prefix_len]
+    }
+
+    #[inline(always)]
+    /// Returns an array containing the first 4 bytes of a `GermanStr`.
+    /// If the string is shorter than 4 bytes, extra bytes are set to 0.
+    /// Can be used for comparisons and ordering as is.
+    /// Since an UTF-8 char can consist of 1-4 bytes, this array can represent
+    /// anywhere from 1 to 4 chars, and potentially only part of the last char.
+    pub const fn prefix_bytes_array(&self) -> [u8; 4] {
+        self.prefix
+    }
+
+    #[inline]
+    /// Returns a slice containing every byte of a `GermanStr`, except the first 4.
+    pub fn suffix_bytes_slice(&self) -> &[u8] {
+        let suffix_len = self.len().saturating_sub(4);
+        if self.len.get() as usize > MAX_INLINE_BYTES {
+            unsafe {
+                // Safety:
+                // self.len  > MAX_INLINE_BYTES => self.last8 is heap ptr.
+                let ptr = self.last8.ptr.as_non_null().as_ptr();
+
+                // Safety:
+                // 1. The data is part of a single object.
+                // 2. Pointer is checked for null at alloc.
+                // 3. ptr has the correct offset for the length
+                // 4. Heap values are properly initialized.
+                // 5. Values in the slice are never mutated.
+                slice::from_raw_parts(ptr.add(4), suffix_len)
+            }
+        } else {
+            unsafe {
+                &self.last8.buf[0..suffix_len]
+            }
+        }
+    }
+
+    #[inline(always)]
+    pub fn as_str(&self) -> &str {
+        Deref::deref(self)
+    }
+
+    #[inline(always)]
+    /// Returns the bytes of the string, without assuming that they are
+    /// valid UTF-8.
+    pub(crate) fn raw_bytes(&self) -> &[u8] {
+        let ptr = self.heap_ptr()
+            .unwrap_or_else(|| unsafe {
+                // Safety: the offset is in bounds of self.
+                // The pointer is derived from the whole struct rather than
+                // from self.prefix, since inline bytes continue in last8.
+                NonNull::from(self).cast::<u8>().add(mem::offset_of!(GermanStr, prefix))
+            });
+        unsafe {
+            // Safety:
+            // * Since we're making a &[u8], it is guaranteed to be aligned.
+            // * The pointer used is NonNull and part of a single object (self
+            // or the heap buffer).
+            // * The len of the slice is correct.
+            // * The len is shorter than isize::MAX (2^63 - 1, MAX_LEN < 2^32).
+            // * ptr + len < isize::MAX, or the heap buffer/struct would overflow usize::MAX.
+            slice::from_raw_parts(ptr.as_ptr(), self.len.get() as usize)
+        }
+    }
+
+    #[inline(always)]
+    /// Returns the raw representation of the struct, as 2 words: the first
+    /// one contains `len` and `prefix`, the second one `last8`.
+    fn as_words(&self) -> [u64; 2] {
+        unsafe {
+            // Safety: GermanStr is repr(C), 16 bytes long and has no padding,
+            // and all the bytes of Last8 are initialized whichever variant is used.
+            mem::transmute_copy(self)
+        }
+    }
+
+    #[inline(always)]
+    /// Returns the 16 bytes representing `self`, for kernels hashing or
+    /// comparing strings by their representation, such as vectorized hash
+    /// joins.
+    ///
+    /// Inlined strings are equal if and only if their representations are
+    /// equal. The representation of heap-allocated strings contains the
+    /// address of their buffer: it is only stable while the buffer lives,
+    /// within a process run, and equal strings with different buffers have
+    /// different representations. The layout of the representation isn't
+    /// part of the stable API, and may change in any release.
+    /// ```
+    /// use german_str::GermanStr;
+    ///
+    /// let short = GermanStr::new("short").unwrap();
+    /// assert_eq!(short.as_raw_bytes(), GermanStr::new("short").unwrap().as_raw_bytes());
+    /// assert_ne!(short.as_raw_bytes(), GermanStr::new("shorT").unwrap().as_raw_bytes());
+    /// ```
+    pub fn as_raw_bytes(&self) -> [u8; 16] {
+        unsafe {
+            // Safety: same as in `as_words`.
+            mem::transmute_copy(self)
+        }
+    }
+
+    #[inline(always)]
+    pub fn as_bytes(&self) -> &[u8] {
+        self.as_str().as_bytes()
+    }
+
+    #[inline]
+    /// Calls `f` with a mutable view of the string and returns its result if
+    /// `self` is heap-allocated and its buffer isn't shared, returns `None`
+    /// without calling `f` otherwise.
+    ///
+    /// Since `f` only gets a `&mut str`, the length of the string can't
+    /// change. The prefix stored in `self`, and whether it is ASCII, are
+    /// updated once `f` returns, or if it panics.
+    /// ```
+    /// use german_str::GermanStr;
+    ///
+    /// let mut s = GermanStr::new("longer than 12 bytes").unwrap();
+    /// s.with_mut(str::make_ascii_uppercase).unwrap();
+    /// assert_eq!(s, "LONGER THAN 12 BYTES");
+    /// ```
+    pub fn with_mut<R>(&mut self, f: impl FnOnce(&mut str) -> R) -> Option<R> {
+        let ptr = self.writable_heap_ptr()?;
+        let len = self.len();
+        let _resync = Resync { german: self };
+        let s = unsafe {
+            // Safety:
+            // * The buffer is owned by self, which is borrowed mutably, so
+            //   no other reference to the buffer exists.
+            // * The len of the slice is correct, see Deref for GermanStr.
+            // * The &mut str can only be mutated in ways that keep it valid UTF-8.
+            core::str::from_utf8_unchecked_mut(slice::from_raw_parts_mut(ptr.as_ptr(), len))
+        };
+        Some(f(s))
+    }
+
+    #[inline]
+    /// Returns the heap buffer of `self` if it can be written to: if it is
+    /// owned by `self`, and not kept alive by an owner such as a `Bytes`.
+    pub(crate) fn writable_heap_ptr(&self) -> Option<NonNull<u8>> {
+        let ptr = self.heap_ptr().filter(|_| !self.has_shared_buffer())?;
+        #[cfg(feature = "std")]
+        if self.has_foreign_buffer() && !crate::foreign::is_writable(ptr) {
+            return None;
+        }
+        Some(ptr)
+    }
+
+    #[inline(always)]
+    /// Returns whether `self` contains exactly `other`, looking at the
+    /// prefixes first.
+    fn eq_bytes(&self, other: &[u8]) -> bool {
+        self.len() == other.len() &&
+        self.prefix == bytes_prefix(other) &&
+        self.suffix_bytes_slice() == other.get(4..).unwrap_or_default()
+    }
+
+    #[inline]
+    /// Returns the largest char boundary of `self` that is `<= index`.
+    fn floor_char_boundary(&self, index: usize) -> usize {
+        if index >= self.len() {
+            return self.len();
+        }
+        let mut end = index;
+        while !self.is_char_boundary(end) {
+            end -= 1;
+        }
+        end
+    }
+
+    #[inline]
+    /// Returns the longest beginning of `self` that is at most `max_bytes`
+    /// long, without splitting a char.
+    ///
+    /// The result is inlined if it fits in `MAX_INLINE_BYTES`.
+    pub fn truncate_to(&self, max_bytes: usize) -> GermanStr {
+        let end = self.floor_char_boundary(max_bytes);
+        GermanStr::new(&self[..end]).expect("a substring of a GermanStr can't be too long")
+    }
+
+    /// Same as `GermanStr::truncate_to`, but ends the result with `…` when
+    /// `self` is truncated. The result, including the `…`, is at most
+    /// `max_bytes` long.
+    ///
+    /// If `max_bytes` is too small to fit the `…`, it is omitted.
+    /// ```
+    /// use german_str::GermanStr;
+    ///
+    /// let s = GermanStr::new("a long log message").unwrap();
+    /// assert_eq!(s.truncate_with_ellipsis(9), "a long…");
+    /// ```
+    pub fn truncate_with_ellipsis(&self, max_bytes: usize) -> GermanStr {
+        const ELLIPSIS: &str = "…";
+        if self.len() <= max_bytes || max_bytes < ELLIPSIS.len() {
+            return self.truncate_to(max_bytes);
+        }
+        let end = self.floor_char_boundary(max_bytes - ELLIPSIS.len());
+        let mut writer = Writer::new();
+        writer.push_str(&self[..end])
+            .and_then(|_| writer.push_str(ELLIPSIS))
+            .expect("a substring of a GermanStr can't be too long");
+        GermanStr::from(writer)
+    }
+
+    #[inline]
+    /// Returns a subslice of the string, or `None` if the range is out of
+    /// bounds or doesn't fall on char boundaries.
+    pub fn get<I: SliceIndex<str>>(&self, index: I) -> Option<&I::Output> {
+        self.as_str().get(index)
+    }
+
+    #[allow(clippy::inherent_to_string_shadow_display)]
+    #[inline(always)]
+    pub fn to_string(&self) -> String {
+        self.as_str().to_owned()
+    }
+
+    #[inline(always)]
+    pub const fn len(&self) -> usize {
+        self.len.get() as usize
+    }
+
+    #[inline(always)]
+    pub const fn is_empty(&self) -> bool  {
+        self.len.get() == 0
+    }
+
+    #[inline]
+    /// Returns the number of chars in the string, like `self.chars().count()`
+    /// but without decoding them.
+    ///
+    /// Uses SIMD instructions on long strings if the `bytecount` feature is
+    /// enabled. On x86, they are detected at runtime, which requires the
+    /// `std` feature.
+    pub fn char_count(&self) -> usize {
+        if let Some(count) = self.fast_char_count() {
+            return count;
+        }
+        count_chars(self.as_str())
+    }
+
+    #[inline]
+    /// Returns the number of chars in the string if it can be computed in
+    /// constant time, which is the case if the string is inlined, or known
+    /// to be ASCII (see `GermanStr::is_ascii`).
+    pub fn fast_char_count(&self) -> Option<usize> {
+        if let Some(ptr) = self.heap_tagged() {
+            return (ptr.stolen() & ASCII_PTR != 0).then(|| self.len());
+        }
+        // Every byte starts a char, except the continuation bytes. The
+        // padding of inlined strings is made of 0s, which aren't continuation
+        // bytes: they can be counted over the whole buffer without branching.
+        let prefix = u32::from_ne_bytes(self.prefix) as u64;
+        let suffix = unsafe {
+            // Safety: the string is stored inline.
+            u64::from_ne_bytes(self.last8.buf)
+        };
+        Some(self.len() - count_continuation_bytes(prefix) - count_continuation_bytes(suffix))
+    }
+
+    #[inline]
+    /// Returns whether the string only contains ASCII chars, like
+    /// `str::is_ascii`.
+    ///
+    /// The result is computed when the string is created, so this runs in
+    /// constant time. Long strings referencing buffers they don't own, such
+    /// as the ones created by `GermanStr::from_static` or stored in a
+    /// `GermanStrVec`, may have to be scanned instead.
+    pub fn is_ascii(&self) -> bool {
+        if let Some(ptr) = self.heap_tagged() {
+            return ptr.stolen() & ASCII_PTR != 0 || self.as_bytes().is_ascii();
+        }
+        // The padding of inlined strings is made of 0s, which are ASCII.
+        let prefix = u32::from_ne_bytes(self.prefix) as u64;
+        let suffix = unsafe {
+            // Safety: the string is stored inline.
+            u64::from_ne_bytes(self.last8.buf)
+        };
+        (prefix | suffix) & HIGH_BITS == 0
+    }
+
+    #[inline(always)]
+    /// Returns whether a heap allocation is used to store the string.
+    pub const fn is_heap_allocated(&self) -> bool {
+        self.len.get() as usize > MAX_INLINE_BYTES
+    }
+
+    #[inline(always)]
+    /// Returns whether the string is stored entirely within `self`, without a heap allocation.
+    pub const fn is_inlined(&self) -> bool {
+        !self.is_heap_allocated()
+    }
+
+    #[inline]
+    /// Returns the length in bytes of the longest common prefix of `self`
+    /// and `other`, which may end in the middle of a char.
+    ///
+    /// The strings are compared 4 bytes then 8 bytes at a time: the first
+    /// word is the prefix stored in the struct, so strings which differ in
+    /// their first 4 bytes are never dereferenced.
+    /// ```
+    /// use german_str::GermanStr;
+    ///
+    /// let lhs = GermanStr::new("/usr/local/bin/cargo").unwrap();
+    /// let rhs = GermanStr::new("/usr/local/lib").unwrap();
+    /// assert_eq!(lhs.longest_common_prefix(&rhs), "/usr/local/".len());
+    /// ```
+    pub fn longest_common_prefix(&self, other: &GermanStr) -> usize {
+        let max = self.len().min(other.len());
+        // Byte i of a little-endian word is its i-th least significant
+        // byte, so the first different byte is found by trailing_zeros.
+        let diff = u32::from_le_bytes(self.prefix) ^ u32::from_le_bytes(other.prefix);
+        if diff != 0 || max <= 4 {
+            return max.min(diff.trailing_zeros() as usize / 8);
+        }
+        if self.is_inlined() && other.is_inlined() {
+            let diff = unsafe {
+                // Safety: both strings are stored inline.
+                u64::from_le_bytes(self.last8.buf) ^ u64::from_le_bytes(other.last8.buf)
+            };
+            // The padding is made of 0s on both sides, and is cut by max.
+            return max.min(4 + diff.trailing_zeros() as usize / 8);
+        }
+        4 + common_prefix_len(self.suffix_bytes_slice(), other.suffix_bytes_slice())
+    }
+
+    #[inline(always)]
+    /// Compares `self` and `other` using only their prefixes and lengths.
+    ///
+    /// Returns `None` if this isn't enough to order them, in which case
+    /// `Ord::cmp` has to look at the rest of the strings. This lets sorting
+    /// algorithms run a cheap first pass, and only fully compare ties.
+    pub fn cmp_prefix(&self, other: &GermanStr) -> Option<cmp::Ordering> {
+        match be_u32(self.prefix).cmp(&be_u32(other.prefix)) {
+            cmp::Ordering::Equal if self.len.get() <= 4 || other.len.get() <= 4 => {
+                // If one string fits in its prefix, it is equal to the
+                // beginning of the other one (padded with 0s).
+                Some(self.len.get().cmp(&other.len.get()))
+            }
+            cmp::Ordering::Equal => None,
+            ord => Some(ord),
+        }
+    }
+
+    #[inline(always)]
+    /// Returns a key combining the prefix and the length of `self`, whose
+    /// order is consistent with `cmp_prefix`: keys are only equal when
+    /// `cmp_prefix` returns `None` or `Some(Equal)`.
+    ///
+    /// This lets external sorters use `sort_by_cached_key`-like algorithms,
+    /// and only fully compare strings whose keys are equal. Strings of 4 or
+    /// less bytes are equal to any string with the same key.
+    /// ```
+    /// use german_str::GermanStr;
+    ///
+    /// let key = |s| GermanStr::new(s).unwrap().prefix_key();
+    /// assert!(key("abc") < key("abc\0"));
+    /// assert!(key("abcd") < key("abcde"));
+    /// assert!(key("abcde") < key("abd"));
+    /// assert_eq!(key("abcde"), key("abcdefghijklmnop"));
+    /// ```
+    pub fn prefix_key(&self) -> u64 {
+        // The lengths of strings longer than 4 bytes don't matter, since
+        // they are only ordered by the rest of their bytes.
+        ((be_u32(self.prefix) as u64) << 32) | self.len().min(5) as u64
+    }
+
+    /// Appends to `out` a key whose byte-wise order is the order of
+    /// `GermanStr`s, for use with generic byte sorts (such as radix sorts).
+    ///
+    /// `0x00` bytes are escaped as `0x00 0xFF` and the key ends with
+    /// `0x00 0x00`, so no key is a prefix of another one: keys of several
+    /// fields can be concatenated and still sort like tuples of fields.
+    pub fn write_sort_key(&self, out: &mut Vec<u8>) {
+        out.reserve(self.len() + 2);
+        for chunk in self.as_bytes().split_inclusive(|b| *b == 0) {
+            out.extend_from_slice(chunk);
+            if chunk.last() == Some(&0) {
+                out.push(0xFF);
+            }
+        }
+        out.extend_from_slice(&[0, 0]);
+    }
+
+    #[inline(always)]
+    /// Compares `self` with a `&str`, looking at the prefixes first.
+    pub(crate) fn cmp_str(&self, other: &str) -> cmp::Ordering {
+        be_u32(self.prefix)
+            .cmp(&be_u32(str_prefix::<&str>(other)))
+            .then_with(|| self.suffix_bytes_slice().cmp(str_suffix::<&str>(&other)))
+            // Prefixes are padded with 0s: "a" and "a\0" are only told apart by their len.
+            .then_with(|| self.len().cmp(&other.len()))
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// How a `GermanStr` is stored, as returned by `GermanStr::storage`.
+pub enum Storage {
+    /// The string is entirely stored in the struct itself.
+    Inline,
+
+    /// The string is stored in a heap buffer of `len` bytes.
+    Heap {
+        /// Whether the buffer may be shared with other `GermanStr`s, in which
+        /// case dropping `self` doesn't free it (see
+        /// `GermanStr::has_shared_buffer`).
+        shared: bool,
+        ptr: NonNull<u8>,
+        len: usize,
+    },
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// How `GermanStr::allocated_size` counts shared heap buffers, which are
+/// referenced by several strings.
+pub enum SharedAccounting {
+    /// Shared buffers aren't counted, since they are accounted for by
+    /// their owner.
+    Ignore,
+
+    /// Shared buffers are counted in full by every string referencing them.
+    Full,
+
+    /// Shared buffers are split evenly between this many strings, rounded
+    /// up.
+    Split(usize),
+}
+
+/// Updates the prefix of a `GermanStr`, and whether it is ASCII, when
+/// dropped, after its buffer was mutated by `GermanStr::with_mut`.
+struct Resync<'a> {
+    /// Always heap-allocated, with an owned buffer.
+    german: &'a mut GermanStr,
+}
+
+impl Drop for Resync<'_> {
+    #[inline]
+    fn drop(&mut self) {
+        self.german.prefix = str_prefix::<&str>(self.german.as_str());
+        unsafe {
+            // Safety: self.german is heap-allocated, with an owned buffer.
+            let ascii = ascii_bit(self.german.as_bytes());
+            let flags = self.german.last8.ptr.stolen() & (ALIGNED_PTR | FOREIGN_PTR);
+            self.german.last8.ptr = self.german.last8.ptr.steal(OWNED_PTR | ascii | flags);
+        }
+    }
+}
+
+impl Clone for GermanStr {
+    #[inline]
+    fn clone(&self) -> Self {
+        if let Some(self_ptr) = self.heap_ptr() {
+            let flags = unsafe {
+                // Safety: self is heap-allocated.
+                self.last8.ptr.stolen() & !(OWNED_PTR | FOREIGN_PTR)
+            };
+            // The clone of an over-aligned buffer is over-aligned too, but
+            // the clone of a foreign buffer is allocated like other buffers.
+            let layout = buffer_layout(self.len.get(), flags);
+            let ptr = unsafe {
+                // Safety: layout is not zero-sized, otherwise we would store the string inplace.
+                alloc::alloc::alloc(layout)
+            };
+            let Some(ptr) = NonNull::new(ptr) else {
+                alloc::alloc::handle_alloc_error(layout);
+            };
+            unsafe {
+                // Safety:
+                //   1. Both pointers are valid.
+                //   2. *_ u8 is always aligned.
+                //   3. The 2 regions can't overlap since they belong to different objects.
+                ptr::copy_nonoverlapping(
+                    self_ptr.as_ptr(),
+                    ptr.as_ptr(),
+                    self.len(),
+                );
+            }
+            let tagged = TaggedPtr::new_stealing(ptr, OWNED_PTR | flags);
+            #[cfg(feature = "metrics")]
+            crate::metrics::record_alloc(self.len());
+            GermanStr {
+                prefix: self.prefix,
+                len: self.len,
+                last8: Last8 { ptr: tagged },
+            }
+        } else {
+            GermanStr {
+                len: self.len,
+                prefix: self.prefix,
+                last8: self.last8,
+            }
+        }
+    }
+}
+
+impl Drop for GermanStr {
+    #[inline]
+    fn drop(&mut self) {
+        #[cfg(feature = "zeroize")]
+        crate::zeroize::wipe(self);
+        let ptr = match self.heap_ptr() {
+            Some(ptr) if !self.has_shared_buffer() => ptr,
+            Some(_) | None => return,
+            // If the heap buffer is shared, or the string is inlined,
+            // dropping should be a no-op.
+        };
+        let flags = unsafe {
+            // Safety: self is heap-allocated.
+            self.last8.ptr.stolen()
+        };
+        #[cfg(feature = "std")]
+        if flags & FOREIGN_PTR != 0 {
+            unsafe {
+                // Safety: the buffer is owned by self, and was adopted by
+                // from_foreign_buffer.
+                crate::foreign::release(ptr, self.len());
+            }
+            #[cfg(feature = "metrics")]
+            crate::metrics::record_free(self.len());
+            return;
+        }
+        unsafe {
+            // Safety: the buffer was allocated with this layout.
+            alloc::alloc::dealloc(ptr.as_ptr(), buffer_layout(self.len.get(), flags));
+        }
+        #[cfg(feature = "metrics")]
+        crate::metrics::record_free(self.len());
+    }
+}
+
+// Safety: According to the rustonomicon, "something can safely be Send unless it shares mutable
+// state with something else without enforcing exclusive access to it."
+// The `ptr` is never shared between `GermanStr`, so there's no shared mutable state.
+unsafe impl Send for GermanStr {}
+
+// Safety: Again, according to the rustonomicon, there's no issue here since GermanStr are
+// immutable.
+unsafe impl Sync for GermanStr {}
+
+impl Deref for GermanStr {
+    type Target = str;
+
+    #[inline(always)]
+    fn deref(&self) -> &str {
+        unsafe {
+            // Safety:
+            // A `GermanStr` is guaranteed to be a valid UTF8 string,
+            // since it can only be constructed from an impl AsRef<str>,
+            // a String, or a Writer that accepts &str.
+            core::str::from_utf8_unchecked(self.raw_bytes())
+        }
+    }
+}
+
+// `Borrow<[u8]>` isn't implemented: `GermanStr` hashes like a `str`, which
+// differs from how `[u8]` hashes.
+impl AsRef<[u8]> for GermanStr {
+    #[inline(always)]
+    fn as_ref(&self) -> &[u8] {
+        self.as_bytes()
+    }
+}
+
+impl PartialEq<[u8]> for GermanStr {
+    #[inline(always)]
+    fn eq(&self, other: &[u8]) -> bool {
+        self.eq_bytes(other)
+    }
+}
+
+impl PartialEq<GermanStr> for [u8] {
+    #[inline(always)]
+    fn eq(&self, other: &GermanStr) -> bool {
+        other.eq_bytes(self)
+    }
+}
+
+impl PartialEq<&[u8]> for GermanStr {
+    #[inline(always)]
+    fn eq(&self, other: &&[u8]) -> bool {
+        self.eq_bytes(other)
+    }
+}
+
+impl PartialEq<GermanStr> for &[u8] {
+    #[inline(always)]
+    fn eq(&self, other: &GermanStr) -> bool {
+        other.eq_bytes(self)
+    }
+}
+
+impl<I: SliceIndex<str>> Index<I> for GermanStr {
+    type Output = I::Output;
+
+    #[inline(always)]
+    fn index(&self, index: I) -> &I::Output {
+        &self.as_str()[index]
+    }
+}
+
+impl AsRef<str> for GermanStr {
+    #[inline(always)]
+    fn as_ref(&self) -> &str {
+        Deref::deref(self)
+    }
+}
+
+impl PartialEq<GermanStr> for GermanStr {
+    #[inline(always)]
+    fn eq(&self, other: &GermanStr) -> bool {
+        let lhs = self.as_words();
+        let rhs = other.as_words();
+        if self.len.get() as usize <= MAX_INLINE_BYTES {
+            // The bytes after the end of an inline string are always 0, so
+            // 2 inline strings are equal iff all their bytes are equal.
+            // If `other` is heap-allocated, its `len` is different.
+            return lhs == rhs;
+        }
+        // The first word contains `len` and `prefix`.
+        if lhs[0] != rhs[0] {
+            return false;
+        }
+        // Same len and same buffer, like for shared or interned strings.
+        if lhs[1] == rhs[1] {
+            return true;
+        }
+        self.suffix_bytes_slice() == other.suffix_bytes_slice()
+    }
+}
+
+impl Eq for GermanStr {}
+
+impl Ord for GermanStr {
+    #[inline]
+    fn cmp(&self, other: &Self) -> cmp::Ordering {
+        self.cmp_prefix(other).unwrap_or_else(||
+            if self.is_inlined() && other.is_inlined() {
+                unsafe {
+                    // Safety: obviously both strings are stored inline.
+                    u64::from_be_bytes(self.last8.buf).cmp(&u64::from_be_bytes(other.last8.buf))
+                }
+                // Extra bytes are set to 0: "abcde" and "abcde\0" are only
+                // told apart by their len.
+                .then(self.len.get().cmp(&other.len.get()))
+            } else {
+                self.suffix_bytes_slice().cmp(other.suffix_bytes_slice())
+            }
+        )
+    }
+}
+
+impl Default for GermanStr {
+    #[inline(always)]
+    fn default() -> GermanStr {
+        GermanStr {
+            len: Len::new(0),
+            prefix: [0; 4],
+            last8: Last8 { buf: [0; 8] },
+        }
+    }
+}
+
+impl FromStr for GermanStr {
+    type Err = InitError;
+
+    #[inline]
+    fn from_str(s: &str) -> Result<GermanStr, Self::Err> {
+        GermanStr::new(s)
+    }
+}
+
+impl Borrow<str> for GermanStr {
+    #[inline(always)]
+    fn borrow(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl core::fmt::Debug for GermanStr {
+    #[inline]
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        core::fmt::Debug::fmt(self.as_str(), f)
+    }
+}
+
+
+impl core::fmt::Display for GermanStr {
+    #[inline]
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        core::fmt::Display::fmt(self.as_str(), f)
+    }
+}
+
+impl core::fmt::Display for FromUtf8Error {
+    #[inline]
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        core::fmt::Display::fmt(&self.error, f)
+    }
+}
+
+impl core::error::Error for FromUtf8Error {
+    #[inline]
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        Some(&self.error)
+    }
+}
+
+impl core::hash::Hash for GermanStr {
+    #[inline(always)]
+    fn hash<H: core::hash::Hasher>(&self, hasher: &mut H) {
+        self.as_str().hash(hasher);
+    }
+}
+
+
+impl PartialOrd for GermanStr {
+    #[inline(always)]
+    fn partial_cmp(&self, other: &GermanStr) -> Option<core::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl PartialEq<str> for GermanStr {
+    #[inline(always)]
+    fn eq(&self, other: &str) -> bool {
+        self.eq_bytes(other.as_bytes())
+    }
+}
+
+impl PartialEq<GermanStr> for str {
+    #[inline(always)]
+    fn eq(&self, other: &GermanStr) -> bool {
+        other.eq_bytes(self.as_bytes())
+    }
+}
+
+impl<'a> PartialEq<&'a str> for GermanStr {
+    #[inline(always)]
+    fn eq(&self, other: &&'a str) -> bool {
+        self.eq_bytes(other.as_bytes())
+    }
+}
+
+impl PartialEq<GermanStr> for &str {
+    #[inline(always)]
+    fn eq(&self, other: &GermanStr) -> bool {
+        other.eq_bytes(self.as_bytes())
+    }
+}
+
+impl PartialEq<String> for GermanStr {
+    #[inline(always)]
+    fn eq(&self, other: &String) -> bool {
+        self.eq_bytes(other.as_bytes())
+    }
+}
+
+impl PartialEq<GermanStr> for String {
+    #[inline(always)]
+    fn eq(&self, other: &GermanStr) -> bool {
+        other.eq_bytes(self.as_bytes())
+    }
+}
+
+impl<'a> PartialEq<&'a String> for GermanStr {
+    #[inline(always)]
+    fn eq(&self, other: &&'a String) -> bool {
+        self.eq_bytes(other.as_bytes())
+    }
+}
+
+impl PartialEq<GermanStr> for &String {
+    #[inline(always)]
+    fn eq(&self, other: &GermanStr) -> bool {
+        other.eq_bytes(self.as_bytes())
+    }
+}
+
+impl PartialOrd<str> for GermanStr {
+    #[inline(always)]
+    fn partial_cmp(&self, other: &str) -> Option<cmp::Ordering> {
+        Some(self.cmp_str(other))
+    }
+}
+
+impl PartialOrd<GermanStr> for str {
+    #[inline(always)]
+    fn partial_cmp(&self, other: &GermanStr) -> Option<cmp::Ordering> {
+        Some(other.cmp_str(self).reverse())
+    }
+}
+
+impl PartialOrd<&str> for GermanStr {
+    #[inline(always)]
+    fn partial_cmp(&self, other: &&str) -> Option<cmp::Ordering> {
+        Some(self.cmp_str(other))
+    }
+}
+
+impl PartialOrd<GermanStr> for &str {
+    #[inline(always)]
+    fn partial_cmp(&self, other: &GermanStr) -> Option<cmp::Ordering> {
+        Some(other.cmp_str(self).reverse())
+    }
+}
+
+impl PartialOrd<String> for GermanStr {
+    #[inline(always)]
+    fn partial_cmp(&self, other: &String) -> Option<cmp::Ordering> {
+        Some(self.cmp_str(other))
+    }
+}
+
+impl PartialOrd<GermanStr> for String {
+    #[inline(always)]
+    fn partial_cmp(&self, other: &GermanStr) -> Option<cmp::Ordering> {
+        Some(other.cmp_str(self).reverse())
+    }
+}
+
+impl PartialOrd<&String> for GermanStr {
+    #[inline(always)]
+    fn partial_cmp(&self, other: &&String) -> Option<cmp::Ordering> {
+        Some(self.cmp_str(other))
+    }
+}
+
+impl PartialOrd<GermanStr> for &String {
+    #[inline(always)]
+    fn partial_cmp(&self, other: &GermanStr) -> Option<cmp::Ordering> {
+        Some(other.cmp_str(self).reverse())
+    }
+}
+
+impl TryFrom<&str> for GermanStr {
+    type Error = InitError;
+
+    #[inline]
+    fn try_from(s: &str) -> Result<GermanStr, InitError> {
+        GermanStr::new(s)
+        }
+    }
+
+impl TryFrom<&mut str> for GermanStr {
+    type Error = InitError;
+
+    #[inline]
+    fn try_from(s: &mut str) -> Result<GermanStr,InitError> {
+        GermanStr::new(s)
+    }
+}
+
+impl TryFrom<&String> for GermanStr {
+    type Error = InitError;
+
+    #[inline]
+    fn try_from(s: &String) -> Result<GermanStr,InitError> {
+        GermanStr::new(s)
+    }
+}
+
+impl TryFrom<String> for GermanStr {
+    type Error = InitError;
+
+    #[inline(always)]
+    fn try_from(text: String) -> Result<Self, Self::Error> {
+        Self::new(text)
+    }
+}
+
+impl TryFrom<Box<str>> for GermanStr {
+    type Error = InitError;
+
+    #[inline(always)]
+    fn try_from(s: Box<str>) -> Result<GermanStr, Self::Error> {
+        GermanStr::new(s)
+    }
+}
+
+impl TryFrom<Arc<str>> for GermanStr {
+    type Error = InitError;
+
+    #[inline(always)]
+    fn try_from(s: Arc<str>) -> Result<GermanStr, Self::Error> {
+        GermanStr::new(s)
+    }
+}
+
+impl From<GermanStr> for Arc<str> {
+    #[inline(always)]
+    fn from(text: GermanStr) -> Self {
+        text.as_str().into()
+    }
+}
+
+impl<'a> TryFrom<Cow<'a, str>> for GermanStr {
+    type Error = InitError;
+
+    #[inline]
+    fn try_from(s: Cow<'a, str>) -> Result<GermanStr,InitError> {
+        GermanStr::new(s)
+    }
+}
+
+impl From<char> for GermanStr {
+    #[inline]
+    fn from(c: char) -> Self {
+        // A char is at most 4 bytes of UTF-8, so it always fits inline.
+        let mut buf = [0; 4];
+        GermanStr::new_inline(c.encode_utf8(&mut buf))
+    }
+}
+
+macro_rules! impl_from_int {
+    ($($t:ty),* ; |$n:ident| $negative:expr, $magnitude:expr) => {$(
+        impl From<$t> for GermanStr {
+            #[inline]
+            /// Formats the integer in base 10, like its `Display` impl but
+            /// without going through `core::fmt`. Every integer of up to
+            /// 12 digits (including the sign) is inlined.
+            fn from($n: $t) -> Self {
+                #[cfg(feature = "itoa")]
+                {
+                    GermanStr::from_int_str(itoa::Buffer::new().format($n))
+                }
+                #[cfg(not(feature = "itoa"))]
+                {
+                    let mut buf = [0; 40];
+                    GermanStr::from_int_str(int_to_str($magnitude, $negative, &mut buf))
+                }
+            }
+        }
+    )*};
+}
+
+impl_from_int!(u8, u16, u32, u64, usize; |n| false, n as u128);
+impl_from_int!(u128; |n| false, n);
+impl_from_int!(i8, i16, i32, i64, isize; |n| n < 0, n.unsigned_abs() as u128);
+impl_from_int!(i128; |n| n < 0, n.unsigned_abs());
+
+#[cfg(feature = "ryu")]
+impl From<f32> for GermanStr {
+    #[inline]
+    /// Formats the float with `ryu`, which produces the shortest
+    /// representation that roundtrips. This differs from the `Display`
+    /// impl for some values: `1e20_f32` becomes `"1e20"`, and `1.0_f32`
+    /// becomes `"1.0"`.
+    fn from(n: f32) -> Self {
+        GermanStr::from_int_str(ryu::Buffer::new().format(n))
+    }
+}
+
+#[cfg(feature = "ryu")]
+impl From<f64> for GermanStr {
+    #[inline]
+    /// Formats the float with `ryu`, which produces the shortest
+    /// representation that roundtrips. This differs from the `Display`
+    /// impl for some values: `1e20_f64` becomes `"1e20"`, and `1.0_f64`
+    /// becomes `"1.0"`.
+    fn from(n: f64) -> Self {
+        GermanStr::from_int_str(ryu::Buffer::new().format(n))
+    }
+}
+
+impl From<GermanStr> for String {
+    #[inline(always)]
+    fn from(text: GermanStr) -> Self {
+        text.as_str().into()
+    }
+}
+
+#[derive(Default)]
+/// Deduplicates the heap buffers of equal `GermanStr`s.
+///
+/// Every heap-allocated string returned by `GermanStrInterner::intern` shares
+/// its buffer with all other equal strings returned by the same interner, as
+/// if they had been created with `GermanStr::leaky_shared_clone`.
+/// Inlined strings are returned as is, since they don't use a heap buffer.
+///
+/// Dropping the interner doesn't free the buffers: call
+/// `GermanStrInterner::free` once every interned `GermanStr` has been
+/// dropped, or the buffers will be leaked.
+pub struct GermanStrInterner {
+    /// One instance per distinct heap-allocated string. Every buffer is shared.
+    strings: BTreeSet<GermanStr>,
+}
+
+impl GermanStrInterner {
+    #[inline]
+    pub const fn new() -> Self {
+        GermanStrInterner {
+            strings: BTreeSet::new(),
+        }
+    }
+
+    /// Returns a `GermanStr` equal to `src`, reusing the heap buffer of a
+    /// previously interned equal string if there is one.
+    pub fn intern(&mut self, src: &str) -> Result<GermanStr, InitError> {
+        if src.len() <= MAX_INLINE_BYTES {
+            return Ok(GermanStr::new_inline(src));
+        }
+        if let Some(interned) = self.strings.get(src) {
+            // Every string in self.strings has a shared buffer, so copying
+            // it bitwise is equivalent to calling leaky_shared_clone.
+            return Ok(GermanStr {
+                len: interned.len,
+                prefix: interned.prefix,
+                last8: interned.last8,
+            });
+        }
+        let mut interned = GermanStr::new(src)?;
+        let copy = interned.leaky_shared_clone();
+        self.strings.insert(interned);
+        Ok(copy)
+    }
+
+    #[inline]
+    /// Returns the number of distinct heap buffers held by the interner.
+    pub fn len(&self) -> usize {
+        self.strings.len()
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.strings.is_empty()
+    }
+
+    /// Frees every heap buffer held by the interner.
+    ///
+    /// # Safety
+    /// No `GermanStr` returned by `self.intern` with a heap buffer should be
+    /// used after calling this method.
+    pub unsafe fn free(self) {
+        for s in self.strings {
+            unsafe {
+                // Safety:
+                // 1. only heap-allocated strings are stored in the interner.
+                // 2. each buffer is stored exactly once.
+                s.free();
+            }
+        }
+    }
+}
+
+#[inline]
+/// Returns the first 4 bytes of a string.
+/// If the string has less than 4 bytes, extra bytes are set to 0.
+pub fn str_prefix<T>(src: impl AsRef<str>) -> [u8; 4] {
+    bytes_prefix(src.as_ref().as_bytes())
+}
+
+#[cfg(not(feature = "itoa"))]
+/// Writes the base 10 representation of an integer at the end of `buf`,
+/// and returns it. 40 bytes fit `i128::MIN`.
+fn int_to_str(mut magnitude: u128, negative: bool, buf: &mut [u8; 40]) -> &str {
+    let mut start = buf.len();
+    // 128-bit divisions are much slower, only use them for the top digits.
+    while magnitude > u64::MAX as u128 {
+        start -= 1;
+        buf[start] = b'0' + (magnitude % 10) as u8;
+        magnitude /= 10;
+    }
+    let mut n = magnitude as u64;
+    loop {
+        start -= 1;
+        buf[start] = b'0' + (n % 10) as u8;
+        n /= 10;
+        if n == 0 {
+            break;
+        }
+    }
+    if negative {
+        start -= 1;
+        buf[start] = b'-';
+    }
+    unsafe {
+        // Safety: only ASCII digits and '-' were written.
+        core::str::from_utf8_unchecked(&buf[start..])
+    }
+}
+
+#[inline]
+/// Validates that `bytes` is UTF-8.
+pub(crate) fn str_from_utf8(bytes: &[u8]) -> Result<&str, InitError> {
+    #[cfg(feature = "simdutf8")]
+    if let Ok(s) = simdutf8::basic::from_utf8(bytes) {
+        return Ok(s);
+    }
+    // Without simdutf8, or to get the detailed error when the SIMD
+    // validation failed.
+    core::str::from_utf8(bytes).map_err(InitError::InvalidUtf8)
+}
+
+#[inline]
+/// Counts the chars of a long string.
+fn count_chars(s: &str) -> usize {
+    #[cfg(feature = "bytecount")]
+    return bytecount::num_chars(s.as_bytes());
+    // The standard library already counts the non-continuation bytes a word
+    // at a time.
+    #[cfg(not(feature = "bytecount"))]
+    s.chars().count()
+}
+
+#[inline(always)]
+/// Returns the layout of a heap buffer of `len` bytes, aligned to
+/// `MAX_BUFFER_ALIGN` if `aligned` has the `ALIGNED_PTR` bit set, and to 1
+/// otherwise.
+const fn buffer_layout(len: u32, aligned: usize) -> Layout {
+    let align = if aligned & ALIGNED_PTR != 0 { MAX_BUFFER_ALIGN } else { 1 };
+    unsafe {
+        // Safety: align is a power of two, and len is too short to overflow
+        // isize::MAX once rounded up to it.
+        Layout::from_size_align_unchecked(len as usize, align)
+    }
+}
+
+#[inline]
+/// Returns the length of the longest common prefix of `lhs` and `rhs`,
+/// comparing them 8 bytes at a time.
+fn common_prefix_len(lhs: &[u8], rhs: &[u8]) -> usize {
+    let len = lhs.len().min(rhs.len());
+    let (lhs, rhs) = (&lhs[..len], &rhs[..len]);
+    let words = lhs.chunks_exact(8).zip(rhs.chunks_exact(8));
+    for (i, (lhs_word, rhs_word)) in words.enumerate() {
+        let lhs_word = u64::from_le_bytes(lhs_word.try_into().expect("chunks are 8 bytes long"));
+        let rhs_word = u64::from_le_bytes(rhs_word.try_into().expect("chunks are 8 bytes long"));
+        let diff = lhs_word ^ rhs_word;
+        if diff != 0 {
+            return i * 8 + diff.trailing_zeros() as usize / 8;
+        }
+    }
+    let start = len - len % 8;
+    start + lhs[start..].iter().zip(&rhs[start..]).take_while(|(l, r)| l == r).count()
+}
+
+/// The high bit of every byte of an u64.
+const HIGH_BITS: u64 = 0x8080_8080_8080_8080;
+
+#[inline(always)]
+/// Loads a prefix as an integer which compares like the bytes of the
+/// prefix: its first byte is the most significant one, whatever the byte
+/// order of the platform (the bytes are swapped on little-endian ones).
+pub(crate) const fn be_u32(prefix: [u8; 4]) -> u32 {
+    u32::from_be_bytes(prefix)
+}
+
+#[inline(always)]
+/// Returns the number of UTF-8 continuation bytes (0b10xxxxxx) in the 8 bytes
+/// of `word`: their high bit is set, and the next one isn't.
+/// Bits are only compared within a byte, so the byte order of `word`
+/// doesn't matter.
+const fn count_continuation_bytes(word: u64) -> usize {
+    (word & !(word << 1) & HIGH_BITS).count_ones() as usize
+}
+
+#[inline]
+/// Returns the `ASCII_PTR` bit to store in the heap pointer of `bytes`.
+pub(crate) fn ascii_bit(bytes: &[u8]) -> usize {
+    if bytes.is_ascii() {
+        ASCII_PTR
+    } else {
+        0
+    }
+}
+
+#[inline]
+/// Returns the first 4 bytes of a byte slice.
+/// If the slice has less than 4 bytes, extra bytes are set to 0.
+pub(crate) fn bytes_prefix(src: &[u8]) -> [u8; 4] {
+    let prefix_len = src.len().min(4);
+    let mut bytes = [0; 4];
+    bytes[..prefix_len].copy_from_slice(&src[..prefix_len]);
+    bytes
+}
+
+#[inline]
+/// Returns a slice to every byte of a string, skipping the first 4.
+pub fn str_suffix<T>(src: &impl AsRef<str>) -> &[u8] {
+    src.as_ref().as_bytes().get(4..).unwrap_or_default()
+}
+
+/// Almost identical to `ToString`, but converts to `GermanStr` instead.
+///
+/// This goes through `core::fmt`: integers (and floats, with the `ryu`
+/// feature) are faster to convert with their `From` impls.
+pub trait ToGermanStr {
+    fn to_german_str(&self) -> GermanStr;
+}
+
+/// A `fmt::Write` implementation building a `GermanStr`, which only
+/// allocates once more than `MAX_INLINE_BYTES` bytes are written.
+///
+/// Used by `format_german_str!` and `try_format_german_str!`.
+pub struct Writer {
+    len: usize,
+    inline: [u8; MAX_INLINE_BYTES],
+    heap: String,
+}
+
+impl Writer {
+    #[must_use]
+    #[allow(clippy::new_without_default)]
+    pub const fn new() -> Self {
+        Writer {
+            len: 0,
+            inline: [0; MAX_INLINE_BYTES],
+            heap: String::new(),
+        }
+    }
+
+    fn push_str(&mut self, s: &str) -> Result<(), InitError> {
+        let old_len = self.len;
+        self.len += s.len();
+        if self.len > MAX_LEN {
+            return Err(InitError::TooLong);
+        }
+        if self.len <= MAX_INLINE_BYTES {
+            // we are still inline after the write
+            self.inline[old_len..self.len].copy_from_slice(s.as_bytes());
+        } else if old_len <= MAX_INLINE_BYTES {
+            // we need to switch from inline to heap
+            self.heap.reserve(self.len);
+            unsafe {
+                // SAFETY: inline data is guaranteed to be valid utf8 for previously
+                // written bytes since this is the only &mut method and we write an
+                // entire &str at each call, which is valid UTF8 bytes.
+                self.heap
+                    .as_mut_vec()
+                    .extend_from_slice(&self.inline[..old_len]);
+            }
+            self.heap.push_str(s);
+        } else {
+            self.heap.push_str(s);
+        }
+        Ok(())
+    }
+
+    /// Returns the written string, or `InitError::TooLong` if more than
+    /// `MAX_LEN` bytes were written.
+    pub fn finish(self) -> Result<GermanStr, InitError> {
+        if self.len > MAX_LEN {
+            return Err(InitError::TooLong);
+        }
+        if self.len <= MAX_INLINE_BYTES {
+            let mut prefix = [0; 4];
+            prefix.clone_from_slice(&self.inline[0..4]);
+            let mut last8 = [0; 8];
+            last8.clone_from_slice(&self.inline[4..MAX_INLINE_BYTES]);
+            Ok(GermanStr {
+                len: Len::new(self.len),
+                prefix,
+                last8: Last8 { buf: last8 },
+            })
+        } else {
+            // Shrinking the buffer avoids copying the str in most cases, and
+            // is required so that it is freed with the right layout.
+            let buf = self.heap.into_boxed_str().into_boxed_bytes();
+            Ok(unsafe {
+                // Safety: buf comes from a String, and its len was checked.
+                GermanStr::from_boxed_bytes_unchecked(buf)
+            })
+        }
+    }
+}
+
+impl fmt::Write for Writer {
+    #[inline]
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.push_str(s)
+            .map_err(|_| fmt::Error)
+    }
+}
+
+/// Formats arguments to a [`GermanStr`], potentially without allocating.
+///
+/// See [`alloc::format!`] or [`format_args!`] for syntax documentation.
+#[macro_export]
+macro_rules! format_german_str {
+    ($($tt:tt)*) => {{
+        use ::core::fmt::Write;
+        let mut w = $crate::Writer::new();
+        w.write_fmt(format_args!($($tt)*))
+            .expect("tried to format_german_str a GermanStr bigger than the maximum GermanStr size");
+        $crate::GermanStr::from(w)
+    }};
+}
+
+/// Same as [`format_german_str!`], but returns `InitError::TooLong` instead
+/// of panicking when the output is bigger than `MAX_LEN`.
+///
+/// Like `alloc::format!`, it still panics if a formatting trait
+/// implementation returns an error by itself.
+/// ```
+/// use german_str::try_format_german_str;
+///
+/// let id = 42;
+/// let key = try_format_german_str!("user:{id}").unwrap();
+/// assert_eq!(key, "user:42");
+/// assert!(key.is_inlined());
+/// ```
+#[macro_export]
+macro_rules! try_format_german_str {
+    ($($tt:tt)*) => {{
+        use ::core::fmt::Write;
+        let mut w = $crate::Writer::new();
+        let written = w.write_fmt(format_args!($($tt)*));
+        match w.finish() {
+            Ok(s) => {
+                written.expect("a formatting trait implementation returned an error");
+                Ok(s)
+            }
+            Err(e) => Err(e),
+        }
+    }};
+}
+
+/// Creates a `GermanStr` from a string literal, at compile time.
+///
+/// Literals of at most `MAX_INLINE_BYTES` bytes are inlined, longer ones
+/// reference the literal without allocating (see `GermanStr::from_static`).
+/// The result can be used to initialize `const`s and `static`s.
+/// ```
+/// use german_str::{gstr, GermanStr};
+///
+/// static KEYWORDS: [GermanStr; 2] = [gstr!("fn"), gstr!("a keyword longer than 12 bytes")];
+/// assert_eq!(KEYWORDS[1], "a keyword longer than 12 bytes");
+/// ```
+#[macro_export]
+macro_rules! gstr {
+    ($s:expr) => {{
+        const S: $crate::GermanStr = $crate::GermanStr::from_static($s);
+        S
+    }};
+}
+
+impl From<Writer> for GermanStr {
+    #[inline]
+    /// Panics if more than `MAX_LEN` bytes were written, see
+    /// `Writer::finish` for a fallible version.
+    fn from(value: Writer) -> Self {
+        value.finish()
+            .expect("tried to create a GermanStr bigger than the maximum GermanStr size")
+    }
+}
+
+impl<T> ToGermanStr for T
+where
+    T: fmt::Display + ?Sized,
+{
+    fn to_german_str(&self) -> GermanStr {
+        format_german_str!("{}", self)
+    }
+}
+
+#[cfg(any(feature = "arbitrary", feature = "proptest"))]
+/// Builds a string of exactly `len` bytes out of `chars`, padding it with
+/// ASCII bytes when the next char wouldn't fit.
+pub(crate) fn fill_to_len(chars: &[char], len: usize) -> String {
+    let mut s = String::with_capacity(len);
+    for c in chars {
+        if s.len() + c.len_utf8() > len {
+            break;
+        }
+        s.push(*c);
+    }
+    while s.len() < len {
+        s.push('a');
+    }
+    s
+}
@@ -0,0 +1,24 @@
+use get_size::GetSize;
+
+use crate::{GermanStr, GermanStrVec};
+
+/// Shared buffers aren't counted, to avoid counting them once per
+/// string: they are counted by their owner.
+impl GetSize for GermanStr {
+    #[inline]
+    fn get_heap_size(&self) -> usize {
+        if self.has_shared_buffer() {
+            0
+        } else {
+            self.heap_bytes()
+        }
+    }
+}
+
+/// Counts the vector of strings, and the buffer shared by them.
+impl GetSize for GermanStrVec {
+    #[inline]
+    fn get_heap_size(&self) -> usize {
+        self.strings.capacity() * GermanStr::get_stack_size() + self.data.capacity()
+    }
+}
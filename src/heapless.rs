@@ -0,0 +1,41 @@
+use heapless::{CapacityError, LenType, String};
+
+use crate::{GermanStr, InitError};
+
+impl<const N: usize, LenT: LenType> TryFrom<String<N, LenT>> for GermanStr {
+    type Error = InitError;
+
+    #[inline]
+    fn try_from(s: String<N, LenT>) -> Result<GermanStr, InitError> {
+        GermanStr::new(s.as_str())
+    }
+}
+
+impl<const N: usize, LenT: LenType> TryFrom<&String<N, LenT>> for GermanStr {
+    type Error = InitError;
+
+    #[inline]
+    fn try_from(s: &String<N, LenT>) -> Result<GermanStr, InitError> {
+        GermanStr::new(s.as_str())
+    }
+}
+
+/// Fails if the string is longer than the capacity `N`.
+impl<const N: usize, LenT: LenType> TryFrom<GermanStr> for String<N, LenT> {
+    type Error = CapacityError;
+
+    #[inline]
+    fn try_from(s: GermanStr) -> Result<String<N, LenT>, CapacityError> {
+        String::try_from(s.as_str())
+    }
+}
+
+/// Fails if the string is longer than the capacity `N`.
+impl<const N: usize, LenT: LenType> TryFrom<&GermanStr> for String<N, LenT> {
+    type Error = CapacityError;
+
+    #[inline]
+    fn try_from(s: &GermanStr) -> Result<String<N, LenT>, CapacityError> {
+        String::try_from(s.as_str())
+    }
+}
@@ -0,0 +1,54 @@
+use alloc::borrow::Cow;
+
+use heed_traits::{BoxedError, BytesDecode, BytesEncode};
+
+use crate::{GermanStr, GermanStrRef};
+
+/// A heed codec storing `GermanStr`s as their UTF-8 bytes.
+///
+/// LMDB sorts keys by their bytes by default, which matches the order
+/// of `GermanStr`. Decoding validates the UTF-8, and copies the string
+/// unless it can be inlined: use `GermanStrRefCodec` to borrow it from
+/// the database instead.
+pub enum GermanStrCodec {}
+
+impl<'a> BytesEncode<'a> for GermanStrCodec {
+    type EItem = GermanStr;
+
+    #[inline]
+    fn bytes_encode(item: &'a GermanStr) -> Result<Cow<'a, [u8]>, BoxedError> {
+        Ok(Cow::Borrowed(item.as_bytes()))
+    }
+}
+
+impl BytesDecode<'_> for GermanStrCodec {
+    type DItem = GermanStr;
+
+    #[inline]
+    fn bytes_decode(bytes: &[u8]) -> Result<GermanStr, BoxedError> {
+        Ok(GermanStr::new(crate::str_from_utf8(bytes)?)?)
+    }
+}
+
+/// A heed codec storing strings like `GermanStrCodec`, but decoding
+/// them into `GermanStrRef`s borrowing the memory map of the database,
+/// without any copy.
+pub enum GermanStrRefCodec {}
+
+impl<'a> BytesEncode<'a> for GermanStrRefCodec {
+    type EItem = GermanStrRef<'a>;
+
+    #[inline]
+    fn bytes_encode(item: &'a GermanStrRef<'a>) -> Result<Cow<'a, [u8]>, BoxedError> {
+        Ok(Cow::Borrowed(item.as_bytes()))
+    }
+}
+
+impl<'a> BytesDecode<'a> for GermanStrRefCodec {
+    type DItem = GermanStrRef<'a>;
+
+    #[inline]
+    fn bytes_decode(bytes: &'a [u8]) -> Result<GermanStrRef<'a>, BoxedError> {
+        Ok(GermanStrRef::new(crate::str_from_utf8(bytes)?)?)
+    }
+}
@@ -0,0 +1,58 @@
+use http::header::{InvalidHeaderName, InvalidHeaderValue};
+use http::{HeaderName, HeaderValue};
+
+use crate::{GermanStr, InitError};
+
+/// Header values may contain non-ASCII bytes: they are accepted as long
+/// as they are valid UTF-8, unlike with `HeaderValue::to_str`.
+impl TryFrom<&HeaderValue> for GermanStr {
+    type Error = InitError;
+
+    #[inline]
+    fn try_from(value: &HeaderValue) -> Result<GermanStr, InitError> {
+        GermanStr::new(crate::str_from_utf8(value.as_bytes())?)
+    }
+}
+
+impl TryFrom<GermanStr> for HeaderValue {
+    type Error = InvalidHeaderValue;
+
+    #[inline]
+    fn try_from(s: GermanStr) -> Result<HeaderValue, InvalidHeaderValue> {
+        HeaderValue::from_str(s.as_str())
+    }
+}
+
+impl TryFrom<&GermanStr> for HeaderValue {
+    type Error = InvalidHeaderValue;
+
+    #[inline]
+    fn try_from(s: &GermanStr) -> Result<HeaderValue, InvalidHeaderValue> {
+        HeaderValue::from_str(s.as_str())
+    }
+}
+
+impl From<&HeaderName> for GermanStr {
+    #[inline]
+    fn from(name: &HeaderName) -> GermanStr {
+        GermanStr::new(name.as_str()).expect("header names are at most 64KB long")
+    }
+}
+
+impl TryFrom<GermanStr> for HeaderName {
+    type Error = InvalidHeaderName;
+
+    #[inline]
+    fn try_from(s: GermanStr) -> Result<HeaderName, InvalidHeaderName> {
+        HeaderName::from_bytes(s.as_bytes())
+    }
+}
+
+impl TryFrom<&GermanStr> for HeaderName {
+    type Error = InvalidHeaderName;
+
+    #[inline]
+    fn try_from(s: &GermanStr) -> Result<HeaderName, InvalidHeaderName> {
+        HeaderName::from_bytes(s.as_bytes())
+    }
+}
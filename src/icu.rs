@@ -0,0 +1,32 @@
+use core::cmp::Ordering;
+
+use icu_collator::Collator;
+
+use crate::GermanStr;
+
+impl GermanStr {
+    #[inline]
+    /// Compares `self` and `other` according to the locale-aware rules of
+    /// `collator`, instead of byte-wise like `Ord::cmp`.
+    pub fn collate_cmp(&self, other: &GermanStr, collator: &Collator) -> Ordering {
+        if self == other {
+            return Ordering::Equal;
+        }
+        collator.compare(self.as_str(), other.as_str())
+    }
+}
+
+/// Returns a comparator ordering `GermanStr`s with `collator`, which can be
+/// used with `sort_by` and similar methods.
+/// ```
+/// use german_str::{collation_order, GermanStr};
+/// use icu_collator::{Collator, CollatorOptions};
+///
+/// let collator = Collator::try_new(&Default::default(), CollatorOptions::new()).unwrap();
+/// let mut words = ["Zug", "Äpfel", "Apfel"].map(|s| GermanStr::new(s).unwrap());
+/// words.sort_by(collation_order(&collator));
+/// assert_eq!(words, ["Apfel", "Äpfel", "Zug"]);
+/// ```
+pub fn collation_order(collator: &Collator) -> impl Fn(&GermanStr, &GermanStr) -> Ordering + '_ {
+    move |lhs, rhs| lhs.collate_cmp(rhs, collator)
+}
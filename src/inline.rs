@@ -0,0 +1,227 @@
+use core::borrow::Borrow;
+use core::cmp;
+use core::fmt;
+use core::ops::Deref;
+use core::str::FromStr;
+
+use crate::{InitError, MAX_INLINE_BYTES};
+#[cfg(feature = "alloc")]
+use crate::{GermanStr, Last8, Len};
+
+/// A string of at most `MAX_INLINE_BYTES` bytes, which never allocates.
+///
+/// It stores its bytes like an inlined `GermanStr`, but is `Copy` and can
+/// be created in `const` contexts. Converting it to a `GermanStr` never
+/// allocates. Its layout differs from the one of `GermanStr`, which stores
+/// the complement of its length: don't transmute one into the other.
+///
+/// Unlike `GermanStr`, it is available without the `alloc` feature.
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[repr(C)]
+pub struct InlineGermanStr {
+    /// Number of bytes of the string, at most `MAX_INLINE_BYTES`.
+    len: u32,
+
+    /// The bytes of the string. Extra bytes are set to 0.
+    buf: [u8; MAX_INLINE_BYTES],
+}
+
+impl InlineGermanStr {
+    #[inline]
+    /// Creates an `InlineGermanStr`, failing if `src` is longer than
+    /// `MAX_INLINE_BYTES`.
+    pub const fn new(src: &str) -> Result<Self, InitError> {
+        if src.len() > MAX_INLINE_BYTES {
+            return Err(InitError::TooLongForInline);
+        }
+        let mut buf = [0; MAX_INLINE_BYTES];
+        let mut i = 0;
+        while i < src.len() {
+            buf[i] = src.as_bytes()[i];
+            i += 1;
+        }
+        Ok(InlineGermanStr {
+            len: src.len() as u32,
+            buf,
+        })
+    }
+
+    #[inline(always)]
+    pub const fn as_str(&self) -> &str {
+        let (bytes, _) = self.buf.split_at(self.len as usize);
+        unsafe {
+            // Safety: the first `self.len` bytes of `buf` were copied from a &str.
+            core::str::from_utf8_unchecked(bytes)
+        }
+    }
+
+    #[inline(always)]
+    pub const fn len(&self) -> usize {
+        self.len as usize
+    }
+
+    #[inline(always)]
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    #[inline(always)]
+    /// Returns an array containing the first 4 bytes of the string.
+    /// If the string is shorter than 4 bytes, extra bytes are set to 0.
+    pub const fn prefix_bytes_array(&self) -> [u8; 4] {
+        [self.buf[0], self.buf[1], self.buf[2], self.buf[3]]
+    }
+
+    #[cfg(feature = "alloc")]
+    #[inline]
+    /// Converts `self` into a `GermanStr`, without allocating.
+    pub const fn to_german_str(self) -> GermanStr {
+        let buf = self.buf;
+        GermanStr {
+            len: Len::new(self.len as usize),
+            prefix: [buf[0], buf[1], buf[2], buf[3]],
+            last8: Last8 {
+                buf: [buf[4], buf[5], buf[6], buf[7], buf[8], buf[9], buf[10], buf[11]],
+            },
+        }
+    }
+}
+
+impl Default for InlineGermanStr {
+    #[inline(always)]
+    fn default() -> Self {
+        InlineGermanStr {
+            len: 0,
+            buf: [0; MAX_INLINE_BYTES],
+        }
+    }
+}
+
+impl Deref for InlineGermanStr {
+    type Target = str;
+
+    #[inline(always)]
+    fn deref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl AsRef<str> for InlineGermanStr {
+    #[inline(always)]
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl Borrow<str> for InlineGermanStr {
+    #[inline(always)]
+    fn borrow(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl Ord for InlineGermanStr {
+    #[inline]
+    fn cmp(&self, other: &Self) -> cmp::Ordering {
+        // Extra bytes are set to 0, which sorts before any other byte, so
+        // comparing the buffers gives the string order unless one string is
+        // the other one followed by '\0's, in which case the longest is greater.
+        self.buf
+            .cmp(&other.buf)
+            .then_with(|| self.len.cmp(&other.len))
+    }
+}
+
+impl PartialOrd for InlineGermanStr {
+    #[inline(always)]
+    fn partial_cmp(&self, other: &Self) -> Option<cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl core::hash::Hash for InlineGermanStr {
+    #[inline(always)]
+    fn hash<H: core::hash::Hasher>(&self, hasher: &mut H) {
+        self.as_str().hash(hasher);
+    }
+}
+
+impl fmt::Debug for InlineGermanStr {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Debug::fmt(self.as_str(), f)
+    }
+}
+
+impl fmt::Display for InlineGermanStr {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(self.as_str(), f)
+    }
+}
+
+impl FromStr for InlineGermanStr {
+    type Err = InitError;
+
+    #[inline]
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        InlineGermanStr::new(s)
+    }
+}
+
+impl TryFrom<&str> for InlineGermanStr {
+    type Error = InitError;
+
+    #[inline]
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        InlineGermanStr::new(s)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl TryFrom<&GermanStr> for InlineGermanStr {
+    type Error = InitError;
+
+    #[inline]
+    fn try_from(s: &GermanStr) -> Result<Self, Self::Error> {
+        InlineGermanStr::new(s.as_str())
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl From<InlineGermanStr> for GermanStr {
+    #[inline(always)]
+    fn from(s: InlineGermanStr) -> Self {
+        s.to_german_str()
+    }
+}
+
+impl PartialEq<str> for InlineGermanStr {
+    #[inline(always)]
+    fn eq(&self, other: &str) -> bool {
+        self.as_str() == other
+    }
+}
+
+impl PartialEq<&str> for InlineGermanStr {
+    #[inline(always)]
+    fn eq(&self, other: &&str) -> bool {
+        self.as_str() == *other
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl PartialEq<GermanStr> for InlineGermanStr {
+    #[inline(always)]
+    fn eq(&self, other: &GermanStr) -> bool {
+        self.len == other.len.get() && self.as_str() == other.as_str()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl PartialEq<InlineGermanStr> for GermanStr {
+    #[inline(always)]
+    fn eq(&self, other: &InlineGermanStr) -> bool {
+        other == self
+    }
+}
@@ -0,0 +1,100 @@
+use core::hash::{BuildHasher, BuildHasherDefault};
+use core::sync::atomic::{AtomicU64, Ordering};
+use std::collections::HashSet;
+use std::hash::DefaultHasher;
+use std::sync::{Mutex, MutexGuard};
+
+use crate::{GermanStr, InitError, MAX_INLINE_BYTES};
+
+type Hasher = BuildHasherDefault<DefaultHasher>;
+
+/// Number of independently locked parts of the intern table.
+const SHARDS: usize = 32;
+
+/// Every string is heap-allocated, with a shared buffer.
+static TABLE: [Mutex<HashSet<GermanStr, Hasher>>; SHARDS] =
+    [const { Mutex::new(HashSet::with_hasher(Hasher::new())) }; SHARDS];
+
+static HITS: AtomicU64 = AtomicU64::new(0);
+static MISSES: AtomicU64 = AtomicU64::new(0);
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+/// Statistics about the global intern table, see `GermanStr::intern_stats`.
+pub struct InternStats {
+    /// Number of distinct strings in the table.
+    pub strings: usize,
+    /// Total size of their heap buffers.
+    pub bytes: usize,
+    /// Number of calls to `GermanStr::intern` which reused a buffer.
+    pub hits: u64,
+    /// Number of calls to `GermanStr::intern` which allocated a buffer.
+    pub misses: u64,
+}
+
+fn shard(src: &str) -> MutexGuard<'static, HashSet<GermanStr, Hasher>> {
+    let idx = Hasher::new().hash_one(src) as usize % SHARDS;
+    // A panic can't leave the table in an inconsistent state.
+    TABLE[idx].lock().unwrap_or_else(|e| e.into_inner())
+}
+
+impl GermanStr {
+    /// Returns a `GermanStr` equal to `src`, which shares its heap buffer
+    /// with every other equal string interned in the process.
+    ///
+    /// Strings that can be inlined are returned as is. The buffers stay
+    /// allocated until `GermanStr::clear_interned` is called.
+    pub fn intern(src: &str) -> Result<GermanStr, InitError> {
+        if src.len() <= MAX_INLINE_BYTES {
+            return Ok(GermanStr::new_inline(src));
+        }
+        let mut shard = shard(src);
+        if let Some(interned) = shard.get(src) {
+            HITS.fetch_add(1, Ordering::Relaxed);
+            return Ok(unsafe {
+                // Safety: interned is only dropped by clear_interned.
+                interned.shared_view()
+            });
+        }
+        let mut interned = GermanStr::new(src)?;
+        let copy = interned.leaky_shared_clone();
+        shard.insert(interned);
+        MISSES.fetch_add(1, Ordering::Relaxed);
+        Ok(copy)
+    }
+
+    /// Returns statistics about the global intern table.
+    pub fn intern_stats() -> InternStats {
+        let mut stats = InternStats {
+            hits: HITS.load(Ordering::Relaxed),
+            misses: MISSES.load(Ordering::Relaxed),
+            ..InternStats::default()
+        };
+        for shard in &TABLE {
+            let shard = shard.lock().unwrap_or_else(|e| e.into_inner());
+            stats.strings += shard.len();
+            stats.bytes += shard.iter().map(GermanStr::len).sum::<usize>();
+        }
+        stats
+    }
+
+    /// Frees every buffer of the global intern table, and resets its
+    /// statistics.
+    ///
+    /// # Safety
+    /// No heap-allocated `GermanStr` returned by `GermanStr::intern`
+    /// should be used after calling this method.
+    pub unsafe fn clear_interned() {
+        for shard in &TABLE {
+            let mut shard = shard.lock().unwrap_or_else(|e| e.into_inner());
+            for s in shard.drain() {
+                unsafe {
+                    // Safety: strings in the table are heap-allocated,
+                    // and each buffer is stored exactly once.
+                    s.free();
+                }
+            }
+        }
+        HITS.store(0, Ordering::Relaxed);
+        MISSES.store(0, Ordering::Relaxed);
+    }
+}
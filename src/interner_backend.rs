@@ -0,0 +1,153 @@
+use alloc::vec::Vec;
+use core::fmt;
+use core::iter::Enumerate;
+use core::marker::PhantomData;
+use core::slice;
+
+use string_interner::backend::Backend;
+use string_interner::{DefaultSymbol, Symbol};
+
+use crate::GermanStr;
+
+/// A `string_interner` backend storing each interned string as a
+/// `GermanStr`.
+///
+/// Strings of at most `MAX_INLINE_BYTES` are stored inline, without any
+/// allocation, and strings interned with `get_or_intern_static` reference
+/// their static buffer. When the backend is used directly, symbols resolve
+/// to `&GermanStr`s with `resolve_german`, which keeps their fast
+/// comparisons.
+/// ```
+/// use german_str::GermanStrBackend;
+/// use string_interner::StringInterner;
+///
+/// let mut interner = StringInterner::<GermanStrBackend>::new();
+/// let short = interner.get_or_intern("short");
+/// let long = interner.get_or_intern_static("a static string longer than 12 bytes");
+/// assert_eq!(interner.get_or_intern("short"), short);
+/// assert_eq!(interner.resolve(long), Some("a static string longer than 12 bytes"));
+/// ```
+pub struct GermanStrBackend<S = DefaultSymbol> {
+    strings: Vec<GermanStr>,
+    marker: PhantomData<fn() -> S>,
+}
+
+impl<S: Symbol> GermanStrBackend<S> {
+    #[inline]
+    /// Returns the string interned as `symbol`, or `None` if `symbol`
+    /// wasn't created by this backend.
+    pub fn resolve_german(&self, symbol: S) -> Option<&GermanStr> {
+        self.strings.get(symbol.to_usize())
+    }
+
+    #[inline]
+    fn push(&mut self, string: GermanStr) -> S {
+        let symbol = S::try_from_usize(self.strings.len()).expect("symbol space exhausted");
+        self.strings.push(string);
+        symbol
+    }
+}
+
+impl<S> Default for GermanStrBackend<S> {
+    #[inline]
+    fn default() -> Self {
+        GermanStrBackend {
+            strings: Vec::new(),
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<S> Clone for GermanStrBackend<S> {
+    #[inline]
+    fn clone(&self) -> Self {
+        GermanStrBackend {
+            strings: self.strings.clone(),
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<S> fmt::Debug for GermanStrBackend<S> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("GermanStrBackend").field("strings", &self.strings).finish()
+    }
+}
+
+impl<S: Symbol> Backend for GermanStrBackend<S> {
+    type Symbol = S;
+    type Iter<'a>
+        = GermanStrBackendIter<'a, S>
+    where
+        Self: 'a;
+
+    #[inline]
+    fn with_capacity(cap: usize) -> Self {
+        GermanStrBackend {
+            strings: Vec::with_capacity(cap),
+            marker: PhantomData,
+        }
+    }
+
+    #[inline]
+    /// Panics if `string` is longer than `MAX_LEN`.
+    fn intern(&mut self, string: &str) -> S {
+        self.push(GermanStr::new(string).expect("interned strings are at most MAX_LEN bytes long"))
+    }
+
+    #[inline]
+    /// Panics if `string` is longer than `MAX_LEN`.
+    fn intern_static(&mut self, string: &'static str) -> S {
+        self.push(GermanStr::from_static(string))
+    }
+
+    #[inline]
+    fn shrink_to_fit(&mut self) {
+        self.strings.shrink_to_fit();
+    }
+
+    #[inline]
+    fn resolve(&self, symbol: S) -> Option<&str> {
+        self.resolve_german(symbol).map(GermanStr::as_str)
+    }
+
+    #[inline]
+    unsafe fn resolve_unchecked(&self, symbol: S) -> &str {
+        unsafe {
+            // Safety: the caller guarantees that symbol was created by this
+            // backend.
+            self.strings.get_unchecked(symbol.to_usize()).as_str()
+        }
+    }
+
+    #[inline]
+    fn iter(&self) -> GermanStrBackendIter<'_, S> {
+        GermanStrBackendIter {
+            strings: self.strings.iter().enumerate(),
+            marker: PhantomData,
+        }
+    }
+}
+
+/// Iterator over the symbols and strings of a `GermanStrBackend`.
+pub struct GermanStrBackendIter<'a, S> {
+    strings: Enumerate<slice::Iter<'a, GermanStr>>,
+    marker: PhantomData<fn() -> S>,
+}
+
+impl<'a, S: Symbol> Iterator for GermanStrBackendIter<'a, S> {
+    type Item = (S, &'a str);
+
+    #[inline]
+    fn next(&mut self) -> Option<(S, &'a str)> {
+        self.strings.next().map(|(i, s)| {
+            (S::try_from_usize(i).expect("symbols were created for every string"), s.as_str())
+        })
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.strings.size_hint()
+    }
+}
@@ -0,0 +1,102 @@
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt;
+use core::ops::Deref;
+
+use crate::{GermanStr, InitError, MAX_LEN};
+
+/// The lines of a string, stored as `GermanStr`s which reference a single
+/// buffer.
+///
+/// The lines longer than `MAX_INLINE_BYTES` are shared views of the buffer,
+/// instead of being copied to the heap one by one. They can only be
+/// borrowed from `GermanStrLines`, which frees the buffer once when dropped:
+/// `clone()` them to get copies that outlive it.
+///
+/// See `MmapStrings::open_lines` to read the lines of a memory-mapped file
+/// instead.
+/// ```
+/// use german_str::GermanStr;
+///
+/// let lines = GermanStr::lines_from("short\na line longer than 12 bytes\r\n").unwrap();
+/// assert_eq!(lines.as_slice(), ["short", "a line longer than 12 bytes"]);
+/// assert!(lines[1].has_shared_buffer());
+/// ```
+pub struct GermanStrLines {
+    /// Declared before `buffer`, so that it is dropped first.
+    strings: Vec<GermanStr>,
+    /// A `String` rather than a `Box<str>`: moving a `Box` asserts that it
+    /// is the only pointer to its buffer, which would invalidate `strings`.
+    buffer: String,
+}
+
+impl GermanStr {
+    /// Splits `text` into lines like `str::lines`, without allocating a
+    /// buffer per line.
+    ///
+    /// `text` is moved to the returned value if it is a `String`, and
+    /// copied otherwise.
+    pub fn lines_from(text: impl Into<String>) -> Result<GermanStrLines, InitError> {
+        let buffer = text.into();
+        let mut strings = Vec::new();
+        for line in buffer.lines() {
+            if line.len() > MAX_LEN {
+                return Err(InitError::TooLong);
+            }
+            strings.push(unsafe {
+                // Safety: the buffer isn't mutated, and outlives the
+                // strings, which can't be moved out of GermanStrLines.
+                GermanStr::new_shared(line)
+            });
+        }
+        Ok(GermanStrLines { strings, buffer })
+    }
+}
+
+impl GermanStrLines {
+    #[cfg(feature = "std")]
+    /// Reads the file at `path`, and splits it into lines.
+    ///
+    /// Fails with `io::ErrorKind::InvalidData` if the file isn't valid
+    /// UTF-8, or if a line is longer than `MAX_LEN`.
+    pub fn read_file(path: impl AsRef<std::path::Path>) -> std::io::Result<Self> {
+        use alloc::string::ToString as _;
+        use std::io;
+
+        let text = std::fs::read_to_string(path)?;
+        GermanStr::lines_from(text).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e.to_string()))
+    }
+
+    #[inline(always)]
+    pub fn as_slice(&self) -> &[GermanStr] {
+        &self.strings
+    }
+
+    #[inline(always)]
+    /// Returns the whole text the lines were read from.
+    pub fn buffer(&self) -> &str {
+        &self.buffer
+    }
+
+    #[inline]
+    /// Copies the lines, so that they can outlive the buffer.
+    pub fn to_vec(&self) -> Vec<GermanStr> {
+        self.strings.to_vec()
+    }
+}
+
+impl Deref for GermanStrLines {
+    type Target = [GermanStr];
+
+    #[inline(always)]
+    fn deref(&self) -> &[GermanStr] {
+        self.as_slice()
+    }
+}
+
+impl fmt::Debug for GermanStrLines {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Debug::fmt(self.as_slice(), f)
+    }
+}
@@ -0,0 +1,29 @@
+use wr_malloc_size_of::{MallocShallowSizeOf, MallocSizeOf, MallocSizeOfOps};
+
+use crate::{GermanStr, GermanStrVec};
+
+/// Only owned heap buffers are measured: shared buffers are measured by
+/// their owner, and may not point to the start of an allocation.
+/// Foreign buffers aren't measured, since they may not have been
+/// allocated by the global allocator.
+impl MallocSizeOf for GermanStr {
+    #[inline]
+    fn size_of(&self, ops: &mut MallocSizeOfOps) -> usize {
+        match self.heap_ptr() {
+            Some(ptr) if !self.has_shared_buffer() && !self.has_foreign_buffer() => unsafe {
+                // Safety: owned buffers are allocated by the global
+                // allocator, and ptr is the start of the allocation.
+                ops.malloc_size_of(ptr.as_ptr())
+            },
+            _ => 0,
+        }
+    }
+}
+
+/// Measures the vector of strings, and the buffer shared by them.
+impl MallocSizeOf for GermanStrVec {
+    #[inline]
+    fn size_of(&self, ops: &mut MallocSizeOfOps) -> usize {
+        self.strings.shallow_size_of(ops) + self.data.shallow_size_of(ops)
+    }
+}
@@ -0,0 +1,111 @@
+use core::sync::atomic::{AtomicUsize, Ordering};
+
+static ALLOCS: AtomicUsize = AtomicUsize::new(0);
+static FREES: AtomicUsize = AtomicUsize::new(0);
+static ALLOCATED_BYTES: AtomicUsize = AtomicUsize::new(0);
+static FREED_BYTES: AtomicUsize = AtomicUsize::new(0);
+static SHARED_REUSES: AtomicUsize = AtomicUsize::new(0);
+
+/// A snapshot of the global allocation counters of `GermanStr`, enabled by
+/// the `metrics` feature.
+///
+/// The counters are updated with relaxed atomic operations: a snapshot
+/// taken while other threads allocate may be slightly inconsistent.
+/// ```
+/// use german_str::{AllocMetrics, GermanStr};
+///
+/// let before = AllocMetrics::snapshot();
+/// let mut owner = GermanStr::new("longer than 12 bytes").unwrap();
+/// let shared = owner.leaky_shared_clone();
+/// let _short = GermanStr::new("inlined").unwrap();
+/// let diff = AllocMetrics::snapshot().since(&before);
+/// # // Other tests may run concurrently.
+/// assert!(diff.allocs >= 1);
+/// assert!(diff.allocated_bytes >= 20);
+/// assert!(diff.shared_reuses >= 1);
+/// # unsafe { shared.free() };
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct AllocMetrics {
+    /// Number of owned heap buffers allocated, or adopted from a `String`,
+    /// `Vec<u8>` or `Box<str>`.
+    pub allocs: usize,
+
+    /// Number of owned heap buffers freed. Buffers given away without
+    /// being freed (by `GermanStr::leak`, or when converted to `Bytes`
+    /// without copying) aren't counted.
+    pub frees: usize,
+
+    /// Total len of the buffers counted in `allocs`.
+    pub allocated_bytes: usize,
+
+    /// Total len of the buffers counted in `frees`.
+    pub freed_bytes: usize,
+
+    /// Number of heap-allocated strings created as shared views of an
+    /// existing buffer instead of allocating a copy (by
+    /// `GermanStr::leaky_shared_clone`, `SharedBufferRegistry`,
+    /// `GermanStrDict`...).
+    pub shared_reuses: usize,
+}
+
+impl AllocMetrics {
+    /// Returns the current values of the global counters.
+    pub fn snapshot() -> AllocMetrics {
+        AllocMetrics {
+            allocs: ALLOCS.load(Ordering::Relaxed),
+            frees: FREES.load(Ordering::Relaxed),
+            allocated_bytes: ALLOCATED_BYTES.load(Ordering::Relaxed),
+            freed_bytes: FREED_BYTES.load(Ordering::Relaxed),
+            shared_reuses: SHARED_REUSES.load(Ordering::Relaxed),
+        }
+    }
+
+    /// Resets the global counters to 0, and returns their previous values.
+    pub fn reset() -> AllocMetrics {
+        AllocMetrics {
+            allocs: ALLOCS.swap(0, Ordering::Relaxed),
+            frees: FREES.swap(0, Ordering::Relaxed),
+            allocated_bytes: ALLOCATED_BYTES.swap(0, Ordering::Relaxed),
+            freed_bytes: FREED_BYTES.swap(0, Ordering::Relaxed),
+            shared_reuses: SHARED_REUSES.swap(0, Ordering::Relaxed),
+        }
+    }
+
+    #[inline]
+    /// Returns the counts of events which happened between `earlier` and
+    /// `self`.
+    pub fn since(&self, earlier: &AllocMetrics) -> AllocMetrics {
+        AllocMetrics {
+            allocs: self.allocs.wrapping_sub(earlier.allocs),
+            frees: self.frees.wrapping_sub(earlier.frees),
+            allocated_bytes: self.allocated_bytes.wrapping_sub(earlier.allocated_bytes),
+            freed_bytes: self.freed_bytes.wrapping_sub(earlier.freed_bytes),
+            shared_reuses: self.shared_reuses.wrapping_sub(earlier.shared_reuses),
+        }
+    }
+
+    #[inline]
+    /// Number of owned buffers which were allocated but not freed.
+    pub fn live_allocs(&self) -> usize {
+        self.allocs.wrapping_sub(self.frees)
+    }
+}
+
+#[inline(always)]
+pub(crate) fn record_alloc(len: usize) {
+    ALLOCS.fetch_add(1, Ordering::Relaxed);
+    ALLOCATED_BYTES.fetch_add(len, Ordering::Relaxed);
+}
+
+#[inline(always)]
+pub(crate) fn record_free(len: usize) {
+    FREES.fetch_add(1, Ordering::Relaxed);
+    FREED_BYTES.fetch_add(len, Ordering::Relaxed);
+}
+
+#[inline(always)]
+pub(crate) fn record_shared_reuse() {
+    SHARED_REUSES.fetch_add(1, Ordering::Relaxed);
+}
@@ -0,0 +1,29 @@
+use minicbor::decode::{self, Decoder};
+use minicbor::encode::{self, Encoder, Write};
+use minicbor::{CborLen, Decode, Encode};
+
+use crate::GermanStr;
+
+// `GermanStr`s are encoded as definite-length text strings, like `str`.
+
+impl<C> Encode<C> for GermanStr {
+    #[inline]
+    fn encode<W: Write>(&self, e: &mut Encoder<W>, _: &mut C) -> Result<(), encode::Error<W::Error>> {
+        e.str(self.as_str())?.ok()
+    }
+}
+
+impl<C> CborLen<C> for GermanStr {
+    #[inline]
+    fn cbor_len(&self, ctx: &mut C) -> usize {
+        self.as_str().cbor_len(ctx)
+    }
+}
+
+impl<'b, C> Decode<'b, C> for GermanStr {
+    #[inline]
+    fn decode(d: &mut Decoder<'b>, _: &mut C) -> Result<GermanStr, decode::Error> {
+        let p = d.position();
+        GermanStr::new(d.str()?).map_err(|_| decode::Error::message("string longer than MAX_LEN bytes").at(p))
+    }
+}
@@ -0,0 +1,102 @@
+use alloc::string::ToString as _;
+use alloc::vec::Vec;
+use core::ops::Deref;
+use std::fs::File;
+use std::io;
+use std::path::Path;
+
+use memmap2::Mmap;
+
+use crate::{GermanStr, InitError, MAX_LEN};
+
+/// `GermanStr`s read from a memory-mapped file.
+///
+/// The strings longer than `MAX_INLINE_BYTES` reference the mapping
+/// directly as shared buffers, instead of being copied to the heap. They
+/// can only be borrowed from `MmapStrings`, which keeps the mapping alive:
+/// `clone()` them to get copies that outlive it.
+pub struct MmapStrings {
+    /// Declared before `mmap`, so that it is dropped first.
+    strings: Vec<GermanStr>,
+    mmap: Mmap,
+}
+
+impl MmapStrings {
+    /// Maps the file at `path`, and splits it on `\n` (and `\r\n`).
+    ///
+    /// # Safety
+    /// The file must not be modified or truncated while it is mapped,
+    /// see `memmap2::Mmap`.
+    pub unsafe fn open_lines(path: impl AsRef<Path>) -> io::Result<Self> {
+        let mut strings = unsafe {
+            // Safety: guaranteed by the caller.
+            MmapStrings::open_split(path, b'\n')?
+        };
+        for s in &mut strings.strings {
+            if s.ends_with('\r') {
+                let line = &s[..s.len() - 1];
+                *s = unsafe {
+                    // Safety: line references the mapping.
+                    GermanStr::new_shared(line)
+                };
+            }
+        }
+        Ok(strings)
+    }
+
+    /// Maps the file at `path`, and splits it on every `delimiter` byte.
+    ///
+    /// Fails with `io::ErrorKind::InvalidData` if a string isn't valid
+    /// UTF-8 or is longer than `MAX_LEN`.
+    ///
+    /// # Safety
+    /// The file must not be modified or truncated while it is mapped,
+    /// see `memmap2::Mmap`.
+    pub unsafe fn open_split(path: impl AsRef<Path>, delimiter: u8) -> io::Result<Self> {
+        let file = File::open(path)?;
+        let mmap = unsafe {
+            // Safety: guaranteed by the caller.
+            Mmap::map(&file)?
+        };
+        let mut strings = Vec::new();
+        let mut bytes: &[u8] = &mmap;
+        if bytes.last() == Some(&delimiter) {
+            bytes = &bytes[..bytes.len() - 1];
+        }
+        if !bytes.is_empty() {
+            for part in bytes.split(|b| *b == delimiter) {
+                let s = match crate::str_from_utf8(part) {
+                    Ok(s) if s.len() > MAX_LEN => Err(InitError::TooLong),
+                    res => res,
+                };
+                let s = s.map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e.to_string()))?;
+                strings.push(unsafe {
+                    // Safety: the mapping isn't mutated, and outlives the
+                    // strings, which can't be moved out of self.
+                    GermanStr::new_shared(s)
+                });
+            }
+        }
+        Ok(MmapStrings { strings, mmap })
+    }
+
+    #[inline(always)]
+    pub fn as_slice(&self) -> &[GermanStr] {
+        &self.strings
+    }
+
+    #[inline(always)]
+    /// Returns the whole mapped file.
+    pub fn mapped_bytes(&self) -> &[u8] {
+        &self.mmap
+    }
+}
+
+impl Deref for MmapStrings {
+    type Target = [GermanStr];
+
+    #[inline(always)]
+    fn deref(&self) -> &[GermanStr] {
+        self.as_slice()
+    }
+}
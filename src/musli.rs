@@ -0,0 +1,52 @@
+use core::fmt;
+
+use musli::de::UnsizedVisitor;
+use musli::{Allocator, Context, Decode, Decoder, Encode, Encoder};
+
+use crate::GermanStr;
+
+// `GermanStr`s are encoded like `String`s.
+
+impl<M> Encode<M> for GermanStr {
+    type Encode = str;
+
+    #[inline]
+    fn encode<E>(&self, encoder: E) -> Result<(), E::Error>
+    where
+        E: Encoder<Mode = M>,
+    {
+        self.as_str().encode(encoder)
+    }
+
+    #[inline]
+    fn as_encode(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl<'de, M, A: Allocator> Decode<'de, M, A> for GermanStr {
+    #[inline]
+    fn decode<D>(decoder: D) -> Result<GermanStr, D::Error>
+    where
+        D: Decoder<'de, Mode = M, Allocator = A>,
+    {
+        decoder.decode_string(Visitor)
+    }
+}
+
+struct Visitor;
+
+#[musli::trait_defaults]
+impl<C: Context> UnsizedVisitor<'_, C, str> for Visitor {
+    type Ok = GermanStr;
+
+    #[inline]
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("a string")
+    }
+
+    #[inline]
+    fn visit_ref(self, cx: C, string: &str) -> Result<GermanStr, C::Error> {
+        GermanStr::new(string).map_err(|e| cx.custom(e))
+    }
+}
@@ -0,0 +1,52 @@
+use alloc::format;
+use alloc::string::String;
+
+use napi::bindgen_prelude::{FromNapiValue, ToNapiValue, TypeName, ValidateNapiValue};
+use napi::{sys, Status, ValueType};
+
+use crate::GermanStr;
+
+impl TypeName for GermanStr {
+    #[inline]
+    fn type_name() -> &'static str {
+        "GermanStr"
+    }
+
+    #[inline]
+    fn value_type() -> ValueType {
+        ValueType::String
+    }
+}
+
+impl ValidateNapiValue for GermanStr {}
+
+impl ToNapiValue for GermanStr {
+    #[inline]
+    unsafe fn to_napi_value(env: sys::napi_env, val: Self) -> napi::Result<sys::napi_value> {
+        unsafe {
+            // Safety: the caller guarantees that env is valid.
+            <&str>::to_napi_value(env, val.as_str())
+        }
+    }
+}
+
+impl ToNapiValue for &GermanStr {
+    #[inline]
+    unsafe fn to_napi_value(env: sys::napi_env, val: Self) -> napi::Result<sys::napi_value> {
+        unsafe {
+            // Safety: the caller guarantees that env is valid.
+            <&str>::to_napi_value(env, val.as_str())
+        }
+    }
+}
+
+impl FromNapiValue for GermanStr {
+    unsafe fn from_napi_value(env: sys::napi_env, napi_val: sys::napi_value) -> napi::Result<Self> {
+        let s = unsafe {
+            // Safety: the caller guarantees that env and napi_val are
+            // valid.
+            String::from_napi_value(env, napi_val)?
+        };
+        GermanStr::new(s).map_err(|e| napi::Error::new(Status::InvalidArg, format!("{e}")))
+    }
+}
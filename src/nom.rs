@@ -0,0 +1,111 @@
+//! Parsers consume `GermanStrRef`s, which are as cheap to slice as `&str`s:
+//! owned `GermanStr`s are parsed through `GermanStrRef::from(&german)`, since
+//! their substrings would have to be copied.
+
+use core::str::{CharIndices, Chars, FromStr};
+
+use nom::{AsBytes, Compare, CompareResult, FindSubstring, FindToken, Input, Needed, Offset, ParseTo};
+
+use crate::GermanStrRef;
+
+#[inline(always)]
+fn substr(s: &str) -> GermanStrRef<'_> {
+    GermanStrRef::new(s).expect("substrings are at most MAX_LEN bytes long")
+}
+
+impl<'a> Input for GermanStrRef<'a> {
+    type Item = char;
+    type Iter = Chars<'a>;
+    type IterIndices = CharIndices<'a>;
+
+    #[inline]
+    fn input_len(&self) -> usize {
+        self.len()
+    }
+
+    #[inline]
+    fn take(&self, index: usize) -> Self {
+        substr(&self.as_str()[..index])
+    }
+
+    #[inline]
+    fn take_from(&self, index: usize) -> Self {
+        substr(&self.as_str()[index..])
+    }
+
+    #[inline]
+    fn take_split(&self, index: usize) -> (Self, Self) {
+        let (prefix, suffix) = self.as_str().split_at(index);
+        (substr(suffix), substr(prefix))
+    }
+
+    #[inline]
+    fn position<P>(&self, predicate: P) -> Option<usize>
+    where
+        P: Fn(char) -> bool,
+    {
+        self.as_str().find(predicate)
+    }
+
+    #[inline]
+    fn iter_elements(&self) -> Chars<'a> {
+        self.as_str().chars()
+    }
+
+    #[inline]
+    fn iter_indices(&self) -> CharIndices<'a> {
+        self.as_str().char_indices()
+    }
+
+    #[inline]
+    fn slice_index(&self, count: usize) -> Result<usize, Needed> {
+        self.as_str().slice_index(count)
+    }
+}
+
+impl Offset for GermanStrRef<'_> {
+    #[inline]
+    fn offset(&self, second: &Self) -> usize {
+        self.as_str().offset(second.as_str())
+    }
+}
+
+impl AsBytes for GermanStrRef<'_> {
+    #[inline]
+    fn as_bytes(&self) -> &[u8] {
+        self.as_str().as_bytes()
+    }
+}
+
+impl<'b> Compare<&'b str> for GermanStrRef<'_> {
+    #[inline]
+    fn compare(&self, t: &'b str) -> CompareResult {
+        self.as_str().compare(t)
+    }
+
+    #[inline]
+    fn compare_no_case(&self, t: &'b str) -> CompareResult {
+        self.as_str().compare_no_case(t)
+    }
+}
+
+impl<'b> FindSubstring<&'b str> for GermanStrRef<'_> {
+    #[inline]
+    fn find_substring(&self, substr: &'b str) -> Option<usize> {
+        self.as_str().find(substr)
+    }
+}
+
+impl FindToken<char> for GermanStrRef<'_> {
+    #[inline]
+    fn find_token(&self, token: char) -> bool {
+        self.as_str().contains(token)
+    }
+}
+
+impl<R: FromStr> ParseTo<R> for GermanStrRef<'_> {
+    #[inline]
+    fn parse_to(&self) -> Option<R> {
+        self.as_str().parse().ok()
+    }
+}
@@ -0,0 +1,202 @@
+use core::fmt;
+use core::mem;
+use core::slice;
+
+use crate::{bytes_prefix, GermanStrRef, InitError, MAX_INLINE_BYTES, MAX_LEN};
+
+/// A relocatable string record, whose long strings are stored at an offset
+/// in a base region instead of behind a pointer.
+///
+/// Like a `GermanStr`, it stores its length and first 4 bytes, then either
+/// the rest of the string (padded with 0s) if it's at most 12 bytes long,
+/// or the offset of the whole string in the base region. Since it contains
+/// no pointer, records can be placed in shared memory segments mapped at
+/// different addresses by several processes: `resolve` turns them into
+/// `GermanStrRef`s against the local mapping of the region.
+///
+/// The bytes returned by `to_bytes` have the layout of the records of an
+/// `UmbraColumn`. `size_of::<GermanStrOffset>() == 16`.
+/// ```
+/// use german_str::GermanStrOffset;
+///
+/// let mut region = Vec::new();
+/// let short = GermanStrOffset::new("short", 0).unwrap();
+/// let long = GermanStrOffset::new("a string longer than 12 bytes", region.len()).unwrap();
+/// region.extend_from_slice(b"a string longer than 12 bytes");
+///
+/// assert_eq!(short.resolve(&region).unwrap(), "short");
+/// assert_eq!(long.resolve(&region).unwrap(), "a string longer than 12 bytes");
+/// assert!(long.resolve(&region).unwrap() < short.resolve(&region).unwrap());
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+#[repr(C)]
+pub struct GermanStrOffset {
+    len: u32,
+    prefix: [u8; 4],
+    /// The rest of the string if it is at most `MAX_INLINE_BYTES` long,
+    /// its offset in the base region as a little-endian u64 otherwise.
+    last8: [u8; 8],
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+/// Represents the reasons why resolving a `GermanStrOffset` or a
+/// `PageGermanStr` could fail.
+pub enum ResolveError {
+    /// The string is out of the bounds of the base region.
+    OutOfBounds,
+
+    /// The record is invalid: its string is too long, or doesn't match its
+    /// prefix or padding.
+    InvalidRecord,
+
+    /// The string isn't valid UTF-8.
+    InvalidUtf8,
+}
+
+impl GermanStrOffset {
+    /// Creates a record for `src`, which is stored at `offset` in the base
+    /// region if it can't be inlined. `offset` is ignored otherwise.
+    pub fn new(src: &str, offset: usize) -> Result<Self, InitError> {
+        if src.len() > MAX_LEN {
+            return Err(InitError::TooLong);
+        }
+        let mut last8 = [0; 8];
+        if src.len() <= MAX_INLINE_BYTES {
+            if let Some(suffix) = src.as_bytes().get(4..) {
+                last8[..suffix.len()].copy_from_slice(suffix);
+            }
+        } else {
+            last8 = (offset as u64).to_le_bytes();
+        }
+        Ok(GermanStrOffset {
+            len: src.len() as u32,
+            prefix: bytes_prefix(src.as_bytes()),
+            last8,
+        })
+    }
+
+    #[inline(always)]
+    pub const fn len(&self) -> usize {
+        self.len as usize
+    }
+
+    #[inline(always)]
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    #[inline(always)]
+    /// Returns an array containing the first 4 bytes of the string.
+    /// If the string is shorter than 4 bytes, extra bytes are set to 0.
+    pub const fn prefix_bytes_array(&self) -> [u8; 4] {
+        self.prefix
+    }
+
+    #[inline]
+    /// Returns the offset of the string in the base region, or `None` if
+    /// it is inlined.
+    pub fn offset(&self) -> Option<u64> {
+        (self.len() > MAX_INLINE_BYTES).then(|| u64::from_le_bytes(self.last8))
+    }
+
+    /// Returns the string, reading it from `base` if it isn't inlined.
+    ///
+    /// The record is validated, since it may have been written by another
+    /// process.
+    pub fn resolve<'a>(&'a self, base: &'a [u8]) -> Result<GermanStrRef<'a>, ResolveError> {
+        resolve_record(self.len(), self.inline_bytes(), u64::from_le_bytes(self.last8), base)
+    }
+
+    #[inline(always)]
+    /// Returns the 12 bytes following `len`: the inlined string, padded
+    /// with 0s, for inlined strings.
+    fn inline_bytes(&self) -> &[u8] {
+        unsafe {
+            // Safety: GermanStrOffset is repr(C) without padding, so prefix
+            // is directly followed by last8. The pointer is derived from the
+            // whole struct rather than from self.prefix.
+            let ptr = (self as *const GermanStrOffset).cast::<u8>().add(mem::offset_of!(GermanStrOffset, prefix));
+            slice::from_raw_parts(ptr, MAX_INLINE_BYTES)
+        }
+    }
+
+    #[inline]
+    /// Returns the record as bytes, in the format of the records of an
+    /// `UmbraColumn`: all integers are little-endian.
+    pub fn to_bytes(&self) -> [u8; 16] {
+        let mut bytes = [0; 16];
+        bytes[..4].copy_from_slice(&self.len.to_le_bytes());
+        bytes[4..8].copy_from_slice(&self.prefix);
+        bytes[8..].copy_from_slice(&self.last8);
+        bytes
+    }
+
+    #[inline]
+    /// Reads a record written by `to_bytes`. It is only validated by
+    /// `resolve`.
+    pub fn from_bytes(bytes: [u8; 16]) -> Self {
+        GermanStrOffset {
+            len: u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]),
+            prefix: [bytes[4], bytes[5], bytes[6], bytes[7]],
+            last8: [bytes[8], bytes[9], bytes[10], bytes[11], bytes[12], bytes[13], bytes[14], bytes[15]],
+        }
+    }
+}
+
+/// Validates a record, and returns its string.
+///
+/// `inline` are the 12 bytes following the length of the record, and
+/// `start` the offset of its string in `base` if it isn't inlined.
+pub(crate) fn resolve_record<'a>(
+    len: usize,
+    inline: &'a [u8],
+    start: u64,
+    base: &'a [u8],
+) -> Result<GermanStrRef<'a>, ResolveError> {
+    let bytes = if len <= MAX_INLINE_BYTES {
+        // The padding must be 0s, since comparisons rely on it.
+        if inline[len..].iter().any(|&b| b != 0) {
+            return Err(ResolveError::InvalidRecord);
+        }
+        &inline[..len]
+    } else {
+        if len > MAX_LEN {
+            return Err(ResolveError::InvalidRecord);
+        }
+        let bytes = usize::try_from(start)
+            .ok()
+            .and_then(|start| base.get(start..start.checked_add(len)?))
+            .ok_or(ResolveError::OutOfBounds)?;
+        if bytes[..4] != inline[..4] {
+            return Err(ResolveError::InvalidRecord);
+        }
+        bytes
+    };
+    let s = crate::str_from_utf8(bytes).map_err(|_| ResolveError::InvalidUtf8)?;
+    GermanStrRef::new(s).map_err(|_| ResolveError::InvalidRecord)
+}
+
+impl fmt::Debug for GermanStrOffset {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("GermanStrOffset")
+            .field("len", &self.len)
+            .field("prefix", &self.prefix)
+            .field("offset", &self.offset())
+            .finish()
+    }
+}
+
+impl fmt::Display for ResolveError {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ResolveError::OutOfBounds => f.write_str("String out of the bounds of its base region."),
+            ResolveError::InvalidRecord => f.write_str("Invalid string record."),
+            ResolveError::InvalidUtf8 => f.write_str("Invalid UTF-8 in string record."),
+        }
+    }
+}
+
+impl core::error::Error for ResolveError {}
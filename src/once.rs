@@ -0,0 +1,76 @@
+use core::fmt;
+use std::sync::OnceLock;
+
+use crate::GermanStr;
+
+/// A `GermanStr` which is initialized once, and can be used in a `static`.
+/// ```
+/// use german_str::{GermanStr, OnceGermanStr};
+///
+/// static HOSTNAME: OnceGermanStr = OnceGermanStr::new();
+///
+/// fn hostname() -> &'static GermanStr {
+///     HOSTNAME.get_or_init(|| GermanStr::new("computed once, then cached").unwrap())
+/// }
+/// assert_eq!(hostname(), "computed once, then cached");
+/// assert!(std::ptr::eq(hostname(), hostname()));
+/// ```
+#[derive(Default)]
+pub struct OnceGermanStr {
+    inner: OnceLock<GermanStr>,
+}
+
+impl OnceGermanStr {
+    #[inline]
+    pub const fn new() -> Self {
+        OnceGermanStr {
+            inner: OnceLock::new(),
+        }
+    }
+
+    #[inline]
+    /// Returns the string, or `None` if it wasn't initialized yet.
+    pub fn get(&self) -> Option<&GermanStr> {
+        self.inner.get()
+    }
+
+    #[inline]
+    /// Initializes the string with `value`, or returns it back if the
+    /// string was already initialized.
+    pub fn set(&self, value: GermanStr) -> Result<(), GermanStr> {
+        self.inner.set(value)
+    }
+
+    #[inline]
+    /// Returns the string, initializing it with `f` if it wasn't already.
+    ///
+    /// If several threads call this concurrently, only one `f` runs, and
+    /// the others wait for its result.
+    pub fn get_or_init(&self, f: impl FnOnce() -> GermanStr) -> &GermanStr {
+        self.inner.get_or_init(f)
+    }
+
+    #[inline]
+    pub fn into_inner(self) -> Option<GermanStr> {
+        self.inner.into_inner()
+    }
+}
+
+impl From<GermanStr> for OnceGermanStr {
+    #[inline]
+    fn from(german: GermanStr) -> Self {
+        OnceGermanStr {
+            inner: OnceLock::from(german),
+        }
+    }
+}
+
+impl fmt::Debug for OnceGermanStr {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.get() {
+            Some(german) => f.debug_tuple("OnceGermanStr").field(german).finish(),
+            None => f.write_str("OnceGermanStr(<uninit>)"),
+        }
+    }
+}
@@ -0,0 +1,189 @@
+use core::borrow::Borrow;
+use core::fmt;
+use core::ops::Deref;
+use std::ffi::{OsStr, OsString};
+use std::path::Path;
+
+use crate::{GermanStr, InitError};
+
+/// Same as `GermanStr`, but contains an `OsStr` instead of a `str`.
+///
+/// The string is stored as its platform-specific encoded bytes (see
+/// `OsStr::as_encoded_bytes`), WTF-8 on Windows. It is compared byte-wise,
+/// which is consistent with `OsStr`.
+#[derive(Clone, Default, PartialEq, Eq, PartialOrd, Ord)]
+pub struct GermanOsStr {
+    /// Contains the encoded bytes of an `OsStr`, which can't be accessed as
+    /// a `str`.
+    inner: GermanStr,
+}
+
+impl GermanOsStr {
+    #[inline]
+    pub fn new(src: impl AsRef<OsStr>) -> Result<Self, InitError> {
+        Ok(GermanOsStr {
+            inner: GermanStr::from_raw_bytes(src.as_ref().as_encoded_bytes())?,
+        })
+    }
+
+    #[inline(always)]
+    pub fn as_os_str(&self) -> &OsStr {
+        unsafe {
+            // Safety: inner was created from the encoded bytes of an OsStr,
+            // on the same platform.
+            OsStr::from_encoded_bytes_unchecked(self.inner.raw_bytes())
+        }
+    }
+
+    #[inline(always)]
+    /// Returns the string if it is valid UTF-8.
+    pub fn as_german_str(&self) -> Option<&GermanStr> {
+        self.as_os_str().to_str().map(|_| &self.inner)
+    }
+
+    #[inline(always)]
+    pub fn len(&self) -> usize {
+        self.inner.len()
+    }
+
+    #[inline(always)]
+    pub fn is_empty(&self) -> bool {
+        self.inner.is_empty()
+    }
+
+    #[inline(always)]
+    pub fn is_inlined(&self) -> bool {
+        self.inner.is_inlined()
+    }
+
+    #[inline(always)]
+    pub fn is_heap_allocated(&self) -> bool {
+        self.inner.is_heap_allocated()
+    }
+
+    #[inline(always)]
+    /// Returns the first 4 encoded bytes of the string, padded with 0s.
+    pub fn prefix_bytes_array(&self) -> [u8; 4] {
+        self.inner.prefix_bytes_array()
+    }
+}
+
+impl Deref for GermanOsStr {
+    type Target = OsStr;
+
+    #[inline(always)]
+    fn deref(&self) -> &OsStr {
+        self.as_os_str()
+    }
+}
+
+impl AsRef<OsStr> for GermanOsStr {
+    #[inline(always)]
+    fn as_ref(&self) -> &OsStr {
+        self.as_os_str()
+    }
+}
+
+impl AsRef<Path> for GermanOsStr {
+    #[inline(always)]
+    fn as_ref(&self) -> &Path {
+        Path::new(self.as_os_str())
+    }
+}
+
+impl Borrow<OsStr> for GermanOsStr {
+    #[inline(always)]
+    fn borrow(&self) -> &OsStr {
+        self.as_os_str()
+    }
+}
+
+impl core::hash::Hash for GermanOsStr {
+    #[inline(always)]
+    fn hash<H: core::hash::Hasher>(&self, hasher: &mut H) {
+        self.as_os_str().hash(hasher);
+    }
+}
+
+impl From<GermanStr> for GermanOsStr {
+    #[inline(always)]
+    /// UTF-8 is a valid encoding of an `OsStr` on every platform, so the
+    /// string is reused as is.
+    fn from(inner: GermanStr) -> Self {
+        GermanOsStr { inner }
+    }
+}
+
+impl TryFrom<GermanOsStr> for GermanStr {
+    type Error = GermanOsStr;
+
+    #[inline]
+    /// Returns the string back if it isn't valid UTF-8.
+    fn try_from(s: GermanOsStr) -> Result<Self, Self::Error> {
+        if s.as_os_str().to_str().is_some() {
+            Ok(s.inner)
+        } else {
+            Err(s)
+        }
+    }
+}
+
+impl TryFrom<&OsStr> for GermanOsStr {
+    type Error = InitError;
+
+    #[inline]
+    fn try_from(s: &OsStr) -> Result<Self, Self::Error> {
+        GermanOsStr::new(s)
+    }
+}
+
+impl TryFrom<OsString> for GermanOsStr {
+    type Error = InitError;
+
+    #[inline]
+    fn try_from(s: OsString) -> Result<Self, Self::Error> {
+        GermanOsStr::new(s)
+    }
+}
+
+impl From<GermanOsStr> for OsString {
+    #[inline]
+    fn from(s: GermanOsStr) -> Self {
+        OsString::from(s.as_os_str())
+    }
+}
+
+impl PartialEq<OsStr> for GermanOsStr {
+    #[inline(always)]
+    fn eq(&self, other: &OsStr) -> bool {
+        self.as_os_str() == other
+    }
+}
+
+impl PartialEq<&OsStr> for GermanOsStr {
+    #[inline(always)]
+    fn eq(&self, other: &&OsStr) -> bool {
+        self.as_os_str() == *other
+    }
+}
+
+impl PartialEq<str> for GermanOsStr {
+    #[inline(always)]
+    fn eq(&self, other: &str) -> bool {
+        self.as_os_str() == other
+    }
+}
+
+impl PartialEq<&str> for GermanOsStr {
+    #[inline(always)]
+    fn eq(&self, other: &&str) -> bool {
+        self.as_os_str() == *other
+    }
+}
+
+impl fmt::Debug for GermanOsStr {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Debug::fmt(self.as_os_str(), f)
+    }
+}
@@ -0,0 +1,151 @@
+use core::fmt;
+use core::mem;
+use core::slice;
+
+use crate::offset::resolve_record;
+use crate::{bytes_prefix, GermanStrRef, InitError, ResolveError, MAX_INLINE_BYTES, MAX_LEN};
+
+/// A string record stored in a database page, whose long strings are
+/// stored in the same page, at a 32-bit offset.
+///
+/// This is how Umbra stores strings on disk: like a `GermanStr`, the record
+/// stores its length and first 4 bytes, then either the rest of the string
+/// (padded with 0s) if it's at most 12 bytes long, or the offset of the
+/// whole string in the page as a little-endian u32, followed by 4 bytes
+/// set to 0. Since it doesn't contain any pointer, pages can be written to
+/// disk and read back at another address: `resolve` turns the records into
+/// `GermanStrRef`s borrowing the page, which stays pinned while they are
+/// used. `size_of::<PageGermanStr>() == 16`.
+/// ```
+/// use german_str::PageGermanStr;
+///
+/// // A page whose payloads are written from its end.
+/// let mut page = [0u8; 4096];
+/// let long = "a string longer than 12 bytes";
+/// let start = page.len() - long.len();
+/// page[start..].copy_from_slice(long.as_bytes());
+/// let record = PageGermanStr::new(long, start as u32).unwrap();
+///
+/// assert_eq!(record.offset(), Some(start as u32));
+/// assert_eq!(record.resolve(&page).unwrap(), long);
+/// assert_eq!(PageGermanStr::new("short", 0).unwrap().resolve(&page).unwrap(), "short");
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+#[repr(C)]
+pub struct PageGermanStr {
+    len: u32,
+    prefix: [u8; 4],
+    /// The rest of the string if it is at most `MAX_INLINE_BYTES` long,
+    /// its offset in the page as a little-endian u32 followed by 4 bytes
+    /// set to 0 otherwise.
+    last8: [u8; 8],
+}
+
+impl PageGermanStr {
+    /// Creates a record for `src`, which is stored at `offset` in the page
+    /// if it can't be inlined. `offset` is ignored otherwise.
+    pub fn new(src: &str, offset: u32) -> Result<Self, InitError> {
+        if src.len() > MAX_LEN {
+            return Err(InitError::TooLong);
+        }
+        let mut last8 = [0; 8];
+        if src.len() <= MAX_INLINE_BYTES {
+            if let Some(suffix) = src.as_bytes().get(4..) {
+                last8[..suffix.len()].copy_from_slice(suffix);
+            }
+        } else {
+            last8[..4].copy_from_slice(&offset.to_le_bytes());
+        }
+        Ok(PageGermanStr {
+            len: src.len() as u32,
+            prefix: bytes_prefix(src.as_bytes()),
+            last8,
+        })
+    }
+
+    #[inline(always)]
+    pub const fn len(&self) -> usize {
+        self.len as usize
+    }
+
+    #[inline(always)]
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    #[inline(always)]
+    /// Returns an array containing the first 4 bytes of the string.
+    /// If the string is shorter than 4 bytes, extra bytes are set to 0.
+    pub const fn prefix_bytes_array(&self) -> [u8; 4] {
+        self.prefix
+    }
+
+    #[inline]
+    /// Returns the offset of the string in the page, or `None` if it is
+    /// inlined.
+    pub fn offset(&self) -> Option<u32> {
+        (self.len() > MAX_INLINE_BYTES).then(|| u32::from_le_bytes(le_bytes(&self.last8[..4])))
+    }
+
+    /// Returns the string, reading it from `page` if it isn't inlined.
+    ///
+    /// The record is validated, since it may have been read from disk.
+    pub fn resolve<'a>(&'a self, page: &'a [u8]) -> Result<GermanStrRef<'a>, ResolveError> {
+        let start = match self.offset() {
+            // The unused bytes must be 0s.
+            Some(_) if self.last8[4..] != [0; 4] => return Err(ResolveError::InvalidRecord),
+            Some(start) => start as u64,
+            None => 0,
+        };
+        resolve_record(self.len(), self.inline_bytes(), start, page)
+    }
+
+    #[inline(always)]
+    /// Returns the 12 bytes following `len`: the inlined string, padded
+    /// with 0s, for inlined strings.
+    fn inline_bytes(&self) -> &[u8] {
+        unsafe {
+            // Safety: same as in `GermanStrOffset::inline_bytes`.
+            let ptr = (self as *const PageGermanStr).cast::<u8>().add(mem::offset_of!(PageGermanStr, prefix));
+            slice::from_raw_parts(ptr, MAX_INLINE_BYTES)
+        }
+    }
+
+    #[inline]
+    /// Returns the record as bytes, to be written in a page: all integers
+    /// are little-endian.
+    pub fn to_bytes(&self) -> [u8; 16] {
+        let mut bytes = [0; 16];
+        bytes[..4].copy_from_slice(&self.len.to_le_bytes());
+        bytes[4..8].copy_from_slice(&self.prefix);
+        bytes[8..].copy_from_slice(&self.last8);
+        bytes
+    }
+
+    #[inline]
+    /// Reads a record written by `to_bytes`. It is only validated by
+    /// `resolve`.
+    pub fn from_bytes(bytes: [u8; 16]) -> Self {
+        PageGermanStr {
+            len: u32::from_le_bytes(le_bytes(&bytes[..4])),
+            prefix: le_bytes(&bytes[4..8]),
+            last8: le_bytes(&bytes[8..]),
+        }
+    }
+}
+
+#[inline(always)]
+fn le_bytes<const N: usize>(src: &[u8]) -> [u8; N] {
+    src.try_into().unwrap()
+}
+
+impl fmt::Debug for PageGermanStr {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("PageGermanStr")
+            .field("len", &self.len)
+            .field("prefix", &self.prefix)
+            .field("offset", &self.offset())
+            .finish()
+    }
+}
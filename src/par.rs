@@ -0,0 +1,45 @@
+use alloc::vec::Vec;
+
+use rayon::iter::ParallelIterator;
+use rayon::slice::ParallelSliceMut;
+
+use crate::{be_u32, GermanStr};
+
+/// Sorts `strings` in parallel with rayon, like `par_sort_unstable`.
+///
+/// The strings are first bucketed by prefix, by sorting them on their
+/// prefix alone, which never reads their heap buffers. The buckets of
+/// strings with equal prefixes are then sorted in parallel.
+/// ```
+/// use german_str::{par_sort_german, GermanStr};
+///
+/// let mut strings: Vec<GermanStr> = ["pear", "apple, which is long", "fig", "apple"]
+///     .into_iter()
+///     .map(|s| GermanStr::new(s).unwrap())
+///     .collect();
+/// par_sort_german(&mut strings);
+/// assert_eq!(strings, ["apple", "apple, which is long", "fig", "pear"]);
+/// ```
+pub fn par_sort_german(strings: &mut [GermanStr]) {
+    strings.par_sort_unstable_by_key(|s| be_u32(s.prefix));
+    strings
+        .par_chunk_by_mut(|lhs, rhs| lhs.prefix == rhs.prefix)
+        .for_each(|bucket| bucket.sort_unstable());
+}
+
+/// Sorts `strings` in parallel with `par_sort_german`, and removes
+/// duplicates.
+/// ```
+/// use german_str::{par_dedup_german, GermanStr};
+///
+/// let mut strings: Vec<GermanStr> = ["b", "a string longer than 12 bytes", "b", "a string longer than 12 bytes"]
+///     .into_iter()
+///     .map(|s| GermanStr::new(s).unwrap())
+///     .collect();
+/// par_dedup_german(&mut strings);
+/// assert_eq!(strings, ["a string longer than 12 bytes", "b"]);
+/// ```
+pub fn par_dedup_german(strings: &mut Vec<GermanStr>) {
+    par_sort_german(strings);
+    strings.dedup();
+}
@@ -0,0 +1,153 @@
+use core::fmt;
+use core::ops::Deref;
+use std::ffi::OsStr;
+use std::path::{Path, PathBuf};
+
+use crate::{GermanOsStr, GermanStr, InitError};
+
+/// Same as `GermanStr`, but contains a `Path`.
+///
+/// Unlike `Path`, which compares paths component by component, a
+/// `GermanPath` is compared byte-wise like a `GermanOsStr`: `"a/b"` and
+/// `"a//b"` are different, and `"a-b" < "a/b"`. This is what makes
+/// comparisons cheap. `Borrow<Path>` isn't implemented for this reason.
+#[derive(Clone, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct GermanPath {
+    inner: GermanOsStr,
+}
+
+impl GermanPath {
+    #[inline]
+    pub fn new(src: impl AsRef<Path>) -> Result<Self, InitError> {
+        Ok(GermanPath {
+            inner: GermanOsStr::new(src.as_ref())?,
+        })
+    }
+
+    #[inline(always)]
+    pub fn as_path(&self) -> &Path {
+        Path::new(self.inner.as_os_str())
+    }
+
+    #[inline(always)]
+    pub fn as_os_str(&self) -> &OsStr {
+        self.inner.as_os_str()
+    }
+
+    #[inline(always)]
+    pub fn as_german_os_str(&self) -> &GermanOsStr {
+        &self.inner
+    }
+
+    #[inline(always)]
+    pub fn len(&self) -> usize {
+        self.inner.len()
+    }
+
+    #[inline(always)]
+    pub fn is_empty(&self) -> bool {
+        self.inner.is_empty()
+    }
+
+    #[inline(always)]
+    pub fn is_inlined(&self) -> bool {
+        self.inner.is_inlined()
+    }
+
+    #[inline(always)]
+    pub fn is_heap_allocated(&self) -> bool {
+        self.inner.is_heap_allocated()
+    }
+}
+
+impl Deref for GermanPath {
+    type Target = Path;
+
+    #[inline(always)]
+    fn deref(&self) -> &Path {
+        self.as_path()
+    }
+}
+
+impl AsRef<Path> for GermanPath {
+    #[inline(always)]
+    fn as_ref(&self) -> &Path {
+        self.as_path()
+    }
+}
+
+impl AsRef<OsStr> for GermanPath {
+    #[inline(always)]
+    fn as_ref(&self) -> &OsStr {
+        self.as_os_str()
+    }
+}
+
+impl From<GermanOsStr> for GermanPath {
+    #[inline(always)]
+    fn from(inner: GermanOsStr) -> Self {
+        GermanPath { inner }
+    }
+}
+
+impl From<GermanPath> for GermanOsStr {
+    #[inline(always)]
+    fn from(path: GermanPath) -> Self {
+        path.inner
+    }
+}
+
+impl From<GermanStr> for GermanPath {
+    #[inline(always)]
+    fn from(s: GermanStr) -> Self {
+        GermanPath { inner: s.into() }
+    }
+}
+
+impl TryFrom<&Path> for GermanPath {
+    type Error = InitError;
+
+    #[inline]
+    fn try_from(p: &Path) -> Result<Self, Self::Error> {
+        GermanPath::new(p)
+    }
+}
+
+impl TryFrom<PathBuf> for GermanPath {
+    type Error = InitError;
+
+    #[inline]
+    fn try_from(p: PathBuf) -> Result<Self, Self::Error> {
+        GermanPath::new(p)
+    }
+}
+
+impl From<GermanPath> for PathBuf {
+    #[inline]
+    fn from(p: GermanPath) -> Self {
+        PathBuf::from(p.as_path())
+    }
+}
+
+impl PartialEq<Path> for GermanPath {
+    #[inline(always)]
+    /// Compares the paths component-wise, like `Path` does.
+    fn eq(&self, other: &Path) -> bool {
+        self.as_path() == other
+    }
+}
+
+impl PartialEq<&Path> for GermanPath {
+    #[inline(always)]
+    /// Compares the paths component-wise, like `Path` does.
+    fn eq(&self, other: &&Path) -> bool {
+        self.as_path() == *other
+    }
+}
+
+impl fmt::Debug for GermanPath {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Debug::fmt(self.as_path(), f)
+    }
+}
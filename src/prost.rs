@@ -0,0 +1,139 @@
+//! Protobuf encoding of `GermanStr`s, for `string` fields.
+//!
+//! `prost-build` can't generate `GermanStr` fields, but these functions
+//! mirror those of `prost::encoding::string`, so messages with `GermanStr`
+//! fields can implement `Message` by hand, exactly like generated code
+//! does. Strings of at most `MAX_INLINE_BYTES` are decoded without any
+//! allocation, longer ones are read into their heap buffer.
+//! ```
+//! use german_str::GermanStr;
+//! use prost::bytes::{Buf, BufMut};
+//! use prost::encoding::{skip_field, DecodeContext, WireType};
+//! use prost::{DecodeError, Message};
+//!
+//! #[derive(Debug, Default, PartialEq)]
+//! struct User {
+//!     name: GermanStr,
+//! }
+//!
+//! impl Message for User {
+//!     fn encode_raw(&self, buf: &mut impl BufMut) {
+//!         if !self.name.is_empty() {
+//!             german_str::prost::encode(1, &self.name, buf);
+//!         }
+//!     }
+//!
+//!     fn merge_field(&mut self, tag: u32, wire_type: WireType, buf: &mut impl Buf, ctx: DecodeContext) -> Result<(), DecodeError> {
+//!         match tag {
+//!             1 => german_str::prost::merge(wire_type, &mut self.name, buf, ctx),
+//!             _ => skip_field(wire_type, tag, buf, ctx),
+//!         }
+//!     }
+//!
+//!     fn encoded_len(&self) -> usize {
+//!         if self.name.is_empty() { 0 } else { german_str::prost::encoded_len(1, &self.name) }
+//!     }
+//!
+//!     fn clear(&mut self) {
+//!         self.name = GermanStr::default();
+//!     }
+//! }
+//!
+//! let user = User { name: GermanStr::new("a string longer than 12 bytes").unwrap() };
+//! assert_eq!(User::decode(&*user.encode_to_vec()).unwrap(), user);
+//! ```
+
+use alloc::vec;
+use alloc::vec::Vec;
+
+use prost::bytes::{Buf, BufMut};
+use prost::encoding::{
+    check_wire_type, decode_varint, encode_key, encode_varint, encoded_len_varint, key_len, DecodeContext, WireType,
+};
+use prost::DecodeError;
+
+use crate::{GermanStr, MAX_INLINE_BYTES, MAX_LEN};
+
+/// Encodes `value` as the field `tag`.
+pub fn encode(tag: u32, value: &GermanStr, buf: &mut impl BufMut) {
+    encode_key(tag, WireType::LengthDelimited, buf);
+    encode_varint(value.len() as u64, buf);
+    buf.put_slice(value.as_bytes());
+}
+
+/// Decodes a string, and replaces `value` with it.
+///
+/// On failure, `value` is left unchanged.
+pub fn merge(
+    wire_type: WireType,
+    value: &mut GermanStr,
+    buf: &mut impl Buf,
+    _ctx: DecodeContext,
+) -> Result<(), DecodeError> {
+    check_wire_type(WireType::LengthDelimited, wire_type)?;
+    let len = decode_varint(buf)?;
+    if len > buf.remaining() as u64 {
+        return Err(decode_error("buffer underflow"));
+    }
+    let len = len as usize;
+    if len > MAX_LEN {
+        return Err(decode_error("invalid string value: longer than MAX_LEN bytes"));
+    }
+    *value = if len <= MAX_INLINE_BYTES {
+        let mut bytes = [0; MAX_INLINE_BYTES];
+        buf.copy_to_slice(&mut bytes[..len]);
+        let src = crate::str_from_utf8(&bytes[..len]).map_err(|_| invalid_utf8())?;
+        GermanStr::new_inline(src)
+    } else {
+        let mut bytes = vec![0; len];
+        buf.copy_to_slice(&mut bytes);
+        GermanStr::from_utf8(bytes).map_err(|_| invalid_utf8())?
+    };
+    Ok(())
+}
+
+/// Encodes each string of `values` as the field `tag`.
+pub fn encode_repeated(tag: u32, values: &[GermanStr], buf: &mut impl BufMut) {
+    for value in values {
+        encode(tag, value, buf);
+    }
+}
+
+/// Decodes a string, and appends it to `values`.
+pub fn merge_repeated(
+    wire_type: WireType,
+    values: &mut Vec<GermanStr>,
+    buf: &mut impl Buf,
+    ctx: DecodeContext,
+) -> Result<(), DecodeError> {
+    let mut value = GermanStr::default();
+    merge(wire_type, &mut value, buf, ctx)?;
+    values.push(value);
+    Ok(())
+}
+
+#[inline]
+/// Returns the number of bytes written by `encode`.
+pub fn encoded_len(tag: u32, value: &GermanStr) -> usize {
+    key_len(tag) + encoded_len_varint(value.len() as u64) + value.len()
+}
+
+#[inline]
+/// Returns the number of bytes written by `encode_repeated`.
+pub fn encoded_len_repeated(tag: u32, values: &[GermanStr]) -> usize {
+    key_len(tag) * values.len()
+        + values.iter().map(|value| encoded_len_varint(value.len() as u64) + value.len()).sum::<usize>()
+}
+
+#[cold]
+fn invalid_utf8() -> DecodeError {
+    decode_error("invalid string value: data is not UTF-8 encoded")
+}
+
+#[cold]
+fn decode_error(description: &'static str) -> DecodeError {
+    // prost doesn't have another way to create decoding errors from outside
+    // of the crate.
+    #[allow(deprecated)]
+    DecodeError::new(description)
+}
@@ -0,0 +1,41 @@
+use alloc::string::ToString;
+use core::convert::Infallible;
+
+use pyo3::exceptions::PyValueError;
+use pyo3::types::{PyString, PyStringMethods};
+use pyo3::{Borrowed, Bound, FromPyObject, IntoPyObject, PyAny, PyErr, Python};
+
+use crate::GermanStr;
+
+/// Accepts Python `str` objects. Raises a `ValueError` if the string is
+/// longer than `MAX_LEN` bytes once encoded in UTF-8.
+impl FromPyObject<'_, '_> for GermanStr {
+    type Error = PyErr;
+
+    fn extract(obj: Borrowed<'_, '_, PyAny>) -> Result<Self, PyErr> {
+        let s = obj.cast::<PyString>()?;
+        GermanStr::new(s.to_cow()?).map_err(|err| PyValueError::new_err(err.to_string()))
+    }
+}
+
+impl<'py> IntoPyObject<'py> for GermanStr {
+    type Target = PyString;
+    type Output = Bound<'py, PyString>;
+    type Error = Infallible;
+
+    #[inline]
+    fn into_pyobject(self, py: Python<'py>) -> Result<Self::Output, Infallible> {
+        Ok(PyString::new(py, self.as_str()))
+    }
+}
+
+impl<'py> IntoPyObject<'py> for &GermanStr {
+    type Target = PyString;
+    type Output = Bound<'py, PyString>;
+    type Error = Infallible;
+
+    #[inline]
+    fn into_pyobject(self, py: Python<'py>) -> Result<Self::Output, Infallible> {
+        Ok(PyString::new(py, self.as_str()))
+    }
+}
@@ -0,0 +1,22 @@
+use alloc::boxed::Box;
+use alloc::string::String;
+
+use crate::{GermanStr, MAX_INLINE_BYTES};
+
+impl quickcheck::Arbitrary for GermanStr {
+    fn arbitrary(g: &mut quickcheck::Gen) -> Self {
+        let s = String::arbitrary(g);
+        GermanStr::new(s).expect("BUG in quickcheck implementation of GermanStr. Please report it at github.com/ostnam/german-str/issues")
+    }
+
+    fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+        // If the string is heap-allocated, first try its longest inlinable
+        // prefix, then fall back to the shrinking of String.
+        let inline = self.is_heap_allocated().then(|| self.truncate_to(MAX_INLINE_BYTES));
+        let shrunk = self
+            .to_string()
+            .shrink()
+            .filter_map(|s| GermanStr::new(s).ok());
+        Box::new(inline.into_iter().chain(shrunk))
+    }
+}
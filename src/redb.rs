@@ -0,0 +1,58 @@
+//! Strings are stored as their UTF-8 bytes, like redb stores `&str`s, whose
+//! byte order matches the order of `GermanStr`.
+
+use alloc::borrow::Cow;
+use core::cmp::Ordering;
+
+use redb::{Key, TypeName, Value};
+
+use crate::GermanStr;
+
+impl Value for GermanStr {
+    type SelfType<'a> = GermanStr;
+    type AsBytes<'a> = &'a str;
+
+    #[inline]
+    fn fixed_width() -> Option<usize> {
+        None
+    }
+
+    #[inline]
+    /// Panics if `data` isn't valid UTF-8, like redb's `&str` does.
+    fn from_bytes<'a>(data: &'a [u8]) -> GermanStr
+    where
+        Self: 'a,
+    {
+        GermanStr::new(<&str>::from_bytes(data)).expect("redb values are at most MAX_LEN bytes long")
+    }
+
+    #[inline]
+    fn as_bytes<'a, 'b: 'a>(value: &'a GermanStr) -> &'a str
+    where
+        Self: 'b,
+    {
+        value.as_str()
+    }
+
+    #[inline]
+    fn type_name() -> TypeName {
+        TypeName::new("german_str::GermanStr")
+    }
+}
+
+impl Key for GermanStr {
+    #[inline]
+    fn compare(data1: &[u8], data2: &[u8]) -> Ordering {
+        data1.cmp(data2)
+    }
+
+    #[inline]
+    fn separator<'a>(left: &'a [u8], right: &'a [u8]) -> Cow<'a, [u8]> {
+        <&str>::separator(left, right)
+    }
+
+    #[inline]
+    fn min_encoded_key() -> Option<Cow<'static, [u8]>> {
+        <&str>::min_encoded_key()
+    }
+}
@@ -0,0 +1,38 @@
+use alloc::string::{String, ToString};
+
+use redis::{FromRedisValue, ParsingError, RedisWrite, ToRedisArgs, ToSingleRedisArg, Value};
+
+use crate::GermanStr;
+
+impl ToRedisArgs for GermanStr {
+    #[inline]
+    fn write_redis_args<W>(&self, out: &mut W)
+    where
+        W: ?Sized + RedisWrite,
+    {
+        out.write_arg(self.as_bytes());
+    }
+}
+
+impl ToSingleRedisArg for GermanStr {}
+
+// Bulk strings are decoded directly into a `GermanStr`: short ones are
+// inlined, and long ones reuse the buffer of the owned `Value`. Other
+// values are converted like `String`s are.
+impl FromRedisValue for GermanStr {
+    fn from_redis_value_ref(v: &Value) -> Result<Self, ParsingError> {
+        match v {
+            Value::BulkString(bytes) => {
+                GermanStr::new(core::str::from_utf8(bytes)?).map_err(|e| ParsingError::from(e.to_string()))
+            }
+            _ => GermanStr::new(String::from_redis_value_ref(v)?).map_err(|e| ParsingError::from(e.to_string())),
+        }
+    }
+
+    fn from_redis_value(v: Value) -> Result<Self, ParsingError> {
+        match v {
+            Value::BulkString(bytes) => GermanStr::from_utf8(bytes).map_err(|e| ParsingError::from(e.to_string())),
+            _ => GermanStr::new(String::from_redis_value(v)?).map_err(|e| ParsingError::from(e.to_string())),
+        }
+    }
+}
@@ -0,0 +1,273 @@
+use alloc::collections::BTreeSet;
+use alloc::vec::Vec;
+use core::borrow::Borrow;
+use core::cell::RefCell;
+use core::cmp;
+use core::fmt;
+use core::marker::PhantomData;
+use core::ops::Deref;
+
+use crate::{GermanStr, InitError};
+
+/// Owns heap buffers shared by many `GermanStr`s, and frees each of them
+/// exactly once when dropped.
+///
+/// This is a safe alternative to `GermanStr::leaky_shared_clone` and
+/// `GermanStr::free`: the shared clones handed out by the registry borrow
+/// it, so they can't outlive their buffers.
+/// ```
+/// use german_str::{GermanStr, SharedBufferRegistry};
+///
+/// let registry = SharedBufferRegistry::new();
+/// let first = registry.share(GermanStr::new("a string longer than 12 bytes").unwrap());
+/// let copies = vec![first.clone(); 1000];
+/// assert_eq!(registry.len(), 1);
+/// assert!(copies.iter().all(|s| s.heap_ptr() == first.heap_ptr()));
+/// ```
+#[derive(Default)]
+pub struct SharedBufferRegistry {
+    /// Strings owning the registered buffers, which are freed when they
+    /// are dropped.
+    owners: RefCell<Vec<GermanStr>>,
+}
+
+impl SharedBufferRegistry {
+    #[inline]
+    pub const fn new() -> Self {
+        SharedBufferRegistry {
+            owners: RefCell::new(Vec::new()),
+        }
+    }
+
+    /// Takes ownership of the buffer of `src`, and returns a shared clone of
+    /// it.
+    ///
+    /// Inlined strings, and strings whose buffer is already shared, aren't
+    /// registered: they are returned as is.
+    pub fn share(&self, src: GermanStr) -> SharedGermanStr<'_> {
+        if src.has_shared_buffer() || src.is_inlined() {
+            return SharedGermanStr::new(src);
+        }
+        let view = unsafe {
+            // Safety: src is moved into self.owners, which is only dropped
+            // with self, and the returned value borrows self.
+            src.shared_view()
+        };
+        self.owners.borrow_mut().push(src);
+        SharedGermanStr::new(view)
+    }
+
+    #[inline]
+    /// Creates a `GermanStr` from `src`, and shares it.
+    pub fn share_str(&self, src: &str) -> Result<SharedGermanStr<'_>, InitError> {
+        Ok(self.share(GermanStr::new(src)?))
+    }
+
+    /// Shares the buffers of `strings`, so that equal strings reference the
+    /// same buffer: the buffer of the first occurrence of every value is
+    /// registered, and the buffers of the next ones are freed.
+    ///
+    /// Returns the shared strings, in the same order as `strings`, and the
+    /// number of heap bytes freed. Strings are only deduplicated with the
+    /// other strings of `strings`, not with buffers registered earlier.
+    /// ```
+    /// use german_str::{GermanStr, SharedBufferRegistry};
+    ///
+    /// let registry = SharedBufferRegistry::new();
+    /// let rows: Vec<GermanStr> = ["a value longer than 12 bytes", "short", "a value longer than 12 bytes"]
+    ///     .into_iter()
+    ///     .map(|s| GermanStr::new(s).unwrap())
+    ///     .collect();
+    /// let (rows, saved) = registry.dedup_shared(rows);
+    /// assert_eq!(saved, 28);
+    /// assert_eq!(registry.len(), 1);
+    /// assert_eq!(rows[0].heap_ptr(), rows[2].heap_ptr());
+    /// ```
+    pub fn dedup_shared(&self, strings: Vec<GermanStr>) -> (Vec<SharedGermanStr<'_>>, usize) {
+        let mut distinct = BTreeSet::<SharedGermanStr<'_>>::new();
+        let mut saved = 0;
+        let deduped = strings
+            .into_iter()
+            .map(|src| {
+                if src.has_shared_buffer() || src.is_inlined() {
+                    return SharedGermanStr::new(src);
+                }
+                if let Some(first) = distinct.get(src.as_str()) {
+                    saved += src.heap_bytes();
+                    return first.clone();
+                }
+                let shared = self.share(src);
+                distinct.insert(shared.clone());
+                shared
+            })
+            .collect();
+        (deduped, saved)
+    }
+
+    #[inline]
+    /// Returns the number of registered buffers.
+    pub fn len(&self) -> usize {
+        self.owners.borrow().len()
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+impl fmt::Debug for SharedBufferRegistry {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_list().entries(self.owners.borrow().iter()).finish()
+    }
+}
+
+/// A `GermanStr` whose buffer is owned by a `SharedBufferRegistry`, by the
+/// string passed to `GermanStr::share_scope`, or by an `AtomicGermanStr`.
+///
+/// Cloning it reuses the same buffer. Cloning the `GermanStr` it derefs to
+/// creates a new `GermanStr` that owns a copy of the buffer, and can outlive
+/// the registry.
+pub struct SharedGermanStr<'a> {
+    /// Either inlined or shared: dropping it never frees anything.
+    inner: GermanStr,
+    registry: PhantomData<&'a SharedBufferRegistry>,
+}
+
+impl SharedGermanStr<'_> {
+    #[inline(always)]
+    pub(crate) fn new(inner: GermanStr) -> Self {
+        SharedGermanStr {
+            inner,
+            registry: PhantomData,
+        }
+    }
+}
+
+impl GermanStr {
+    /// Calls `f` with a handle to `self`, whose clones share the buffer of
+    /// `self`. The buffer is freed once `f` returns.
+    ///
+    /// Neither the handle nor its clones can escape `f`, which makes this
+    /// a safe alternative to `GermanStr::leaky_shared_clone` for short-lived
+    /// copies.
+    /// ```
+    /// use german_str::GermanStr;
+    ///
+    /// let label = GermanStr::new("a label longer than 12 bytes").unwrap();
+    /// let row_count = label.share_scope(|label| {
+    ///     let rows: Vec<_> = (0..100).map(|_| label.clone()).collect();
+    ///     assert!(rows.iter().all(|s| s.heap_ptr() == label.heap_ptr()));
+    ///     rows.len()
+    /// });
+    /// assert_eq!(row_count, 100);
+    /// ```
+    pub fn share_scope<R>(self, f: impl FnOnce(&SharedGermanStr<'_>) -> R) -> R {
+        let handle = SharedGermanStr::new(unsafe {
+            // Safety: `self` is only dropped at the end of this function,
+            // and `f` can't return values borrowing the handle.
+            self.shared_view()
+        });
+        f(&handle)
+    }
+}
+
+impl Clone for SharedGermanStr<'_> {
+    #[inline]
+    fn clone(&self) -> Self {
+        SharedGermanStr::new(unsafe {
+            // Safety: the buffer is owned by the registry (or the scope, or
+            // the atomic cell), which outlives the clone.
+            self.inner.shared_view()
+        })
+    }
+}
+
+impl Deref for SharedGermanStr<'_> {
+    type Target = GermanStr;
+
+    #[inline(always)]
+    fn deref(&self) -> &GermanStr {
+        &self.inner
+    }
+}
+
+impl AsRef<str> for SharedGermanStr<'_> {
+    #[inline(always)]
+    fn as_ref(&self) -> &str {
+        self.inner.as_str()
+    }
+}
+
+impl Borrow<str> for SharedGermanStr<'_> {
+    #[inline(always)]
+    fn borrow(&self) -> &str {
+        self.inner.as_str()
+    }
+}
+
+impl PartialEq for SharedGermanStr<'_> {
+    #[inline(always)]
+    fn eq(&self, other: &Self) -> bool {
+        self.inner == other.inner
+    }
+}
+
+impl Eq for SharedGermanStr<'_> {}
+
+impl Ord for SharedGermanStr<'_> {
+    #[inline(always)]
+    fn cmp(&self, other: &Self) -> cmp::Ordering {
+        self.inner.cmp(&other.inner)
+    }
+}
+
+impl PartialOrd for SharedGermanStr<'_> {
+    #[inline(always)]
+    fn partial_cmp(&self, other: &Self) -> Option<cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl core::hash::Hash for SharedGermanStr<'_> {
+    #[inline(always)]
+    fn hash<H: core::hash::Hasher>(&self, hasher: &mut H) {
+        self.inner.hash(hasher);
+    }
+}
+
+impl PartialEq<str> for SharedGermanStr<'_> {
+    #[inline(always)]
+    fn eq(&self, other: &str) -> bool {
+        self.inner == *other
+    }
+}
+
+impl PartialEq<&str> for SharedGermanStr<'_> {
+    #[inline(always)]
+    fn eq(&self, other: &&str) -> bool {
+        self.inner == *other
+    }
+}
+
+impl PartialEq<GermanStr> for SharedGermanStr<'_> {
+    #[inline(always)]
+    fn eq(&self, other: &GermanStr) -> bool {
+        self.inner == *other
+    }
+}
+
+impl fmt::Debug for SharedGermanStr<'_> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Debug::fmt(&self.inner, f)
+    }
+}
+
+impl fmt::Display for SharedGermanStr<'_> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(&self.inner, f)
+    }
+}
@@ -0,0 +1,141 @@
+use alloc::string::String;
+
+use memchr::memmem;
+
+use crate::GermanStr;
+
+mod private {
+    pub trait Sealed {}
+}
+
+/// Patterns accepted by the `memchr`-accelerated `GermanStr::find_bytes`,
+/// `GermanStr::rfind_bytes` and `GermanStr::contains_bytes`.
+pub trait Needle: private::Sealed {
+    #[doc(hidden)]
+    fn find_in(&self, haystack: &[u8]) -> Option<usize>;
+
+    #[doc(hidden)]
+    fn rfind_in(&self, haystack: &[u8]) -> Option<usize>;
+}
+
+impl private::Sealed for u8 {}
+impl Needle for u8 {
+    #[inline]
+    fn find_in(&self, haystack: &[u8]) -> Option<usize> {
+        memchr::memchr(*self, haystack)
+    }
+
+    #[inline]
+    fn rfind_in(&self, haystack: &[u8]) -> Option<usize> {
+        memchr::memrchr(*self, haystack)
+    }
+}
+
+impl private::Sealed for [u8] {}
+impl Needle for [u8] {
+    #[inline]
+    fn find_in(&self, haystack: &[u8]) -> Option<usize> {
+        memmem::find(haystack, self)
+    }
+
+    #[inline]
+    fn rfind_in(&self, haystack: &[u8]) -> Option<usize> {
+        memmem::rfind(haystack, self)
+    }
+}
+
+impl private::Sealed for char {}
+impl Needle for char {
+    #[inline]
+    fn find_in(&self, haystack: &[u8]) -> Option<usize> {
+        if self.is_ascii() {
+            (*self as u8).find_in(haystack)
+        } else {
+            self.encode_utf8(&mut [0; 4]).as_bytes().find_in(haystack)
+        }
+    }
+
+    #[inline]
+    fn rfind_in(&self, haystack: &[u8]) -> Option<usize> {
+        if self.is_ascii() {
+            (*self as u8).rfind_in(haystack)
+        } else {
+            self.encode_utf8(&mut [0; 4]).as_bytes().rfind_in(haystack)
+        }
+    }
+}
+
+impl private::Sealed for str {}
+impl Needle for str {
+    #[inline(always)]
+    fn find_in(&self, haystack: &[u8]) -> Option<usize> {
+        self.as_bytes().find_in(haystack)
+    }
+
+    #[inline(always)]
+    fn rfind_in(&self, haystack: &[u8]) -> Option<usize> {
+        self.as_bytes().rfind_in(haystack)
+    }
+}
+
+impl private::Sealed for String {}
+impl Needle for String {
+    #[inline(always)]
+    fn find_in(&self, haystack: &[u8]) -> Option<usize> {
+        self.as_bytes().find_in(haystack)
+    }
+
+    #[inline(always)]
+    fn rfind_in(&self, haystack: &[u8]) -> Option<usize> {
+        self.as_bytes().rfind_in(haystack)
+    }
+}
+
+impl private::Sealed for GermanStr {}
+impl Needle for GermanStr {
+    #[inline(always)]
+    fn find_in(&self, haystack: &[u8]) -> Option<usize> {
+        self.as_bytes().find_in(haystack)
+    }
+
+    #[inline(always)]
+    fn rfind_in(&self, haystack: &[u8]) -> Option<usize> {
+        self.as_bytes().rfind_in(haystack)
+    }
+}
+
+impl<T: Needle + ?Sized> private::Sealed for &T {}
+impl<T: Needle + ?Sized> Needle for &T {
+    #[inline(always)]
+    fn find_in(&self, haystack: &[u8]) -> Option<usize> {
+        (**self).find_in(haystack)
+    }
+
+    #[inline(always)]
+    fn rfind_in(&self, haystack: &[u8]) -> Option<usize> {
+        (**self).rfind_in(haystack)
+    }
+}
+
+/// `memchr`-accelerated versions of `str::find`, `str::rfind` and
+/// `str::contains`, which are still available through `Deref`, and accept
+/// other patterns, such as closures.
+impl GermanStr {
+    #[inline]
+    /// Returns the byte index of the first occurrence of `needle`.
+    pub fn find_bytes(&self, needle: impl Needle) -> Option<usize> {
+        needle.find_in(self.as_bytes())
+    }
+
+    #[inline]
+    /// Returns the byte index of the last occurrence of `needle`.
+    pub fn rfind_bytes(&self, needle: impl Needle) -> Option<usize> {
+        needle.rfind_in(self.as_bytes())
+    }
+
+    #[inline]
+    /// Returns whether `needle` occurs in `self`.
+    pub fn contains_bytes(&self, needle: impl Needle) -> bool {
+        self.find_bytes(needle).is_some()
+    }
+}
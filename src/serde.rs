@@ -0,0 +1,109 @@
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt;
+
+use serde::de::{DeserializeSeed, Deserializer, Error, Unexpected, Visitor};
+
+use crate::{GermanStr, GermanStrInterner, InitError};
+
+/// Visitor building a `GermanStr` from the deserialized `&str` with `F`.
+struct GermanStrVisitor<F>(F);
+
+impl<'a, F> Visitor<'a> for GermanStrVisitor<F>
+where
+    F: FnOnce(&str) -> Result<GermanStr, InitError>,
+{
+    type Value = GermanStr;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a string")
+    }
+
+    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+    where
+        E: Error,
+    {
+        (self.0)(v).map_err(Error::custom)
+    }
+
+    fn visit_borrowed_str<E>(self, v: &'a str) -> Result<Self::Value, E>
+    where
+        E: Error,
+    {
+        (self.0)(v).map_err(Error::custom)
+    }
+
+    fn visit_string<E>(self, v: String) -> Result<Self::Value, E>
+    where
+        E: Error,
+    {
+        (self.0)(&v).map_err(Error::custom)
+    }
+
+    fn visit_bytes<E>(self, v: &[u8]) -> Result<Self::Value, E>
+    where
+        E: Error,
+    {
+        match crate::str_from_utf8(v) {
+            Ok(s) => (self.0)(s).map_err(Error::custom),
+            Err(_) => Err(Error::invalid_value(Unexpected::Bytes(v), &self)),
+        }
+    }
+
+    fn visit_borrowed_bytes<E>(self, v: &'a [u8]) -> Result<Self::Value, E>
+    where
+        E: Error,
+    {
+        match crate::str_from_utf8(v) {
+            Ok(s) => (self.0)(s).map_err(Error::custom),
+            Err(_) => Err(Error::invalid_value(Unexpected::Bytes(v), &self)),
+        }
+    }
+
+    fn visit_byte_buf<E>(self, v: Vec<u8>) -> Result<Self::Value, E>
+    where
+        E: Error,
+    {
+        match crate::str_from_utf8(&v) {
+            Ok(s) => (self.0)(s).map_err(Error::custom),
+            Err(_) => Err(Error::invalid_value(Unexpected::Bytes(&v), &self)),
+        }
+    }
+}
+
+impl serde::Serialize for GermanStr {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        self.as_str().serialize(serializer)
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for GermanStr {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        deserializer.deserialize_str(GermanStrVisitor(|s: &str| GermanStr::new(s)))
+    }
+}
+
+/// `DeserializeSeed` that deserializes a `GermanStr` through a
+/// `GermanStrInterner`, so that equal strings share the same heap buffer.
+///
+/// The interned buffers aren't freed automatically: see
+/// `GermanStrInterner::free`.
+pub struct InternedGermanStr<'a>(pub &'a mut GermanStrInterner);
+
+impl<'de> DeserializeSeed<'de> for InternedGermanStr<'_> {
+    type Value = GermanStr;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let interner = self.0;
+        deserializer.deserialize_str(GermanStrVisitor(|s: &str| interner.intern(s)))
+    }
+}
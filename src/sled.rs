@@ -0,0 +1,41 @@
+//! `IVec` buffers are either inlined or reference counted, so they can't be
+//! adopted by a `GermanStr`: their bytes are copied once, without going
+//! through a `String`.
+
+use sled::IVec;
+
+use crate::{GermanStr, InitError};
+
+/// Validates the UTF-8 of `ivec`.
+impl TryFrom<IVec> for GermanStr {
+    type Error = InitError;
+
+    #[inline]
+    fn try_from(ivec: IVec) -> Result<GermanStr, InitError> {
+        GermanStr::try_from(&ivec)
+    }
+}
+
+/// Validates the UTF-8 of `ivec`.
+impl TryFrom<&IVec> for GermanStr {
+    type Error = InitError;
+
+    #[inline]
+    fn try_from(ivec: &IVec) -> Result<GermanStr, InitError> {
+        GermanStr::new(crate::str_from_utf8(ivec)?)
+    }
+}
+
+impl From<GermanStr> for IVec {
+    #[inline]
+    fn from(s: GermanStr) -> IVec {
+        IVec::from(s.as_bytes())
+    }
+}
+
+impl From<&GermanStr> for IVec {
+    #[inline]
+    fn from(s: &GermanStr) -> IVec {
+        IVec::from(s.as_bytes())
+    }
+}
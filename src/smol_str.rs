@@ -0,0 +1,35 @@
+use smol_str::SmolStr;
+
+use crate::{GermanStr, InitError};
+
+impl TryFrom<SmolStr> for GermanStr {
+    type Error = InitError;
+
+    #[inline]
+    fn try_from(s: SmolStr) -> Result<GermanStr, InitError> {
+        GermanStr::new(s.as_str())
+    }
+}
+
+impl TryFrom<&SmolStr> for GermanStr {
+    type Error = InitError;
+
+    #[inline]
+    fn try_from(s: &SmolStr) -> Result<GermanStr, InitError> {
+        GermanStr::new(s.as_str())
+    }
+}
+
+impl From<GermanStr> for SmolStr {
+    #[inline]
+    fn from(s: GermanStr) -> SmolStr {
+        SmolStr::new(s.as_str())
+    }
+}
+
+impl From<&GermanStr> for SmolStr {
+    #[inline]
+    fn from(s: &GermanStr) -> SmolStr {
+        SmolStr::new(s.as_str())
+    }
+}
@@ -0,0 +1,71 @@
+use core::cmp;
+use core::ops::Range;
+
+use crate::{be_u32, str_prefix, str_suffix, GermanStr};
+
+/// Binary searches `needle` in `sorted`, like `<[GermanStr]>::binary_search`.
+///
+/// The prefix of `needle` is only computed once, and the strings of
+/// `sorted` are compared with it first: their heap buffers are only read
+/// when their prefix is equal to the prefix of `needle`.
+/// ```
+/// use german_str::{binary_search_german, GermanStr};
+///
+/// let index: Vec<GermanStr> = ["apple", "banana, which is long", "cherry"]
+///     .into_iter()
+///     .map(|s| GermanStr::new(s).unwrap())
+///     .collect();
+/// assert_eq!(binary_search_german(&index, "banana, which is long"), Ok(1));
+/// assert_eq!(binary_search_german(&index, "blueberry"), Err(2));
+/// ```
+pub fn binary_search_german(sorted: &[GermanStr], needle: &str) -> Result<usize, usize> {
+    let needle_prefix = be_u32(str_prefix::<&str>(needle));
+    let needle_suffix = str_suffix::<&str>(&needle);
+    sorted.binary_search_by(|german| {
+        be_u32(german.prefix)
+            .cmp(&needle_prefix)
+            .then_with(|| german.suffix_bytes_slice().cmp(needle_suffix))
+            // Prefixes are padded with 0s: "a" and "a\0" are only told apart by their len.
+            .then_with(|| german.len().cmp(&needle.len()))
+    })
+}
+
+/// Returns the range of the strings of `sorted` which start with `prefix`.
+///
+/// If `prefix` is at most 4 bytes long, only the prefixes stored in the
+/// strings are compared, and their heap buffers are never read. Otherwise,
+/// they are only read when the first 4 bytes are equal.
+/// ```
+/// use german_str::{equal_range_by_prefix, GermanStr};
+///
+/// let index: Vec<GermanStr> = ["car", "card", "care instructions", "cart", "dog"]
+///     .into_iter()
+///     .map(|s| GermanStr::new(s).unwrap())
+///     .collect();
+/// assert_eq!(equal_range_by_prefix(&index, "car"), 0..4);
+/// assert_eq!(equal_range_by_prefix(&index, "care"), 2..3);
+/// assert_eq!(equal_range_by_prefix(&index, "cat"), 4..4);
+/// ```
+pub fn equal_range_by_prefix(sorted: &[GermanStr], prefix: &str) -> Range<usize> {
+    // Keeps the bytes of the stored prefixes which are compared with the
+    // first bytes of `prefix`.
+    let mask = !u32::MAX.checked_shr(8 * prefix.len().min(4) as u32).unwrap_or(0);
+    let prefix_word = be_u32(str_prefix::<&str>(prefix));
+    let prefix_suffix = str_suffix::<&str>(&prefix);
+    // Compares the first `prefix.len()` bytes of `german` with `prefix`.
+    let cmp_start = |german: &GermanStr| {
+        (be_u32(german.prefix) & mask)
+            .cmp(&prefix_word)
+            .then_with(|| {
+                if prefix_suffix.is_empty() {
+                    return cmp::Ordering::Equal;
+                }
+                let suffix = german.suffix_bytes_slice();
+                suffix[..suffix.len().min(prefix_suffix.len())].cmp(prefix_suffix)
+            })
+            .then_with(|| german.len().min(prefix.len()).cmp(&prefix.len()))
+    };
+    let start = sorted.partition_point(|german| cmp_start(german).is_lt());
+    let len = sorted[start..].partition_point(|german| cmp_start(german).is_eq());
+    start..start + len
+}
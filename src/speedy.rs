@@ -0,0 +1,41 @@
+use alloc::vec::Vec;
+
+use speedy::{Context, Readable, Reader, Writable, Writer};
+
+use crate::{GermanStr, MAX_INLINE_BYTES};
+
+// `GermanStr`s are encoded like `String`s: their length as a u32,
+// followed by their bytes.
+
+impl<C: Context> Writable<C> for GermanStr {
+    #[inline]
+    fn write_to<W: ?Sized + Writer<C>>(&self, writer: &mut W) -> Result<(), C::Error> {
+        self.as_str().write_to(writer)
+    }
+
+    #[inline]
+    fn bytes_needed(&self) -> Result<usize, C::Error> {
+        Writable::<C>::bytes_needed(self.as_str())
+    }
+}
+
+impl<'a, C: Context> Readable<'a, C> for GermanStr {
+    /// Strings of at most `MAX_INLINE_BYTES` are read without any
+    /// allocation, longer ones are read into their heap buffer.
+    fn read_from<R: Reader<'a, C>>(reader: &mut R) -> Result<GermanStr, C::Error> {
+        let len = reader.read_u32()? as usize;
+        if len <= MAX_INLINE_BYTES {
+            let mut buf = [0; MAX_INLINE_BYTES];
+            reader.read_bytes(&mut buf[..len])?;
+            let src = crate::str_from_utf8(&buf[..len]).map_err(speedy::Error::custom)?;
+            return Ok(GermanStr::new_inline(src));
+        }
+        let bytes: Vec<u8> = reader.read_vec(len)?;
+        Ok(GermanStr::from_utf8(bytes).map_err(speedy::Error::custom)?)
+    }
+
+    #[inline]
+    fn minimum_bytes_needed() -> usize {
+        <&str as Readable<'a, C>>::minimum_bytes_needed()
+    }
+}
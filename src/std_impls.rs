@@ -0,0 +1,51 @@
+//! Conversions between `GermanStr` and the `std` OS string and path types.
+//!
+//! `std::error::Error` is the same trait as `core::error::Error`, which is
+//! implemented by the error types without requiring this feature.
+
+use std::ffi::{OsStr, OsString};
+use std::path::{Path, PathBuf};
+
+use crate::GermanStr;
+
+impl AsRef<OsStr> for GermanStr {
+    #[inline(always)]
+    fn as_ref(&self) -> &OsStr {
+        OsStr::new(self.as_str())
+    }
+}
+
+impl AsRef<Path> for GermanStr {
+    #[inline(always)]
+    fn as_ref(&self) -> &Path {
+        Path::new(self.as_str())
+    }
+}
+
+impl From<GermanStr> for OsString {
+    #[inline]
+    fn from(s: GermanStr) -> Self {
+        OsString::from(s.as_str())
+    }
+}
+
+impl From<GermanStr> for PathBuf {
+    #[inline]
+    fn from(s: GermanStr) -> Self {
+        PathBuf::from(s.as_str())
+    }
+}
+
+impl PartialEq<OsStr> for GermanStr {
+    #[inline(always)]
+    fn eq(&self, other: &OsStr) -> bool {
+        self.as_str() == other
+    }
+}
+
+impl PartialEq<GermanStr> for OsStr {
+    #[inline(always)]
+    fn eq(&self, other: &GermanStr) -> bool {
+        self == other.as_str()
+    }
+}
@@ -0,0 +1,235 @@
+use core::borrow::Borrow;
+use core::cmp;
+use core::fmt;
+use core::marker::PhantomData;
+use core::ops::Deref;
+use core::ptr::NonNull;
+use core::slice;
+
+use crate::{bytes_prefix, GermanStr, InitError, MAX_LEN};
+
+/// A borrowed string with the same fast comparisons as `GermanStr`.
+///
+/// Like a `GermanStr`, it stores its length and first 4 bytes inline, but
+/// always points to a `&'a str` it doesn't own, which makes it cheap to
+/// create while scanning existing buffers.
+/// `size_of::<GermanStrRef>() == 16`.
+#[derive(Clone, Copy)]
+#[repr(C)]
+pub struct GermanStrRef<'a> {
+    len: u32,
+    prefix: [u8; 4],
+    ptr: NonNull<u8>,
+    phantom: PhantomData<&'a str>,
+}
+
+// Safety: GermanStrRef is equivalent to a &str.
+unsafe impl Send for GermanStrRef<'_> {}
+unsafe impl Sync for GermanStrRef<'_> {}
+
+impl<'a> GermanStrRef<'a> {
+    #[inline]
+    pub const fn new(src: &'a str) -> Result<Self, InitError> {
+        if src.len() > MAX_LEN {
+            return Err(InitError::TooLong);
+        }
+        let bytes = src.as_bytes();
+        let mut prefix = [0; 4];
+        let mut i = 0;
+        while i < bytes.len() && i < 4 {
+            prefix[i] = bytes[i];
+            i += 1;
+        }
+        Ok(GermanStrRef {
+            len: src.len() as u32,
+            prefix,
+            ptr: unsafe {
+                // Safety: references are never null.
+                NonNull::new_unchecked(bytes.as_ptr().cast_mut())
+            },
+            phantom: PhantomData,
+        })
+    }
+
+    #[inline(always)]
+    pub const fn as_str(&self) -> &'a str {
+        unsafe {
+            // Safety: ptr and len come from a &'a str.
+            let bytes = slice::from_raw_parts(self.ptr.as_ptr().cast_const(), self.len as usize);
+            core::str::from_utf8_unchecked(bytes)
+        }
+    }
+
+    #[inline(always)]
+    pub const fn len(&self) -> usize {
+        self.len as usize
+    }
+
+    #[inline(always)]
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    #[inline(always)]
+    /// Returns an array containing the first 4 bytes of the string.
+    /// If the string is shorter than 4 bytes, extra bytes are set to 0.
+    pub const fn prefix_bytes_array(&self) -> [u8; 4] {
+        self.prefix
+    }
+
+    #[inline]
+    /// Copies the string into a `GermanStr`.
+    pub fn to_german_str(&self) -> GermanStr {
+        GermanStr::new(self.as_str()).expect("GermanStrRef are at most MAX_LEN bytes long")
+    }
+}
+
+impl Default for GermanStrRef<'_> {
+    #[inline(always)]
+    fn default() -> Self {
+        GermanStrRef::new("").unwrap()
+    }
+}
+
+impl Deref for GermanStrRef<'_> {
+    type Target = str;
+
+    #[inline(always)]
+    fn deref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl AsRef<str> for GermanStrRef<'_> {
+    #[inline(always)]
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl Borrow<str> for GermanStrRef<'_> {
+    #[inline(always)]
+    fn borrow(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl PartialEq for GermanStrRef<'_> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.len == other.len
+            && self.prefix == other.prefix
+            && (self.len <= 4 || self.as_str() == other.as_str())
+    }
+}
+
+impl Eq for GermanStrRef<'_> {}
+
+impl Ord for GermanStrRef<'_> {
+    #[inline]
+    fn cmp(&self, other: &Self) -> cmp::Ordering {
+        self.prefix
+            .cmp(&other.prefix)
+            .then_with(|| self.as_bytes().cmp(other.as_bytes()))
+    }
+}
+
+impl PartialOrd for GermanStrRef<'_> {
+    #[inline(always)]
+    fn partial_cmp(&self, other: &Self) -> Option<cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl core::hash::Hash for GermanStrRef<'_> {
+    #[inline(always)]
+    fn hash<H: core::hash::Hasher>(&self, hasher: &mut H) {
+        self.as_str().hash(hasher);
+    }
+}
+
+impl PartialEq<GermanStr> for GermanStrRef<'_> {
+    #[inline]
+    fn eq(&self, other: &GermanStr) -> bool {
+        self.len() == other.len()
+            && self.prefix == other.prefix_bytes_array()
+            && self.as_bytes().get(4..).unwrap_or_default() == other.suffix_bytes_slice()
+    }
+}
+
+impl PartialEq<GermanStrRef<'_>> for GermanStr {
+    #[inline(always)]
+    fn eq(&self, other: &GermanStrRef<'_>) -> bool {
+        other == self
+    }
+}
+
+impl PartialOrd<GermanStr> for GermanStrRef<'_> {
+    #[inline]
+    fn partial_cmp(&self, other: &GermanStr) -> Option<cmp::Ordering> {
+        Some(other.cmp_str(self.as_str()).reverse())
+    }
+}
+
+impl PartialOrd<GermanStrRef<'_>> for GermanStr {
+    #[inline]
+    fn partial_cmp(&self, other: &GermanStrRef<'_>) -> Option<cmp::Ordering> {
+        Some(self.cmp_str(other.as_str()))
+    }
+}
+
+impl PartialEq<str> for GermanStrRef<'_> {
+    #[inline(always)]
+    fn eq(&self, other: &str) -> bool {
+        self.len() == other.len() && self.prefix == bytes_prefix(other.as_bytes()) && self.as_str() == other
+    }
+}
+
+impl PartialEq<&str> for GermanStrRef<'_> {
+    #[inline(always)]
+    fn eq(&self, other: &&str) -> bool {
+        self == *other
+    }
+}
+
+impl<'a> From<&'a GermanStr> for GermanStrRef<'a> {
+    #[inline]
+    fn from(s: &'a GermanStr) -> Self {
+        GermanStrRef {
+            len: s.len() as u32,
+            prefix: s.prefix_bytes_array(),
+            ptr: NonNull::from(s.as_bytes()).cast(),
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<'a> TryFrom<&'a str> for GermanStrRef<'a> {
+    type Error = InitError;
+
+    #[inline]
+    fn try_from(s: &'a str) -> Result<Self, Self::Error> {
+        GermanStrRef::new(s)
+    }
+}
+
+impl From<GermanStrRef<'_>> for GermanStr {
+    #[inline]
+    fn from(s: GermanStrRef<'_>) -> Self {
+        s.to_german_str()
+    }
+}
+
+impl fmt::Debug for GermanStrRef<'_> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Debug::fmt(self.as_str(), f)
+    }
+}
+
+impl fmt::Display for GermanStrRef<'_> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(self.as_str(), f)
+    }
+}
@@ -0,0 +1,75 @@
+//! `proptest` strategies generating `GermanStr`s that exercise the edges of
+//! their representation.
+
+use alloc::string::String;
+
+use proptest::arbitrary::{any, Arbitrary};
+use proptest::collection::vec;
+use proptest::prop_oneof;
+use proptest::strategy::{BoxedStrategy, Just, Strategy};
+
+use crate::{fill_to_len, GermanStr, MAX_INLINE_BYTES};
+
+/// Strings of exactly `len` bytes, mixing single and multi-byte chars.
+fn with_byte_len(len: impl Strategy<Value = usize>) -> impl Strategy<Value = GermanStr> {
+    (len, vec(any::<char>(), 0..64))
+        .prop_map(|(len, chars)| GermanStr::new(fill_to_len(&chars, len)).unwrap())
+}
+
+/// `GermanStr`s stored inline (at most `MAX_INLINE_BYTES` bytes).
+pub fn inline() -> impl Strategy<Value = GermanStr> {
+    with_byte_len(0..=MAX_INLINE_BYTES)
+}
+
+/// `GermanStr`s stored on the heap (more than `MAX_INLINE_BYTES` bytes).
+pub fn heap() -> impl Strategy<Value = GermanStr> {
+    with_byte_len(MAX_INLINE_BYTES + 1..=4 * MAX_INLINE_BYTES)
+}
+
+/// `GermanStr`s whose length is right around `MAX_INLINE_BYTES`
+/// (11, 12 or 13 bytes).
+pub fn boundary() -> impl Strategy<Value = GermanStr> {
+    with_byte_len(prop_oneof![
+        Just(MAX_INLINE_BYTES - 1),
+        Just(MAX_INLINE_BYTES),
+        Just(MAX_INLINE_BYTES + 1),
+    ])
+}
+
+/// `GermanStr`s with a multi-byte char that starts in the 4 bytes prefix
+/// and ends after it.
+pub fn straddling_prefix() -> impl Strategy<Value = GermanStr> {
+    (1..=3_usize, any::<char>(), any::<String>())
+        .prop_filter_map("char doesn't straddle the prefix", |(ascii, c, tail)| {
+            if ascii + c.len_utf8() <= 4 {
+                return None;
+            }
+            let mut s = String::with_capacity(ascii + c.len_utf8() + tail.len());
+            for _ in 0..ascii {
+                s.push('a');
+            }
+            s.push(c);
+            s.push_str(&tail);
+            Some(GermanStr::new(s).unwrap())
+        })
+}
+
+/// Any `GermanStr`, with extra weight on the representation edges.
+pub fn any_german_str() -> impl Strategy<Value = GermanStr> {
+    prop_oneof![
+        any::<String>().prop_map(|s| GermanStr::new(s).unwrap()),
+        inline(),
+        heap(),
+        boundary(),
+        straddling_prefix(),
+    ]
+}
+
+impl Arbitrary for GermanStr {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<GermanStr>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        any_german_str().boxed()
+    }
+}
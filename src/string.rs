@@ -0,0 +1,264 @@
+use alloc::string::String;
+use core::fmt;
+use core::ops::{Deref, DerefMut};
+
+use crate::{GermanStr, InitError, MAX_INLINE_BYTES, MAX_LEN};
+
+/// A growable string, which can be turned into a `GermanStr` without copying
+/// its buffer, like a `String` into a `Box<str>`.
+#[derive(Clone, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct GermanString {
+    buf: String,
+}
+
+impl GermanString {
+    #[inline]
+    pub const fn new() -> Self {
+        GermanString { buf: String::new() }
+    }
+
+    #[inline]
+    pub fn with_capacity(capacity: usize) -> Self {
+        GermanString {
+            buf: String::with_capacity(capacity),
+        }
+    }
+
+    #[inline(always)]
+    pub fn as_str(&self) -> &str {
+        self.buf.as_str()
+    }
+
+    #[inline(always)]
+    pub fn as_mut_str(&mut self) -> &mut str {
+        self.buf.as_mut_str()
+    }
+
+    #[inline(always)]
+    pub fn len(&self) -> usize {
+        self.buf.len()
+    }
+
+    #[inline(always)]
+    pub fn is_empty(&self) -> bool {
+        self.buf.is_empty()
+    }
+
+    #[inline(always)]
+    pub fn capacity(&self) -> usize {
+        self.buf.capacity()
+    }
+
+    #[inline]
+    pub fn reserve(&mut self, additional: usize) {
+        self.buf.reserve(additional);
+    }
+
+    #[inline]
+    pub fn push(&mut self, c: char) {
+        self.buf.push(c);
+    }
+
+    #[inline]
+    pub fn push_str(&mut self, s: &str) {
+        self.buf.push_str(s);
+    }
+
+    #[inline]
+    /// Panics if `idx` is out of bounds or not on a char boundary.
+    pub fn insert(&mut self, idx: usize, c: char) {
+        self.buf.insert(idx, c);
+    }
+
+    #[inline]
+    /// Panics if `idx` is out of bounds or not on a char boundary.
+    pub fn insert_str(&mut self, idx: usize, s: &str) {
+        self.buf.insert_str(idx, s);
+    }
+
+    #[inline]
+    pub fn pop(&mut self) -> Option<char> {
+        self.buf.pop()
+    }
+
+    #[inline]
+    /// Shortens the string to `new_len` bytes. Does nothing if `new_len` is
+    /// greater than the current length.
+    ///
+    /// Panics if `new_len` isn't on a char boundary.
+    pub fn truncate(&mut self, new_len: usize) {
+        self.buf.truncate(new_len);
+    }
+
+    #[inline]
+    pub fn clear(&mut self) {
+        self.buf.clear();
+    }
+
+    /// Converts `self` into a `GermanStr`.
+    ///
+    /// Short strings are inlined, longer ones hand their buffer over to the
+    /// `GermanStr`. Like `String::into_boxed_str`, extra capacity is
+    /// released first.
+    pub fn freeze(self) -> Result<GermanStr, InitError> {
+        if self.buf.len() <= MAX_INLINE_BYTES {
+            return Ok(GermanStr::new_inline(&self.buf));
+        }
+        if self.buf.len() > MAX_LEN {
+            return Err(InitError::TooLong);
+        }
+        let buf = self.buf.into_boxed_str().into_boxed_bytes();
+        Ok(unsafe {
+            // Safety: buf comes from a String, and its len was checked.
+            GermanStr::from_boxed_bytes_unchecked(buf)
+        })
+    }
+}
+
+impl Deref for GermanString {
+    type Target = str;
+
+    #[inline(always)]
+    fn deref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl DerefMut for GermanString {
+    #[inline(always)]
+    fn deref_mut(&mut self) -> &mut str {
+        self.as_mut_str()
+    }
+}
+
+impl AsRef<str> for GermanString {
+    #[inline(always)]
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Write for GermanString {
+    #[inline]
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.push_str(s);
+        Ok(())
+    }
+
+    #[inline]
+    fn write_char(&mut self, c: char) -> fmt::Result {
+        self.push(c);
+        Ok(())
+    }
+}
+
+impl fmt::Debug for GermanString {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Debug::fmt(self.as_str(), f)
+    }
+}
+
+impl fmt::Display for GermanString {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(self.as_str(), f)
+    }
+}
+
+impl From<&str> for GermanString {
+    #[inline]
+    fn from(s: &str) -> Self {
+        GermanString { buf: s.into() }
+    }
+}
+
+impl From<String> for GermanString {
+    #[inline(always)]
+    fn from(buf: String) -> Self {
+        GermanString { buf }
+    }
+}
+
+impl From<&GermanStr> for GermanString {
+    #[inline]
+    fn from(s: &GermanStr) -> Self {
+        GermanString { buf: s.as_str().into() }
+    }
+}
+
+impl From<GermanString> for String {
+    #[inline(always)]
+    fn from(s: GermanString) -> Self {
+        s.buf
+    }
+}
+
+impl TryFrom<GermanString> for GermanStr {
+    type Error = InitError;
+
+    #[inline]
+    fn try_from(s: GermanString) -> Result<Self, Self::Error> {
+        s.freeze()
+    }
+}
+
+impl Extend<char> for GermanString {
+    #[inline]
+    fn extend<I: IntoIterator<Item = char>>(&mut self, iter: I) {
+        self.buf.extend(iter);
+    }
+}
+
+impl<'a> Extend<&'a str> for GermanString {
+    #[inline]
+    fn extend<I: IntoIterator<Item = &'a str>>(&mut self, iter: I) {
+        self.buf.extend(iter);
+    }
+}
+
+impl FromIterator<char> for GermanString {
+    #[inline]
+    fn from_iter<I: IntoIterator<Item = char>>(iter: I) -> Self {
+        GermanString {
+            buf: String::from_iter(iter),
+        }
+    }
+}
+
+impl<'a> FromIterator<&'a str> for GermanString {
+    #[inline]
+    fn from_iter<I: IntoIterator<Item = &'a str>>(iter: I) -> Self {
+        GermanString {
+            buf: String::from_iter(iter),
+        }
+    }
+}
+
+impl PartialEq<str> for GermanString {
+    #[inline(always)]
+    fn eq(&self, other: &str) -> bool {
+        self.as_str() == other
+    }
+}
+
+impl PartialEq<&str> for GermanString {
+    #[inline(always)]
+    fn eq(&self, other: &&str) -> bool {
+        self.as_str() == *other
+    }
+}
+
+impl PartialEq<GermanStr> for GermanString {
+    #[inline(always)]
+    fn eq(&self, other: &GermanStr) -> bool {
+        other == self.as_str()
+    }
+}
+
+impl PartialEq<GermanString> for GermanStr {
+    #[inline(always)]
+    fn eq(&self, other: &GermanString) -> bool {
+        self == other.as_str()
+    }
+}
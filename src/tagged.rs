@@ -0,0 +1,90 @@
+use core::num::NonZeroUsize;
+use core::ptr::NonNull;
+
+/// The 4 highest bits of an address, which aren't used by userspace
+/// pointers on 64-bit platforms: x86_64 and aarch64 use at most 57 bits of
+/// virtual address space.
+const STOLEN_MASK: usize = !(usize::MAX >> 4);
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[repr(transparent)]
+/// A non-null pointer to u8, with the 4 high bits of its address stolen to
+/// store flags (see `OWNED_PTR`, `ASCII_PTR`, `ALIGNED_PTR` and
+/// `FOREIGN_PTR`).
+///
+/// Bits are only stolen and restored with strict provenance APIs
+/// (`map_addr`), which keep the provenance of the original pointer: the
+/// pointer is never round-tripped through an integer.
+pub(crate) struct TaggedPtr(NonNull<u8>);
+
+impl TaggedPtr {
+    #[inline(always)]
+    /// Wraps `ptr` without modifying it: its stolen bits are 0.
+    pub(crate) const fn new(ptr: NonNull<u8>) -> TaggedPtr {
+        TaggedPtr(ptr)
+    }
+
+    #[inline(always)]
+    /// Wraps `ptr`, replacing its stolen bits by those of `bits`.
+    pub(crate) fn new_stealing(ptr: NonNull<u8>, bits: usize) -> TaggedPtr {
+        TaggedPtr(ptr).steal(bits)
+    }
+
+    #[inline(always)]
+    /// Returns the stolen bits, with the other bits set to 0.
+    pub(crate) fn stolen(self) -> usize {
+        self.0.addr().get() & STOLEN_MASK
+    }
+
+    #[inline(always)]
+    /// Replaces the stolen bits by those of `bits`.
+    pub(crate) fn steal(self, bits: usize) -> TaggedPtr {
+        TaggedPtr(self.0.map_addr(|addr| {
+            let addr = (addr.get() & !STOLEN_MASK) | (bits & STOLEN_MASK);
+            unsafe {
+                // Safety: the unstolen bits of a non-null userspace address
+                // are never all 0.
+                NonZeroUsize::new_unchecked(addr)
+            }
+        }))
+    }
+
+    #[inline(always)]
+    /// Returns the original pointer: the stolen bits are restored by
+    /// sign-extending the rest of the address.
+    pub(crate) fn as_non_null(self) -> NonNull<u8> {
+        self.0.map_addr(|addr| {
+            let addr = (((addr.get() << 4) as isize) >> 4) as usize;
+            unsafe {
+                // Safety: same as in `steal`.
+                NonZeroUsize::new_unchecked(addr)
+            }
+        })
+    }
+
+    #[cfg(feature = "portable-atomic")]
+    #[inline(always)]
+    /// Exposes the provenance of the pointer, so that it can be recovered
+    /// by `with_exposed_provenance` after its bits are copied as an integer.
+    pub(crate) fn expose_provenance(self) -> usize {
+        self.0.as_ptr().expose_provenance()
+    }
+
+    #[cfg(feature = "portable-atomic")]
+    #[inline(always)]
+    /// Returns a pointer with the same address and the provenance which was
+    /// exposed for it, for a pointer copied from its bits.
+    ///
+    /// This isn't compatible with strict provenance.
+    ///
+    /// # Safety
+    /// The provenance of the pointer must have been exposed by
+    /// `expose_provenance`.
+    pub(crate) unsafe fn with_exposed_provenance(self) -> TaggedPtr {
+        let ptr = core::ptr::with_exposed_provenance_mut::<u8>(self.0.addr().get());
+        TaggedPtr(unsafe {
+            // Safety: the address is the one of self.
+            NonNull::new_unchecked(ptr)
+        })
+    }
+}
@@ -0,0 +1,45 @@
+use alloc::string::String;
+use core::iter::Empty;
+
+use tantivy::schema::document::{
+    DeserializeError, ReferenceValue, ReferenceValueLeaf, ValueDeserialize, ValueDeserializer,
+};
+use tantivy::schema::{OwnedValue, Value};
+
+use crate::GermanStr;
+
+// Lets custom documents store their text fields as `GermanStr`s: they
+// are indexed from the borrowed `&str`, without any copy.
+impl<'a> Value<'a> for &'a GermanStr {
+    type ArrayIter = Empty<&'a GermanStr>;
+    type ObjectIter = Empty<(&'a str, &'a GermanStr)>;
+
+    #[inline]
+    fn as_value(&self) -> ReferenceValue<'a, Self> {
+        ReferenceValue::Leaf(ReferenceValueLeaf::Str(GermanStr::as_str(self)))
+    }
+}
+
+impl ValueDeserialize for GermanStr {
+    #[inline]
+    fn deserialize<'de, D>(deserializer: D) -> Result<Self, DeserializeError>
+    where
+        D: ValueDeserializer<'de>,
+    {
+        GermanStr::new(deserializer.deserialize_string()?).map_err(DeserializeError::custom)
+    }
+}
+
+impl From<GermanStr> for OwnedValue {
+    #[inline]
+    fn from(s: GermanStr) -> OwnedValue {
+        OwnedValue::Str(String::from(s))
+    }
+}
+
+impl From<&GermanStr> for OwnedValue {
+    #[inline]
+    fn from(s: &GermanStr) -> OwnedValue {
+        OwnedValue::Str(String::from(s.as_str()))
+    }
+}
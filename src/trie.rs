@@ -0,0 +1,173 @@
+use alloc::collections::BTreeMap;
+use alloc::vec::Vec;
+use core::fmt;
+
+use crate::{bytes_prefix, GermanStr};
+
+/// A sorted map keyed by `GermanStr`, optimized for prefix queries.
+///
+/// The first level of the trie groups entries by the 4-byte prefix stored
+/// in their key, so finding the entries starting with a given string only
+/// compares prefixes until it reaches the matching groups, instead of
+/// comparing whole keys like a `BTreeMap<GermanStr, V>` range.
+/// ```
+/// use german_str::{GermanStr, GermanStrTrie};
+///
+/// let mut trie = GermanStrTrie::new();
+/// for (i, word) in ["foo", "foobar", "fob", "bar"].into_iter().enumerate() {
+///     trie.insert(GermanStr::new(word).unwrap(), i);
+/// }
+/// let foo: Vec<_> = trie.iter_prefix("foo").map(|(k, v)| (k.as_str(), *v)).collect();
+/// assert_eq!(foo, [("foo", 0), ("foobar", 1)]);
+/// ```
+pub struct GermanStrTrie<V> {
+    /// Entries grouped by the prefix of their key (padded with 0s), and
+    /// sorted by key within each group.
+    groups: BTreeMap<[u8; 4], Vec<(GermanStr, V)>>,
+
+    len: usize,
+}
+
+impl<V> GermanStrTrie<V> {
+    #[inline]
+    pub const fn new() -> Self {
+        GermanStrTrie {
+            groups: BTreeMap::new(),
+            len: 0,
+        }
+    }
+
+    #[inline(always)]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    #[inline(always)]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Inserts `value` for `key`, and returns the previous value if the key
+    /// was already present.
+    pub fn insert(&mut self, key: GermanStr, value: V) -> Option<V> {
+        let group = self.groups.entry(key.prefix_bytes_array()).or_default();
+        match group.binary_search_by(|(k, _)| k.cmp(&key)) {
+            Ok(i) => Some(core::mem::replace(&mut group[i].1, value)),
+            Err(i) => {
+                group.insert(i, (key, value));
+                self.len += 1;
+                None
+            }
+        }
+    }
+
+    #[inline]
+    pub fn get(&self, key: &str) -> Option<&V> {
+        let group = self.groups.get(&bytes_prefix(key.as_bytes()))?;
+        let i = group.binary_search_by(|(k, _)| k.as_str().cmp(key)).ok()?;
+        Some(&group[i].1)
+    }
+
+    #[inline]
+    pub fn get_mut(&mut self, key: &str) -> Option<&mut V> {
+        let group = self.groups.get_mut(&bytes_prefix(key.as_bytes()))?;
+        let i = group.binary_search_by(|(k, _)| k.as_str().cmp(key)).ok()?;
+        Some(&mut group[i].1)
+    }
+
+    #[inline]
+    pub fn contains_key(&self, key: &str) -> bool {
+        self.get(key).is_some()
+    }
+
+    /// Removes `key` from the trie, and returns its value.
+    pub fn remove(&mut self, key: &str) -> Option<V> {
+        let prefix = bytes_prefix(key.as_bytes());
+        let group = self.groups.get_mut(&prefix)?;
+        let i = group.binary_search_by(|(k, _)| k.as_str().cmp(key)).ok()?;
+        let (_, value) = group.remove(i);
+        if group.is_empty() {
+            self.groups.remove(&prefix);
+        }
+        self.len -= 1;
+        Some(value)
+    }
+
+    #[inline]
+    /// Iterates over all the entries, sorted by key.
+    pub fn iter(&self) -> impl Iterator<Item = (&GermanStr, &V)> + '_ {
+        self.groups.values().flatten().map(|(k, v)| (k, v))
+    }
+
+    /// Iterates over the entries whose key starts with `prefix`, sorted by
+    /// key.
+    pub fn iter_prefix<'a>(
+        &'a self,
+        prefix: &'a str,
+    ) -> impl Iterator<Item = (&'a GermanStr, &'a V)> + 'a {
+        let bytes = prefix.as_bytes();
+        // Keys starting with `prefix` have a prefix between `prefix` padded
+        // with 0s, and `prefix` padded with 0xFF.
+        let start = bytes_prefix(bytes);
+        let mut end = [u8::MAX; 4];
+        end[..bytes.len().min(4)].copy_from_slice(&start[..bytes.len().min(4)]);
+        self.groups
+            .range(start..=end)
+            .flat_map(move |(_, group)| {
+                // Within a group, matching keys are contiguous.
+                let first = group.partition_point(|(k, _)| k.as_str() < prefix);
+                group[first..]
+                    .iter()
+                    .take_while(move |(k, _)| k.starts_with(prefix))
+            })
+            .map(|(k, v)| (k, v))
+    }
+
+    #[inline]
+    pub fn clear(&mut self) {
+        self.groups.clear();
+        self.len = 0;
+    }
+}
+
+impl<V> Default for GermanStrTrie<V> {
+    #[inline]
+    fn default() -> Self {
+        GermanStrTrie::new()
+    }
+}
+
+impl<V: Clone> Clone for GermanStrTrie<V> {
+    #[inline]
+    fn clone(&self) -> Self {
+        GermanStrTrie {
+            groups: self.groups.clone(),
+            len: self.len,
+        }
+    }
+}
+
+impl<V> Extend<(GermanStr, V)> for GermanStrTrie<V> {
+    #[inline]
+    fn extend<I: IntoIterator<Item = (GermanStr, V)>>(&mut self, iter: I) {
+        for (k, v) in iter {
+            self.insert(k, v);
+        }
+    }
+}
+
+impl<V> FromIterator<(GermanStr, V)> for GermanStrTrie<V> {
+    #[inline]
+    fn from_iter<I: IntoIterator<Item = (GermanStr, V)>>(iter: I) -> Self {
+        let mut trie = GermanStrTrie::new();
+        trie.extend(iter);
+        trie
+    }
+}
+
+impl<V: fmt::Debug> fmt::Debug for GermanStrTrie<V> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
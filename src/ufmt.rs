@@ -0,0 +1,36 @@
+use ufmt::{uDebug, uDisplay, uWrite, Formatter};
+
+use crate::GermanStr;
+
+impl uDisplay for GermanStr {
+    #[inline]
+    fn fmt<W: uWrite + ?Sized>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error> {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Quotes and escapes the string like `core::fmt::Debug`, which `ufmt`
+/// doesn't implement for `str`.
+impl uDebug for GermanStr {
+    fn fmt<W: uWrite + ?Sized>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error> {
+        let s = self.as_str();
+        f.write_char('"')?;
+        // Start of the chars which don't need escaping and weren't
+        // written yet, to write them in a single call.
+        let mut from = 0;
+        for (i, c) in s.char_indices() {
+            // Single quotes are only escaped in chars.
+            let escaped = c.escape_debug();
+            if c == '\'' || escaped.len() == 1 {
+                continue;
+            }
+            f.write_str(s.get(from..i).unwrap_or_default())?;
+            for c in escaped {
+                f.write_char(c)?;
+            }
+            from = i + c.len_utf8();
+        }
+        f.write_str(s.get(from..).unwrap_or_default())?;
+        f.write_char('"')
+    }
+}
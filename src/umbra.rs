@@ -0,0 +1,201 @@
+use alloc::vec::Vec;
+use core::fmt;
+use core::marker::PhantomData;
+use core::ops::Deref;
+
+use crate::{GermanStr, MAX_INLINE_BYTES, MAX_LEN};
+
+const MAGIC: [u8; 4] = *b"GSTR";
+const VERSION: u32 = 1;
+const HEADER_LEN: usize = 16;
+const RECORD_LEN: usize = 16;
+
+/// A column of `GermanStr`s read from bytes in the Umbra format, without
+/// copying the strings' bytes.
+///
+/// The format mirrors the in-memory layout of `GermanStr`. All integers are
+/// little-endian:
+/// * a 16 bytes header: the magic bytes `b"GSTR"`, the version of the format
+///   as an u32 (currently 1), and the number of strings as an u64.
+/// * one 16 bytes record per string: its length as an u32, its first 4
+///   bytes (padded with 0s), then either the rest of the string (padded
+///   with 0s) if it's at most 12 bytes long, or the offset of the whole
+///   string in the payload as an u64.
+/// * the payload, containing the bytes of the long strings. Records may
+///   reference the same bytes.
+///
+/// The long strings of a column reference the payload directly as shared
+/// buffers. They can only be borrowed from the `UmbraColumn`, which borrows
+/// the source bytes: `clone()` them to get copies that outlive it.
+/// ```
+/// use german_str::{GermanStr, UmbraColumn};
+///
+/// let strings = [GermanStr::new("short").unwrap(), GermanStr::new("a string longer than 12 bytes").unwrap()];
+/// let mut bytes = Vec::new();
+/// UmbraColumn::write(&strings, &mut bytes);
+///
+/// let column = UmbraColumn::read(&bytes).unwrap();
+/// assert_eq!(column.as_slice(), strings);
+/// assert!(column[1].has_shared_buffer());
+/// ```
+pub struct UmbraColumn<'a> {
+    /// Heap-allocated strings have shared buffers pointing into the source
+    /// bytes.
+    strings: Vec<GermanStr>,
+    bytes: PhantomData<&'a [u8]>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+/// Represents the reasons why reading an `UmbraColumn` could fail.
+pub enum UmbraError {
+    /// The bytes don't start with a valid header, or were written by an
+    /// unsupported version of the format.
+    InvalidHeader,
+
+    /// The bytes are too short to contain every record.
+    Truncated,
+
+    /// The record at this index is invalid: its string is too long, is out
+    /// of the bounds of the payload, or doesn't match its prefix or padding.
+    InvalidRecord(usize),
+
+    /// The string of the record at this index isn't valid UTF-8.
+    InvalidUtf8(usize),
+}
+
+impl UmbraColumn<'_> {
+    /// Appends `strings` to `out`, in the Umbra format.
+    pub fn write(strings: &[GermanStr], out: &mut Vec<u8>) {
+        let payload_len: usize = strings
+            .iter()
+            .filter(|s| s.is_heap_allocated())
+            .map(|s| s.len())
+            .sum();
+        out.reserve(HEADER_LEN + strings.len() * RECORD_LEN + payload_len);
+
+        out.extend_from_slice(&MAGIC);
+        out.extend_from_slice(&VERSION.to_le_bytes());
+        out.extend_from_slice(&(strings.len() as u64).to_le_bytes());
+        let mut offset = 0u64;
+        for s in strings {
+            out.extend_from_slice(&(s.len() as u32).to_le_bytes());
+            out.extend_from_slice(&s.prefix_bytes_array());
+            if s.len() <= MAX_INLINE_BYTES {
+                let mut suffix = [0; 8];
+                suffix[..s.suffix_bytes_slice().len()].copy_from_slice(s.suffix_bytes_slice());
+                out.extend_from_slice(&suffix);
+            } else {
+                out.extend_from_slice(&offset.to_le_bytes());
+                offset += s.len() as u64;
+            }
+        }
+        for s in strings.iter().filter(|s| s.is_heap_allocated()) {
+            out.extend_from_slice(s.as_bytes());
+        }
+    }
+}
+
+impl<'a> UmbraColumn<'a> {
+    /// Reads the strings written by `UmbraColumn::write`.
+    ///
+    /// Every record is validated, which takes linear time, but the only
+    /// allocation is the `Vec` containing the strings.
+    pub fn read(bytes: &'a [u8]) -> Result<Self, UmbraError> {
+        let header = bytes.get(..HEADER_LEN).ok_or(UmbraError::InvalidHeader)?;
+        if header[..4] != MAGIC || u32::from_le_bytes(le_bytes(&header[4..8])) != VERSION {
+            return Err(UmbraError::InvalidHeader);
+        }
+        let count = u64::from_le_bytes(le_bytes(&header[8..16]));
+        let records_len = usize::try_from(count)
+            .ok()
+            .and_then(|count| count.checked_mul(RECORD_LEN))
+            .ok_or(UmbraError::Truncated)?;
+        let records = bytes[HEADER_LEN..]
+            .get(..records_len)
+            .ok_or(UmbraError::Truncated)?;
+        let payload = &bytes[HEADER_LEN + records_len..];
+
+        let mut strings = Vec::with_capacity(records_len / RECORD_LEN);
+        for (i, record) in records.chunks_exact(RECORD_LEN).enumerate() {
+            let len = u32::from_le_bytes(le_bytes(&record[..4])) as usize;
+            let prefix = &record[4..8];
+            let last8 = &record[8..];
+            if len > MAX_LEN {
+                return Err(UmbraError::InvalidRecord(i));
+            }
+            let german = if len <= MAX_INLINE_BYTES {
+                // The padding must be 0s, since comparisons rely on it.
+                if record[4 + len..].iter().any(|&b| b != 0) {
+                    return Err(UmbraError::InvalidRecord(i));
+                }
+                let s = crate::str_from_utf8(&record[4..4 + len])
+                    .map_err(|_| UmbraError::InvalidUtf8(i))?;
+                GermanStr::new_inline(s)
+            } else {
+                let s = usize::try_from(u64::from_le_bytes(le_bytes(last8)))
+                    .ok()
+                    .and_then(|start| payload.get(start..start.checked_add(len)?))
+                    .filter(|s| s[..4] == *prefix)
+                    .ok_or(UmbraError::InvalidRecord(i))?;
+                let s = crate::str_from_utf8(s).map_err(|_| UmbraError::InvalidUtf8(i))?;
+                unsafe {
+                    // Safety: `bytes` is borrowed by self, so it outlives
+                    // the strings, which can't be moved out of self.
+                    GermanStr::new_shared(s)
+                }
+            };
+            strings.push(german);
+        }
+        Ok(UmbraColumn {
+            strings,
+            bytes: PhantomData,
+        })
+    }
+
+    #[inline(always)]
+    pub fn as_slice(&self) -> &[GermanStr] {
+        &self.strings
+    }
+
+    #[inline]
+    /// Copies the strings, so that they can outlive the source bytes.
+    pub fn to_vec(&self) -> Vec<GermanStr> {
+        self.strings.to_vec()
+    }
+}
+
+#[inline(always)]
+fn le_bytes<const N: usize>(src: &[u8]) -> [u8; N] {
+    src.try_into().unwrap()
+}
+
+impl Deref for UmbraColumn<'_> {
+    type Target = [GermanStr];
+
+    #[inline(always)]
+    fn deref(&self) -> &[GermanStr] {
+        self.as_slice()
+    }
+}
+
+impl fmt::Debug for UmbraColumn<'_> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Debug::fmt(self.as_slice(), f)
+    }
+}
+
+impl fmt::Display for UmbraError {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            UmbraError::InvalidHeader => f.write_str("Invalid or unsupported Umbra column header."),
+            UmbraError::Truncated => f.write_str("Truncated Umbra column."),
+            UmbraError::InvalidRecord(i) => write!(f, "Invalid record at index {i} of an Umbra column."),
+            UmbraError::InvalidUtf8(i) => write!(f, "Invalid UTF-8 in record {i} of an Umbra column."),
+        }
+    }
+}
+
+impl core::error::Error for UmbraError {}
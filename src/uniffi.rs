@@ -0,0 +1,12 @@
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use crate::GermanStr;
+
+// Lifted and lowered as a `String`, so `GermanStr` fields of UniFFI
+// records are strings in the foreign bindings. Lifting fails if the
+// string is longer than `MAX_LEN`.
+::uniffi::custom_type!(GermanStr, String, {
+    lower: |s| String::from(s),
+    try_lift: |s| Ok(GermanStr::new(s)?),
+});
@@ -0,0 +1,30 @@
+use alloc::borrow::Cow;
+use alloc::string::String;
+
+use utoipa::openapi::schema::Schema;
+use utoipa::openapi::RefOr;
+use utoipa::{PartialSchema, ToSchema};
+
+use crate::GermanStr;
+
+impl PartialSchema for GermanStr {
+    #[inline]
+    fn schema() -> RefOr<Schema> {
+        String::schema()
+    }
+}
+
+/// The derive macro of utoipa references the schema of a field by name,
+/// unless it knows its type to be primitive from its name alone: a
+/// `GermanStr` field is a reference to a `GermanStr` component, registered
+/// by the `schemas` of the derived type, whose schema is that of `String`.
+/// Fields marked with `#[schema(inline)]` are plain `type: string` schemas.
+///
+/// The name is fixed rather than derived from `core::any::type_name`, whose
+/// output isn't guaranteed.
+impl ToSchema for GermanStr {
+    #[inline]
+    fn name() -> Cow<'static, str> {
+        Cow::Borrowed("GermanStr")
+    }
+}
@@ -0,0 +1,172 @@
+use alloc::vec::Vec;
+use core::fmt;
+use core::ops::Deref;
+use core::slice;
+
+use crate::{GermanStr, InitError, MAX_INLINE_BYTES, MAX_LEN};
+
+/// A vector of `GermanStr`s, which stores the bytes of all of its
+/// heap-allocated strings in a single contiguous buffer.
+///
+/// Compared to a `Vec<GermanStr>`, this avoids one allocation per string,
+/// and strings pushed one after the other are next to each other in memory.
+/// The strings can only be borrowed from the `GermanStrVec`: `clone()` them
+/// to get copies that outlive it.
+#[derive(Default)]
+pub struct GermanStrVec {
+    /// Heap-allocated strings have shared buffers pointing into `data`.
+    pub(crate) strings: Vec<GermanStr>,
+
+    /// Bytes of the heap-allocated strings, concatenated. Bytes are never
+    /// mutated nor removed, unless `strings` is cleared.
+    pub(crate) data: Vec<u8>,
+}
+
+impl GermanStrVec {
+    #[inline]
+    pub const fn new() -> Self {
+        GermanStrVec {
+            strings: Vec::new(),
+            data: Vec::new(),
+        }
+    }
+
+    #[inline]
+    /// Creates a `GermanStrVec` with room for `strings` strings, whose heap
+    /// allocated strings contain `bytes` bytes in total.
+    pub fn with_capacity(strings: usize, bytes: usize) -> Self {
+        GermanStrVec {
+            strings: Vec::with_capacity(strings),
+            data: Vec::with_capacity(bytes),
+        }
+    }
+
+    /// Appends a copy of `src`.
+    pub fn push(&mut self, src: &str) -> Result<(), InitError> {
+        if src.len() <= MAX_INLINE_BYTES {
+            self.strings.push(GermanStr::new_inline(src));
+            return Ok(());
+        }
+        if src.len() > MAX_LEN {
+            return Err(InitError::TooLong);
+        }
+
+        let old_base = self.data.as_ptr();
+        self.data.reserve(src.len());
+        if self.data.as_ptr() != old_base {
+            self.rebase(old_base);
+        }
+        let start = self.data.len();
+        self.data.extend_from_slice(src.as_bytes());
+        let german = unsafe {
+            // Safety:
+            // * the bytes were copied from a &str.
+            // * the buffer is only moved by `reserve`, in which case every
+            //   string is rebased, and never mutated.
+            GermanStr::new_shared(core::str::from_utf8_unchecked(&self.data[start..]))
+        };
+        self.strings.push(german);
+        Ok(())
+    }
+
+    /// Updates the pointers of the heap-allocated strings after `data` was
+    /// moved from `old_base`.
+    fn rebase(&mut self, old_base: *const u8) {
+        for s in &mut self.strings {
+            let Some(ptr) = s.heap_ptr() else {
+                continue;
+            };
+            let start = ptr.as_ptr().addr() - old_base.addr();
+            let bytes = &self.data[start..start + s.len()];
+            *s = unsafe {
+                // Safety: same as in `push`.
+                GermanStr::new_shared(core::str::from_utf8_unchecked(bytes))
+            };
+        }
+    }
+
+    #[inline(always)]
+    pub fn as_slice(&self) -> &[GermanStr] {
+        &self.strings
+    }
+
+    #[inline]
+    /// Returns an iterator over the strings as `&str`.
+    pub fn iter_str(&self) -> impl DoubleEndedIterator<Item = &str> + ExactSizeIterator {
+        self.strings.iter().map(GermanStr::as_str)
+    }
+
+    #[inline(always)]
+    /// Returns the number of bytes stored in the shared buffer.
+    pub fn data_len(&self) -> usize {
+        self.data.len()
+    }
+
+    #[inline]
+    pub fn clear(&mut self) {
+        self.strings.clear();
+        self.data.clear();
+    }
+}
+
+impl Deref for GermanStrVec {
+    type Target = [GermanStr];
+
+    #[inline(always)]
+    fn deref(&self) -> &[GermanStr] {
+        self.as_slice()
+    }
+}
+
+impl Clone for GermanStrVec {
+    fn clone(&self) -> Self {
+        let mut clone = GermanStrVec::with_capacity(self.strings.len(), self.data.len());
+        clone.extend(self.iter_str());
+        clone
+    }
+}
+
+impl fmt::Debug for GermanStrVec {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Debug::fmt(self.as_slice(), f)
+    }
+}
+
+impl PartialEq for GermanStrVec {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.as_slice() == other.as_slice()
+    }
+}
+
+impl Eq for GermanStrVec {}
+
+impl<'a> IntoIterator for &'a GermanStrVec {
+    type Item = &'a GermanStr;
+    type IntoIter = slice::Iter<'a, GermanStr>;
+
+    #[inline(always)]
+    fn into_iter(self) -> Self::IntoIter {
+        self.strings.iter()
+    }
+}
+
+/// Panics if a string is longer than `MAX_LEN`.
+impl<S: AsRef<str>> Extend<S> for GermanStrVec {
+    fn extend<I: IntoIterator<Item = S>>(&mut self, iter: I) {
+        for s in iter {
+            self.push(s.as_ref())
+                .expect("tried to push a string longer than MAX_LEN in a GermanStrVec");
+        }
+    }
+}
+
+/// Panics if a string is longer than `MAX_LEN`.
+impl<S: AsRef<str>> FromIterator<S> for GermanStrVec {
+    fn from_iter<I: IntoIterator<Item = S>>(iter: I) -> Self {
+        let mut vec = GermanStrVec::new();
+        vec.extend(iter);
+        vec
+    }
+}
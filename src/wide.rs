@@ -0,0 +1,261 @@
+use alloc::boxed::Box;
+use alloc::string::String;
+use core::borrow::Borrow;
+use core::cmp;
+use core::fmt;
+use core::ops::Deref;
+
+use crate::{GermanStr, InitError, MAX_LEN};
+
+/// A string that behaves like `GermanStr`, but without the `MAX_LEN` limit.
+///
+/// Strings of at most `MAX_LEN` bytes are stored as a `GermanStr`, and keep
+/// its inlining and fast comparisons. Longer ones are stored in a
+/// `Box<str>`. A `WideGermanStr` takes 24 bytes instead of 16.
+#[derive(Clone)]
+pub struct WideGermanStr(Repr);
+
+#[derive(Clone)]
+enum Repr {
+    Narrow(GermanStr),
+    Wide(Box<str>),
+}
+
+impl WideGermanStr {
+    #[inline]
+    /// Creates a `WideGermanStr`. Unlike `GermanStr::new`, this never fails.
+    pub fn new(src: impl AsRef<str>) -> Self {
+        let src = src.as_ref();
+        if src.len() > MAX_LEN {
+            WideGermanStr(Repr::Wide(src.into()))
+        } else {
+            WideGermanStr::from_narrow_unchecked(src)
+        }
+    }
+
+    #[inline]
+    /// `src.len()` must be <= `MAX_LEN`.
+    fn from_narrow_unchecked(src: &str) -> Self {
+        let narrow = GermanStr::new(src)
+            .expect("src.len() <= MAX_LEN");
+        WideGermanStr(Repr::Narrow(narrow))
+    }
+
+    #[inline(always)]
+    pub fn as_str(&self) -> &str {
+        match &self.0 {
+            Repr::Narrow(s) => s.as_str(),
+            Repr::Wide(s) => s,
+        }
+    }
+
+    #[inline(always)]
+    pub fn len(&self) -> usize {
+        match &self.0 {
+            Repr::Narrow(s) => s.len(),
+            Repr::Wide(s) => s.len(),
+        }
+    }
+
+    #[inline(always)]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    #[inline(always)]
+    /// Returns `true` if the string is longer than `MAX_LEN` bytes, and
+    /// can't be converted to a `GermanStr`.
+    pub fn is_wide(&self) -> bool {
+        matches!(self.0, Repr::Wide(_))
+    }
+
+    #[inline(always)]
+    pub fn is_inlined(&self) -> bool {
+        matches!(&self.0, Repr::Narrow(s) if s.is_inlined())
+    }
+
+    #[inline(always)]
+    pub fn is_heap_allocated(&self) -> bool {
+        !self.is_inlined()
+    }
+
+    #[inline(always)]
+    /// Returns the `GermanStr`, if the string isn't wide.
+    pub fn as_german_str(&self) -> Option<&GermanStr> {
+        match &self.0 {
+            Repr::Narrow(s) => Some(s),
+            Repr::Wide(_) => None,
+        }
+    }
+}
+
+impl Default for WideGermanStr {
+    #[inline(always)]
+    fn default() -> Self {
+        WideGermanStr(Repr::Narrow(GermanStr::default()))
+    }
+}
+
+impl Deref for WideGermanStr {
+    type Target = str;
+
+    #[inline(always)]
+    fn deref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl AsRef<str> for WideGermanStr {
+    #[inline(always)]
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl Borrow<str> for WideGermanStr {
+    #[inline(always)]
+    fn borrow(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl From<GermanStr> for WideGermanStr {
+    #[inline(always)]
+    fn from(s: GermanStr) -> Self {
+        WideGermanStr(Repr::Narrow(s))
+    }
+}
+
+impl From<&str> for WideGermanStr {
+    #[inline(always)]
+    fn from(s: &str) -> Self {
+        WideGermanStr::new(s)
+    }
+}
+
+impl From<String> for WideGermanStr {
+    #[inline]
+    fn from(s: String) -> Self {
+        if s.len() > MAX_LEN {
+            // Reuses the allocation instead of copying gigabytes.
+            WideGermanStr(Repr::Wide(s.into_boxed_str()))
+        } else {
+            WideGermanStr::from_narrow_unchecked(&s)
+        }
+    }
+}
+
+impl From<Box<str>> for WideGermanStr {
+    #[inline]
+    fn from(s: Box<str>) -> Self {
+        if s.len() > MAX_LEN {
+            WideGermanStr(Repr::Wide(s))
+        } else {
+            WideGermanStr::from_narrow_unchecked(&s)
+        }
+    }
+}
+
+impl TryFrom<WideGermanStr> for GermanStr {
+    type Error = InitError;
+
+    #[inline]
+    /// Fails with `InitError::TooLong` if the string is wide.
+    fn try_from(s: WideGermanStr) -> Result<Self, Self::Error> {
+        match s.0 {
+            Repr::Narrow(s) => Ok(s),
+            Repr::Wide(_) => Err(InitError::TooLong),
+        }
+    }
+}
+
+impl From<WideGermanStr> for String {
+    #[inline]
+    fn from(s: WideGermanStr) -> Self {
+        match s.0 {
+            Repr::Narrow(s) => s.into(),
+            Repr::Wide(s) => s.into(),
+        }
+    }
+}
+
+impl PartialEq for WideGermanStr {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        match (&self.0, &other.0) {
+            (Repr::Narrow(lhs), Repr::Narrow(rhs)) => lhs == rhs,
+            _ => self.as_str() == other.as_str(),
+        }
+    }
+}
+
+impl Eq for WideGermanStr {}
+
+impl Ord for WideGermanStr {
+    #[inline]
+    fn cmp(&self, other: &Self) -> cmp::Ordering {
+        match (&self.0, &other.0) {
+            (Repr::Narrow(lhs), Repr::Narrow(rhs)) => lhs.cmp(rhs),
+            _ => self.as_str().cmp(other.as_str()),
+        }
+    }
+}
+
+impl PartialOrd for WideGermanStr {
+    #[inline(always)]
+    fn partial_cmp(&self, other: &Self) -> Option<cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl core::hash::Hash for WideGermanStr {
+    #[inline(always)]
+    fn hash<H: core::hash::Hasher>(&self, hasher: &mut H) {
+        self.as_str().hash(hasher);
+    }
+}
+
+impl PartialEq<str> for WideGermanStr {
+    #[inline(always)]
+    fn eq(&self, other: &str) -> bool {
+        self.as_str() == other
+    }
+}
+
+impl PartialEq<&str> for WideGermanStr {
+    #[inline(always)]
+    fn eq(&self, other: &&str) -> bool {
+        self.as_str() == *other
+    }
+}
+
+impl PartialEq<GermanStr> for WideGermanStr {
+    #[inline(always)]
+    fn eq(&self, other: &GermanStr) -> bool {
+        match &self.0 {
+            Repr::Narrow(s) => s == other,
+            Repr::Wide(_) => false,
+        }
+    }
+}
+
+impl PartialEq<WideGermanStr> for GermanStr {
+    #[inline(always)]
+    fn eq(&self, other: &WideGermanStr) -> bool {
+        other == self
+    }
+}
+
+impl fmt::Debug for WideGermanStr {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Debug::fmt(self.as_str(), f)
+    }
+}
+
+impl fmt::Display for WideGermanStr {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(self.as_str(), f)
+    }
+}
@@ -0,0 +1,165 @@
+//! Like with nom, parsers consume `GermanStrRef`s, whose slices are zero-copy
+//! substrings of the input, and are `GermanStrRef`s themselves.
+
+use core::fmt;
+use core::ops::Range;
+use core::str::{CharIndices, FromStr};
+
+use winnow::stream::{
+    AsBStr, Compare, CompareResult, FindSlice, Needed, Offset, ParseSlice, SliceLen, Stream, StreamIsPartial,
+    UpdateSlice,
+};
+
+use crate::GermanStrRef;
+
+#[inline(always)]
+fn substr(s: &str) -> GermanStrRef<'_> {
+    GermanStrRef::new(s).expect("substrings are at most MAX_LEN bytes long")
+}
+
+impl<'a> Stream for GermanStrRef<'a> {
+    type Token = char;
+    type Slice = GermanStrRef<'a>;
+    type IterOffsets = CharIndices<'a>;
+    type Checkpoint = GermanStrRef<'a>;
+
+    #[inline]
+    fn iter_offsets(&self) -> CharIndices<'a> {
+        self.as_str().char_indices()
+    }
+
+    #[inline]
+    fn eof_offset(&self) -> usize {
+        self.len()
+    }
+
+    #[inline]
+    fn next_token(&mut self) -> Option<char> {
+        let mut rest = self.as_str();
+        let c = rest.next_token()?;
+        *self = substr(rest);
+        Some(c)
+    }
+
+    #[inline]
+    fn peek_token(&self) -> Option<char> {
+        self.as_str().chars().next()
+    }
+
+    #[inline]
+    fn offset_for<P>(&self, predicate: P) -> Option<usize>
+    where
+        P: Fn(char) -> bool,
+    {
+        self.as_str().offset_for(predicate)
+    }
+
+    #[inline]
+    fn offset_at(&self, tokens: usize) -> Result<usize, Needed> {
+        self.as_str().offset_at(tokens)
+    }
+
+    #[inline]
+    fn next_slice(&mut self, offset: usize) -> GermanStrRef<'a> {
+        let (slice, rest) = self.as_str().split_at(offset);
+        *self = substr(rest);
+        substr(slice)
+    }
+
+    #[inline]
+    fn peek_slice(&self, offset: usize) -> GermanStrRef<'a> {
+        substr(&self.as_str()[..offset])
+    }
+
+    #[inline]
+    fn checkpoint(&self) -> GermanStrRef<'a> {
+        *self
+    }
+
+    #[inline]
+    fn reset(&mut self, checkpoint: &GermanStrRef<'a>) {
+        *self = *checkpoint;
+    }
+
+    fn trace(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{self:#?}")
+    }
+}
+
+impl StreamIsPartial for GermanStrRef<'_> {
+    type PartialState = ();
+
+    #[inline]
+    fn complete(&mut self) {}
+
+    #[inline]
+    fn restore_partial(&mut self, _state: ()) {}
+
+    #[inline(always)]
+    fn is_partial_supported() -> bool {
+        false
+    }
+}
+
+impl Offset for GermanStrRef<'_> {
+    #[inline]
+    fn offset_from(&self, start: &Self) -> usize {
+        self.as_str().offset_from(&start.as_str())
+    }
+}
+
+impl SliceLen for GermanStrRef<'_> {
+    #[inline(always)]
+    fn slice_len(&self) -> usize {
+        self.len()
+    }
+}
+
+impl AsBStr for GermanStrRef<'_> {
+    #[inline(always)]
+    fn as_bstr(&self) -> &[u8] {
+        self.as_bytes()
+    }
+}
+
+impl<'b> Compare<&'b str> for GermanStrRef<'_> {
+    #[inline]
+    fn compare(&self, t: &'b str) -> CompareResult {
+        self.as_str().compare(t)
+    }
+}
+
+impl Compare<char> for GermanStrRef<'_> {
+    #[inline]
+    fn compare(&self, t: char) -> CompareResult {
+        self.as_str().compare(t)
+    }
+}
+
+impl<'s> FindSlice<&'s str> for GermanStrRef<'_> {
+    #[inline]
+    fn find_slice(&self, substr: &'s str) -> Option<Range<usize>> {
+        self.as_str().find_slice(substr)
+    }
+}
+
+impl FindSlice<char> for GermanStrRef<'_> {
+    #[inline]
+    fn find_slice(&self, substr: char) -> Option<Range<usize>> {
+        self.as_str().find_slice(substr)
+    }
+}
+
+impl<R: FromStr> ParseSlice<R> for GermanStrRef<'_> {
+    #[inline]
+    fn parse_slice(&self) -> Option<R> {
+        self.as_str().parse().ok()
+    }
+}
+
+impl UpdateSlice for GermanStrRef<'_> {
+    #[inline(always)]
+    fn update_slice(self, inner: Self) -> Self {
+        inner
+    }
+}
@@ -0,0 +1,52 @@
+use alloc::slice;
+
+use zeroize::{Zeroize, ZeroizeOnDrop};
+
+use crate::GermanStr;
+
+/// Wipes the inline bytes, and the heap buffer if it is owned, before
+/// the string is replaced by an empty one.
+///
+/// With the `zeroize` feature, every `GermanStr` is wiped when it is
+/// dropped: this only needs to be called to erase a string early.
+/// Shared buffers are wiped when their owner is dropped or `free`d.
+/// ```
+/// use german_str::GermanStr;
+/// use zeroize::Zeroize;
+///
+/// let mut token = GermanStr::new("a secret api token").unwrap();
+/// token.zeroize();
+/// assert_eq!(token, "");
+/// ```
+impl Zeroize for GermanStr {
+    #[inline]
+    fn zeroize(&mut self) {
+        // The previous value is wiped when it is dropped.
+        drop(core::mem::replace(self, GermanStr::new_inline("")));
+    }
+}
+
+impl ZeroizeOnDrop for GermanStr {}
+
+#[inline]
+/// Wipes the bytes of `german` which are owned by it, before it is
+/// dropped.
+pub(crate) fn wipe(german: &mut GermanStr) {
+    // Buffers kept alive by an owner, such as a `Bytes`, aren't writable.
+    match german.writable_heap_ptr() {
+        Some(ptr) => {
+            let buf = unsafe {
+                // Safety: the buffer is owned by german, so no other
+                // reference to it exists.
+                slice::from_raw_parts_mut(ptr.as_ptr(), german.len())
+            };
+            buf.zeroize();
+        }
+        None if german.is_heap_allocated() => {}
+        None => unsafe {
+            // Safety: german is inlined.
+            german.last8.buf.zeroize();
+        },
+    }
+    german.prefix.zeroize();
+}
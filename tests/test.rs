@@ -1,15 +1,70 @@
-use std::{fmt::Write, ops::Deref};
+#![cfg(feature = "alloc")]
+
+use std::{ffi::CString, fmt::Write, ops::Deref};
 
 use assert_panic::assert_panic;
 use proptest::proptest;
 
-use german_str::{str_prefix, str_suffix, GermanStr, MAX_INLINE_BYTES, MAX_LEN};
+use german_str::{
+    binary_search_german, compact, equal_range_by_prefix, str_prefix, str_suffix, AlignedGermanStr, GermanCStr, GermanCow, GermanStr, GermanStrDict,
+    GermanStrInterner, GermanStrOffset, GermanStrRef, GermanStrTrie, GermanStrVec, GermanString, InitError,
+    InlineGermanStr, PageGermanStr, ResolveError, SharedAccounting, SharedBufferRegistry, Storage, UmbraColumn, UmbraError,
+    WideGermanStr, MAX_BUFFER_ALIGN, MAX_INLINE_BYTES, MAX_LEN,
+};
 
 #[test]
 fn is_2_bytes() {
     assert_eq!(std::mem::size_of::<GermanStr>(), 16);
 }
 
+#[test]
+fn test_aligned() {
+    assert_eq!(std::mem::size_of::<AlignedGermanStr>(), 16);
+    assert_eq!(std::mem::align_of::<AlignedGermanStr>(), 16);
+    let column: Vec<_> = ["short", "a string longer than 12 bytes"]
+        .map(|s| AlignedGermanStr::from(GermanStr::new(s).unwrap()))
+        .into();
+    assert!(column.iter().all(|s| (s as *const AlignedGermanStr).addr().is_multiple_of(16)));
+    assert_eq!(AlignedGermanStr::as_german_strs(&column), ["short", "a string longer than 12 bytes"]);
+    assert!(column[1] < column[0]);
+    assert_eq!(column[1].clone().into_inner(), "a string longer than 12 bytes");
+}
+
+#[test]
+fn test_new_aligned() {
+    fn assert_aligned(german: &GermanStr, align: usize) {
+        assert!(german.heap_ptr().unwrap().as_ptr().addr().is_multiple_of(align));
+        assert_eq!(german.heap_align(), Some(MAX_BUFFER_ALIGN));
+    }
+    let src = "a string longer than 12 bytes, scanned with SIMD";
+    let mut aligned = GermanStr::new_aligned::<32>(src).unwrap();
+    assert_eq!(aligned, src);
+    assert_aligned(&aligned, 32);
+    assert_aligned(&GermanStr::new_aligned::<16>(src).unwrap(), 16);
+    assert_aligned(&GermanStr::new_aligned::<64>(src).unwrap(), 64);
+    assert_aligned(&aligned.clone(), 32);
+    assert!(GermanStr::new_aligned::<32>("inline").unwrap().is_inlined());
+    assert_eq!(GermanStr::new(src).unwrap().heap_align(), Some(1));
+    assert_eq!(GermanStr::new("inline").unwrap().heap_align(), None);
+
+    aligned.with_mut(str::make_ascii_uppercase).unwrap();
+    assert_aligned(&aligned, 32);
+    let shared = aligned.leaky_shared_clone();
+    assert_aligned(&shared, 32);
+    assert_aligned(&shared.clone(), 32);
+    unsafe { shared.free() };
+    std::mem::forget(aligned);
+}
+
+#[test]
+fn test_option_niche() {
+    assert_eq!(std::mem::size_of::<Option<GermanStr>>(), 16);
+    assert_eq!(std::mem::size_of::<Option<Option<GermanStr>>>(), 24);
+    let some = Some(GermanStr::new("a string long enough to be allocated").unwrap());
+    assert_eq!(some.as_deref(), Some("a string long enough to be allocated"));
+    assert!(Some(GermanStr::default()).is_some_and(|s| s.is_empty()));
+}
+
 #[test]
 fn assert_traits() {
     fn f<T: Send + Sync + ::std::fmt::Debug + Clone>() {}
@@ -38,6 +93,621 @@ fn test_equality() {
     let a = GermanStr::new("aaaa").unwrap();
     let b = GermanStr::new("aaaab").unwrap();
     assert_ne!(a, b);
+
+    let german = |s| GermanStr::new(s).unwrap();
+    assert_ne!(german("a"), german("a\0"));
+    assert_ne!(german("abcdefgh"), german("abcdefgh\0"));
+    assert_eq!(german("abcdefghijkl"), german("abcdefghijkl"));
+    assert_ne!(german("abcdefghijkl"), german("abcdefghijklm"));
+    assert_eq!(german("abcdefghijklm"), german("abcdefghijklm"));
+    assert_ne!(german("abcdefghijklm"), german("abcdefghijklz"));
+}
+
+#[test]
+fn test_equality_with_str_padding() {
+    let german = GermanStr::new("abc").unwrap();
+    assert_ne!(german, "abc\0");
+    assert_ne!("abc\0", german);
+    assert_ne!(german, String::from("abc\0\0"));
+    let long = GermanStr::new("abcdefghijkl").unwrap();
+    assert_ne!(long, "abcdefghijkl\0");
+    assert_eq!(long, "abcdefghijkl");
+}
+
+#[test]
+fn test_trie() {
+    let mut trie = GermanStrTrie::new();
+    for (i, word) in ["foo", "fo", "foobar", "fo\0o", "food", "bar", "foo, but long enough to be allocated"]
+        .into_iter()
+        .enumerate()
+    {
+        assert_eq!(trie.insert(GermanStr::new(word).unwrap(), i), None);
+    }
+    assert_eq!(trie.len(), 7);
+    assert_eq!(trie.insert(GermanStr::new("fo").unwrap(), 10), Some(1));
+    assert_eq!(trie.get("fo"), Some(&10));
+    assert_eq!(trie.get("f"), None);
+
+    let keys = |prefix| trie.iter_prefix(prefix).map(|(k, _)| k.as_str()).collect::<Vec<_>>();
+    assert_eq!(keys("foo"), ["foo", "foo, but long enough to be allocated", "foobar", "food"]);
+    assert_eq!(keys("fo"), ["fo", "fo\0o", "foo", "foo, but long enough to be allocated", "foobar", "food"]);
+    assert_eq!(keys("foo,"), ["foo, but long enough to be allocated"]);
+    assert_eq!(keys(""), trie.iter().map(|(k, _)| k.as_str()).collect::<Vec<_>>());
+    assert!(keys("x").is_empty());
+
+    assert_eq!(trie.remove("foo"), Some(0));
+    assert_eq!(trie.remove("foo"), None);
+    assert_eq!(trie.len(), 6);
+    assert!(!trie.contains_key("foo"));
+}
+
+#[test]
+fn test_ordering_with_str_padding() {
+    let german = GermanStr::new("a").unwrap();
+    assert!(german < *"a\0");
+    assert!("a\0" > german);
+    assert!(german > String::new());
+}
+
+#[test]
+fn test_with_mut() {
+    let mut short = GermanStr::new("short").unwrap();
+    assert!(short.with_mut(|_| ()).is_none());
+
+    let mut long = GermanStr::new("this is obviously longer than 12 bytes").unwrap();
+    long.with_mut(str::make_ascii_uppercase).unwrap();
+    assert_eq!(long, "THIS IS OBVIOUSLY LONGER THAN 12 BYTES");
+    assert_eq!(long.prefix_bytes_array(), *b"THIS");
+
+    let shared = long.leaky_shared_clone();
+    assert!(long.with_mut(|_| ()).is_none());
+    unsafe {
+        shared.free();
+    }
+    std::mem::forget(long);
+}
+
+#[test]
+fn test_with_mut_resyncs_on_panic() {
+    let mut long = GermanStr::new("this is obviously longer than 12 bytes").unwrap();
+    let res = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        long.with_mut(|s| {
+            s.make_ascii_uppercase();
+            panic!("mutation failed halfway");
+        })
+    }));
+    assert!(res.is_err());
+    assert_eq!(long.prefix_bytes_array(), *b"THIS");
+    assert_eq!(long, GermanStr::new("THIS IS OBVIOUSLY LONGER THAN 12 BYTES").unwrap());
+}
+
+#[test]
+fn test_from_utf8() {
+    let bytes = b"this is obviously longer than 12 bytes".to_vec();
+    let ptr = bytes.as_ptr();
+    let german = GermanStr::from_utf8(bytes).unwrap();
+    assert_eq!(german, "this is obviously longer than 12 bytes");
+    assert_eq!(german.heap_ptr().unwrap().as_ptr().cast_const(), ptr);
+
+    let err = GermanStr::from_utf8(b"invalid \xff".to_vec()).unwrap_err();
+    assert!(matches!(err.init_error(), InitError::InvalidUtf8(_)));
+    assert_eq!(err.into_bytes(), b"invalid \xff");
+}
+
+#[test]
+fn test_error() {
+    use std::error::Error;
+
+    let err = GermanStr::from_utf8(b"invalid \xff".to_vec()).unwrap_err();
+    let source = err.source().unwrap();
+    assert_eq!(source.to_string(), err.to_string());
+    assert!(source.source().unwrap().is::<std::str::Utf8Error>());
+
+    let boxed: Box<dyn Error + Send + Sync> = InitError::TooLong.into();
+    assert_eq!(boxed.to_string(), "Tried to initialize a GermanStr longer than 4GB.");
+    assert!(boxed.source().is_none());
+}
+
+#[test]
+fn test_try_new() {
+    let src = "a string long enough to be allocated";
+    assert_eq!(GermanStr::try_new(src).unwrap(), src);
+    assert!(GermanStr::try_new("short").unwrap().is_inlined());
+}
+
+#[test]
+fn test_as_raw_bytes() {
+    let short = GermanStr::new("short").unwrap();
+    assert_eq!(short.as_raw_bytes()[4..9], *b"short");
+    assert_eq!(short.as_raw_bytes()[9..], [0; 7]);
+    assert_ne!(short.as_raw_bytes(), GermanStr::new("short\0").unwrap().as_raw_bytes());
+
+    let mut long = GermanStr::new("a string long enough to be allocated").unwrap();
+    let shared = long.leaky_shared_clone();
+    assert_eq!(long.as_raw_bytes(), shared.as_raw_bytes());
+    assert_eq!(long.as_raw_bytes()[4..8], *b"a st");
+    let copy = GermanStr::new(&long).unwrap();
+    assert_eq!(copy.as_raw_bytes()[..8], long.as_raw_bytes()[..8]);
+    assert_ne!(copy.as_raw_bytes(), long.as_raw_bytes());
+    unsafe { shared.free() };
+}
+
+#[test]
+fn test_ordering_padding() {
+    let german = |s| GermanStr::new(s).unwrap();
+    assert!(german("a") < german("a\0"));
+    assert!(german("abcde") < german("abcde\0"));
+    assert_eq!(german("a").cmp_prefix(&german("a\0")), Some(std::cmp::Ordering::Less));
+    assert_eq!(german("abcde").cmp_prefix(&german("abcdf")), None);
+    assert!(german("a").prefix_key() < german("a\0").prefix_key());
+    assert!(german("abcd").prefix_key() < german("abcd\0").prefix_key());
+    assert_eq!(german("abcde").prefix_key(), german("abcde\0").prefix_key());
+}
+
+#[test]
+fn test_ordering_byte_order() {
+    // The bytes which differ are the least significant ones of little-endian
+    // words: comparing words loaded in native order would reverse them.
+    let german = |s| GermanStr::new(s).unwrap();
+    assert!(german("\u{1}\0") > german("\0\u{1}"));
+    assert!(german("ab\u{1}") < german("ac\0"));
+    assert!(german("abcd\u{1}\0") > german("abcd\0\u{1}"));
+    assert!(german("abcdefgh\u{1}") < german("abcdefgi\0"));
+    assert!(german("ab\u{1}") < *"ac\0");
+}
+
+#[test]
+fn test_longest_common_prefix() {
+    let german = |s| GermanStr::new(s).unwrap();
+    assert_eq!(german("").longest_common_prefix(&german("abc")), 0);
+    assert_eq!(german("a").longest_common_prefix(&german("a\0")), 1);
+    assert_eq!(german("abcdefgh").longest_common_prefix(&german("abcdefgh\0\0")), 8);
+    assert_eq!(german("abcdefgh").longest_common_prefix(&german("abcdefgi")), 7);
+    let long = german("a string longer than 12 bytes");
+    assert_eq!(long.longest_common_prefix(&long.clone()), long.len());
+    assert_eq!(long.longest_common_prefix(&german("a string longer than 12 bits")), 25);
+    assert_eq!(long.longest_common_prefix(&german("a string")), 8);
+}
+
+#[test]
+fn test_sorted_search() {
+    let sorted: Vec<GermanStr> = ["", "a", "a\0", "ab", "abcd", "abcde", "abcdefghijklmnop", "abcdefghijklmnoq", "b"]
+        .into_iter()
+        .map(|s| GermanStr::new(s).unwrap())
+        .collect();
+    for (i, s) in sorted.iter().enumerate() {
+        assert_eq!(binary_search_german(&sorted, s), Ok(i));
+    }
+    assert_eq!(binary_search_german(&sorted, "abc"), Err(4));
+    assert_eq!(binary_search_german(&sorted, "c"), Err(9));
+    assert_eq!(equal_range_by_prefix(&sorted, ""), 0..9);
+    assert_eq!(equal_range_by_prefix(&sorted, "a"), 1..8);
+    assert_eq!(equal_range_by_prefix(&sorted, "a\0"), 2..3);
+    assert_eq!(equal_range_by_prefix(&sorted, "abcd"), 4..8);
+    assert_eq!(equal_range_by_prefix(&sorted, "abcdefghijklmno"), 6..8);
+    assert_eq!(equal_range_by_prefix(&sorted, "abcdefghijklmnoq"), 7..8);
+    assert_eq!(equal_range_by_prefix(&sorted, "abd"), 8..8);
+}
+
+#[test]
+fn test_sort_key() {
+    let mut key = Vec::new();
+    GermanStr::new("a\0b").unwrap().write_sort_key(&mut key);
+    assert_eq!(key, b"a\0\xffb\0\0");
+}
+
+#[test]
+fn test_from_char() {
+    for c in ['a', '\0', 'é', '€', '🦀', char::MAX] {
+        let s = GermanStr::from(c);
+        assert!(s.is_inlined());
+        assert_eq!(s.len(), c.len_utf8());
+        assert_eq!(s.char_count(), 1);
+        assert_eq!(s, c.to_string());
+        assert_eq!(s, GermanStr::new(c.to_string()).unwrap());
+    }
+}
+
+#[test]
+fn test_is_ascii() {
+    let german = |s| GermanStr::new(s).unwrap();
+    assert!(german("twelve chars").is_ascii());
+    assert!(!german("caract\u{e8}re").is_ascii());
+    assert_eq!(german("caract\u{e8}re").fast_char_count(), Some(9));
+
+    let mut ascii = german("an ASCII string longer than 12 bytes");
+    assert!(ascii.is_ascii());
+    assert_eq!(ascii.fast_char_count(), Some(36));
+    assert_eq!(ascii.clone().fast_char_count(), Some(36));
+    let shared = ascii.leaky_shared_clone();
+    assert_eq!(shared.fast_char_count(), Some(36));
+    assert!(shared.has_shared_buffer());
+    unsafe { shared.free() };
+    std::mem::forget(ascii);
+
+    let mut owned = german("another ASCII string longer than 12 bytes");
+    owned.with_mut(str::make_ascii_uppercase).unwrap();
+    assert_eq!(owned.fast_char_count(), Some(41));
+    assert!(!owned.has_shared_buffer());
+
+    let unicode = german("a string that isn't ASCII: \u{1f980}");
+    assert!(!unicode.is_ascii());
+    assert_eq!(unicode.fast_char_count(), None);
+    assert_eq!(unicode.char_count(), 28);
+
+    let from_static = GermanStr::from_static("a static ASCII string");
+    assert!(from_static.is_ascii());
+    assert_eq!(from_static.fast_char_count(), None);
+    assert_eq!(from_static.char_count(), 21);
+}
+
+#[test]
+fn test_from_int_bounds() {
+    assert_eq!(GermanStr::from(0_u8), "0");
+    assert_eq!(GermanStr::from(i8::MIN), "-128");
+    assert_eq!(GermanStr::from(i128::MIN), i128::MIN.to_string());
+    assert_eq!(GermanStr::from(u128::MAX), u128::MAX.to_string());
+    assert!(GermanStr::from(-99_999_999_999_i64).is_inlined());
+    assert!(GermanStr::from(1_000_000_000_000_u64).is_heap_allocated());
+}
+
+#[test]
+fn test_try_format() {
+    let short: Result<GermanStr, InitError> = german_str::try_format_german_str!("{}-{}", 1, 2);
+    assert_eq!(short.unwrap(), "1-2");
+    let long = german_str::try_format_german_str!("{:>20}", "right").unwrap();
+    assert_eq!(long, format!("{:>20}", "right"));
+    assert!(long.is_heap_allocated());
+
+    let mut w = german_str::Writer::new();
+    assert!(w.finish().unwrap().is_empty());
+    w = german_str::Writer::new();
+    write!(w, "a string written in {}", 2).unwrap();
+    write!(w, " parts").unwrap();
+    assert_eq!(w.finish().unwrap(), "a string written in 2 parts");
+}
+
+#[test]
+fn test_wide() {
+    assert_eq!(std::mem::size_of::<WideGermanStr>(), 24);
+
+    let short = WideGermanStr::new("short");
+    assert!(short.is_inlined());
+    assert!(!short.is_wide());
+    let long = WideGermanStr::from(String::from("a string too long to be inlined"));
+    assert!(long.is_heap_allocated());
+    assert!(long < short);
+    assert_eq!(long, "a string too long to be inlined");
+
+    let german = GermanStr::try_from(long.clone()).unwrap();
+    assert_eq!(german, long);
+    assert_eq!(WideGermanStr::from(german), long);
+    assert_eq!(long.as_german_str().unwrap(), "a string too long to be inlined");
+    assert!(WideGermanStr::default().is_empty());
+}
+
+#[test]
+fn test_storage() {
+    assert_eq!(GermanStr::default().storage(), Storage::Inline);
+    assert_eq!(GermanStr::new("abcdefghijkl").unwrap().storage(), Storage::Inline);
+
+    let mut long = GermanStr::new("abcdefghijklm").unwrap();
+    let ptr = long.heap_ptr().unwrap();
+    assert_eq!(long.storage(), Storage::Heap { shared: false, ptr, len: 13 });
+    let shared = long.leaky_shared_clone();
+    assert_eq!(long.storage(), Storage::Heap { shared: true, ptr, len: 13 });
+    assert_eq!(shared.storage(), long.storage());
+    unsafe { shared.free() };
+}
+
+#[test]
+fn test_allocated_size() {
+    let short = GermanStr::new("abcdefghijkl").unwrap();
+    assert_eq!(short.heap_bytes(), 0);
+    assert_eq!(short.allocated_size(SharedAccounting::Full), 16);
+
+    let mut long = GermanStr::new("abcdefghijklm").unwrap();
+    assert_eq!(long.heap_bytes(), 13);
+    assert_eq!(long.allocated_size(SharedAccounting::Ignore), 16 + 13);
+    let shared = long.leaky_shared_clone();
+    assert_eq!(shared.heap_bytes(), 13);
+    assert_eq!(shared.allocated_size(SharedAccounting::Ignore), 16);
+    assert_eq!(shared.allocated_size(SharedAccounting::Split(2)), 16 + 7);
+    assert_eq!(shared.allocated_size(SharedAccounting::Split(0)), 16 + 13);
+    unsafe { shared.free() };
+}
+
+#[test]
+fn test_shared_buffer_registry() {
+    let registry = SharedBufferRegistry::new();
+    let long = "a string long enough to be allocated";
+    let shared = registry.share_str(long).unwrap();
+    assert!(shared.has_shared_buffer());
+    let copies: Vec<_> = (0..10).map(|_| shared.clone()).collect();
+    assert!(copies.iter().all(|s| s.heap_ptr() == shared.heap_ptr() && *s == long));
+
+    let owned: GermanStr = (*shared).clone();
+    assert!(!owned.has_shared_buffer());
+    assert_ne!(owned.heap_ptr(), shared.heap_ptr());
+
+    let inline = registry.share_str("short").unwrap();
+    assert!(inline.is_inlined());
+    let other = registry.share(GermanStr::new("another long string to share").unwrap());
+    assert!(shared < other);
+    assert_eq!(registry.len(), 2);
+    drop(copies);
+    drop(registry.share(GermanStr::from_static("a static string is never registered")));
+    assert_eq!(registry.len(), 2);
+}
+
+#[test]
+fn test_dedup_shared() {
+    let registry = SharedBufferRegistry::new();
+    let values = ["a string long enough to be allocated", "short", "another long string to share"];
+    let rows: Vec<GermanStr> = (0..30).map(|i| GermanStr::new(values[i % 3]).unwrap()).collect();
+    let (rows, saved) = registry.dedup_shared(rows);
+    assert_eq!(saved, 9 * (values[0].len() + values[2].len()));
+    assert_eq!(registry.len(), 2);
+    for (i, row) in rows.iter().enumerate() {
+        assert_eq!(*row, values[i % 3]);
+        assert_eq!(row.heap_ptr(), rows[i % 3].heap_ptr());
+    }
+    assert!(rows[0].has_shared_buffer() && rows[1].is_inlined());
+
+    let static_rows: Vec<GermanStr> = (0..3).map(|_| GermanStr::from_static(values[0])).collect();
+    let (static_rows, saved) = registry.dedup_shared(static_rows);
+    assert_eq!(saved, 0);
+    assert_eq!(static_rows.len(), 3);
+    assert_eq!(registry.len(), 2);
+}
+
+#[test]
+fn test_compact() {
+    let values = ["a string long enough to be allocated", "short", "another long string to compact"];
+    let mut rows: Vec<GermanStr> = (0..30).map(|i| GermanStr::new(values[i % 3]).unwrap()).collect();
+    rows.push(GermanStr::from_static("a static string is never compacted"));
+    rows.push(GermanStr::new_aligned::<64>("an aligned string is never compacted").unwrap());
+    let rows = compact(rows);
+    assert_eq!(rows.buffer_len(), 10 * (values[0].len() + values[2].len()));
+    for (i, row) in rows[..30].iter().enumerate() {
+        assert_eq!(*row, values[i % 3]);
+        assert_eq!(row.has_shared_buffer(), i % 3 != 1);
+    }
+    // Strings are copied next to each other, in order.
+    let first = rows[0].heap_ptr().unwrap().as_ptr();
+    assert_eq!(rows[2].heap_ptr().unwrap().as_ptr(), first.wrapping_add(values[0].len()));
+    assert_eq!(rows[3].heap_ptr().unwrap().as_ptr(), first.wrapping_add(values[0].len() + values[2].len()));
+    assert_eq!(rows[31].heap_align(), Some(MAX_BUFFER_ALIGN));
+    assert!(!rows[31].has_shared_buffer());
+
+    // Clones own a copy of their buffer, and outlive the compacted buffer.
+    let clone = rows[0].clone();
+    assert!(!clone.has_shared_buffer());
+    drop(rows);
+    assert_eq!(clone, values[0]);
+
+    let rows = compact(vec![GermanStr::new("short").unwrap(), GermanStr::from_static("a static string is never compacted")]);
+    assert_eq!(rows.buffer_len(), 0);
+    assert_eq!(*rows, ["short", "a static string is never compacted"]);
+}
+
+#[test]
+fn test_new_bulk() {
+    let batch = ["id", "a string long enough to be allocated", "", "another long string in the batch", "exactly 12 b"];
+    let row = GermanStr::new_bulk(&batch).unwrap();
+    assert_eq!(*row, batch);
+    assert_eq!(row.buffer_len(), batch[1].len() + batch[3].len());
+    assert!(row[0].is_inlined() && row[2].is_inlined() && row[4].is_inlined());
+    assert!(row[1].has_shared_buffer() && row[3].has_shared_buffer());
+    assert_eq!(
+        row[3].heap_ptr().unwrap().as_ptr(),
+        row[1].heap_ptr().unwrap().as_ptr().wrapping_add(batch[1].len())
+    );
+    assert_eq!(format!("{row:?}"), format!("{batch:?}"));
+
+    let empty = GermanStr::new_bulk::<String>(&[]).unwrap();
+    assert!(empty.is_empty() && empty.buffer_len() == 0);
+}
+
+#[test]
+fn test_german_str_offset() {
+    let values = ["", "abc", "exactly 12 b", "a string long enough to be stored in the region", "another long string"];
+    let mut region = b"header".to_vec();
+    let records: Vec<GermanStrOffset> = values
+        .iter()
+        .map(|s| {
+            let record = GermanStrOffset::new(s, region.len()).unwrap();
+            if record.offset().is_some() {
+                region.extend_from_slice(s.as_bytes());
+            }
+            record
+        })
+        .collect();
+    assert_eq!(std::mem::size_of::<GermanStrOffset>(), 16);
+    assert_eq!(records[3].offset(), Some(6));
+    assert_eq!(records[2].offset(), None);
+    for (record, value) in records.iter().zip(values) {
+        assert_eq!(record.resolve(&region).unwrap(), value);
+        // Records are relocatable: they can be resolved against a copy.
+        let copy = GermanStrOffset::from_bytes(record.to_bytes());
+        assert_eq!(copy.resolve(&region.clone()).unwrap(), value);
+    }
+
+    assert_eq!(records[4].resolve(&region[..region.len() - 1]), Err(ResolveError::OutOfBounds));
+    let mut bytes = records[3].to_bytes();
+    bytes[4] = b'A';
+    assert_eq!(GermanStrOffset::from_bytes(bytes).resolve(&region), Err(ResolveError::InvalidRecord));
+    let mut bytes = records[1].to_bytes();
+    bytes[8] = b'x';
+    assert_eq!(GermanStrOffset::from_bytes(bytes).resolve(&region), Err(ResolveError::InvalidRecord));
+    bytes[..4].copy_from_slice(&5u32.to_le_bytes());
+    bytes[7] = 0xFF;
+    assert_eq!(GermanStrOffset::from_bytes(bytes).resolve(&region), Err(ResolveError::InvalidUtf8));
+}
+
+#[test]
+fn test_page_german_str() {
+    // A page whose records are written from its start, and whose long
+    // strings are written from its end.
+    let values = ["", "abc", "exactly 12 b", "a string long enough to be stored in the page", "another long string"];
+    let mut page = vec![0u8; 256];
+    let mut end = page.len();
+    for (i, value) in values.iter().enumerate() {
+        if value.len() > MAX_INLINE_BYTES {
+            end -= value.len();
+            page[end..end + value.len()].copy_from_slice(value.as_bytes());
+        }
+        let record = PageGermanStr::new(value, end as u32).unwrap();
+        page[i * 16..(i + 1) * 16].copy_from_slice(&record.to_bytes());
+    }
+    assert_eq!(std::mem::size_of::<PageGermanStr>(), 16);
+
+    // The page is read back at another address.
+    let page = page.clone();
+    let records: Vec<PageGermanStr> = page[..values.len() * 16]
+        .chunks_exact(16)
+        .map(|bytes| PageGermanStr::from_bytes(bytes.try_into().unwrap()))
+        .collect();
+    assert_eq!(records[2].offset(), None);
+    assert_eq!(records[3].offset(), Some(256 - values[3].len() as u32));
+    for (record, value) in records.iter().zip(values) {
+        assert_eq!(record.resolve(&page).unwrap(), value);
+    }
+    assert!(records[3].resolve(&page).unwrap() < records[4].resolve(&page).unwrap());
+
+    assert_eq!(records[3].resolve(&page[..200]), Err(ResolveError::OutOfBounds));
+    let mut bytes = records[4].to_bytes();
+    bytes[15] = 1;
+    assert_eq!(PageGermanStr::from_bytes(bytes).resolve(&page), Err(ResolveError::InvalidRecord));
+}
+
+#[test]
+fn test_share_scope() {
+    let long = GermanStr::new("a string long enough to be allocated").unwrap();
+    let ptr = long.heap_ptr();
+    let clones = long.share_scope(|handle| {
+        assert!(handle.has_shared_buffer());
+        let clones = vec![handle.clone(); 5];
+        assert!(clones.iter().all(|s| s.heap_ptr() == ptr));
+        // Deep copies can escape the scope.
+        clones.iter().map(|s| (**s).clone()).collect::<Vec<_>>()
+    });
+    assert!(clones.iter().all(|s| !s.has_shared_buffer() && s.heap_ptr() != ptr));
+
+    let inline = GermanStr::new("short").unwrap();
+    assert_eq!(inline.share_scope(|handle| handle.len()), 5);
+}
+
+#[test]
+fn test_ptr_eq() {
+    let short = GermanStr::new("short").unwrap();
+    assert!(short.ptr_eq(&short.clone()));
+    assert!(!short.ptr_eq(&GermanStr::new("short\0").unwrap()));
+
+    let long = GermanStr::new("a string long enough to be allocated").unwrap();
+    assert!(!long.ptr_eq(&long.clone()));
+    assert!(!long.ptr_eq(&short));
+    let ptr = long.heap_ptr();
+    long.share_scope(|handle| {
+        assert_eq!(handle.heap_ptr(), ptr);
+        assert!(handle.clone().ptr_eq(handle));
+    });
+
+    let mut interner = GermanStrInterner::new();
+    let a = interner.intern("another string long enough to be allocated").unwrap();
+    let b = interner.intern("another string long enough to be allocated").unwrap();
+    assert!(a.ptr_eq(&b));
+    assert_eq!(a, b);
+    unsafe { interner.free() };
+}
+
+#[test]
+fn test_umbra_column() {
+    let strings: Vec<_> = ["", "abc", "twelve chars", "a string longer than 12 bytes", "another long string"]
+        .into_iter()
+        .map(|s| GermanStr::new(s).unwrap())
+        .collect();
+    let mut bytes = Vec::new();
+    UmbraColumn::write(&strings, &mut bytes);
+    assert_eq!(bytes.len(), 16 + 16 * 5 + 29 + 19);
+
+    let column = UmbraColumn::read(&bytes).unwrap();
+    assert_eq!(column.as_slice(), strings);
+    let payload = bytes[96..].as_ptr_range();
+    assert!(payload.contains(&column[3].heap_ptr().unwrap().as_ptr().cast_const()));
+    let owned = column.to_vec();
+    assert!(!owned[3].has_shared_buffer());
+    drop(column);
+    assert_eq!(owned, strings);
+
+    assert_eq!(UmbraColumn::read(b"GSTR").unwrap_err(), UmbraError::InvalidHeader);
+    assert_eq!(UmbraColumn::read(&bytes[..50]).unwrap_err(), UmbraError::Truncated);
+    assert_eq!(UmbraColumn::read(&bytes[..bytes.len() - 1]).unwrap_err(), UmbraError::InvalidRecord(4));
+    let mut invalid = bytes.clone();
+    invalid[16 + 16 + 7] = b'x';
+    assert_eq!(UmbraColumn::read(&invalid).unwrap_err(), UmbraError::InvalidRecord(1));
+    invalid[16 + 16 + 7] = 0;
+    invalid[16 + 16 + 6] = 0xFF;
+    assert_eq!(UmbraColumn::read(&invalid).unwrap_err(), UmbraError::InvalidUtf8(1));
+}
+
+#[test]
+fn test_lines_from() {
+    let text = String::from("short\n\na line longer than 12 bytes\r\nanother long line\n");
+    let lines = GermanStr::lines_from(text).unwrap();
+    assert_eq!(lines.as_slice(), ["short", "", "a line longer than 12 bytes", "another long line"]);
+    let buffer = lines.buffer().as_bytes().as_ptr_range();
+    assert!(lines[2..].iter().all(|s| buffer.contains(&s.heap_ptr().unwrap().as_ptr().cast_const())));
+    let owned = lines.to_vec();
+    drop(lines);
+    assert_eq!(owned[3], "another long line");
+    assert!(GermanStr::lines_from("").unwrap().is_empty());
+}
+
+#[test]
+fn test_leak_static() {
+    let long = GermanStr::new("a string longer than 12 bytes").unwrap();
+    let ptr = long.heap_ptr().unwrap();
+    let leaked: &'static str = long.leak();
+    assert_eq!(leaked, "a string longer than 12 bytes");
+    assert_eq!(leaked.as_ptr(), ptr.as_ptr().cast_const());
+
+    assert_eq!(GermanStr::new("short").unwrap().leak(), "short");
+    assert_eq!(GermanStr::default().leak(), "");
+
+    let mut owner = GermanStr::new("another string longer than 12 bytes").unwrap();
+    let shared = owner.leaky_shared_clone();
+    let leaked = shared.leak();
+    assert_ne!(leaked.as_ptr(), owner.heap_ptr().unwrap().as_ptr().cast_const());
+    unsafe { owner.free() };
+    assert_eq!(leaked, "another string longer than 12 bytes");
+}
+
+#[test]
+fn test_c_str() {
+    use std::ffi::{CStr, CString};
+
+    let short = GermanCStr::new("eleven char").unwrap();
+    assert!(short.is_inlined());
+    assert_eq!(short.count_bytes(), 11);
+    assert_eq!(short, c"eleven char");
+    let long = GermanCStr::new(b"twelve chars").unwrap();
+    assert!(long.is_heap_allocated());
+    assert_eq!(long.to_bytes_with_nul(), b"twelve chars\0");
+    let from_ptr = unsafe { CStr::from_ptr(long.as_ptr()) };
+    assert_eq!(from_ptr, c"twelve chars");
+
+    let c_str = |s| GermanCStr::new(s).unwrap();
+    assert!(c_str("a") < c_str("ab"));
+    assert!(c_str("ab") < c_str("b"));
+    assert_eq!(GermanCStr::from_c_str(c"twelve chars").unwrap(), long);
+    assert_eq!(CString::from(long), CString::new("twelve chars").unwrap());
+    assert!(GermanCStr::default().is_empty());
+    assert_eq!(GermanCStr::default(), c"");
+
+    assert_eq!(GermanCStr::new("nul\0byte"), Err(InitError::InteriorNul(3)));
+    let german = GermanStr::new("converted from a GermanStr").unwrap();
+    assert_eq!(GermanCStr::try_from(&german).unwrap().to_str().unwrap(), german);
 }
 
 #[test]
@@ -67,6 +737,167 @@ fn test_leak() {
     assert_eq!(leaked.heap_ptr(), leaked_copy.heap_ptr());
 }
 
+#[test]
+fn test_from_static() {
+    static SHORT: GermanStr = GermanStr::from_static("short");
+    static LONG: GermanStr = german_str::gstr!("this is obviously longer than 12 bytes");
+    assert!(SHORT.is_inlined());
+    assert_eq!(SHORT, "short");
+    assert!(LONG.has_shared_buffer());
+    assert_eq!(LONG, "this is obviously longer than 12 bytes");
+
+    let copy = german_str::gstr!("this is obviously longer than 12 bytes");
+    assert_eq!(copy.heap_ptr(), LONG.heap_ptr());
+    let owned = copy.clone();
+    assert!(!owned.has_shared_buffer());
+    assert_eq!(owned, LONG);
+}
+
+#[test]
+fn test_from_static_bytes() {
+    const SHORT: GermanStr = GermanStr::from_static_bytes(b"short");
+    const LONG: GermanStr = GermanStr::from_static_bytes(b"tr\xc3\xa8s long, en UTF-8");
+    const UNCHECKED: GermanStr = unsafe {
+        GermanStr::from_static_bytes_unchecked(b"this is obviously longer than 12 bytes")
+    };
+    assert_eq!(SHORT, "short");
+    assert_eq!(LONG, "très long, en UTF-8");
+    assert!(LONG.has_shared_buffer());
+    assert_eq!(UNCHECKED, "this is obviously longer than 12 bytes");
+    assert_panic!({
+        GermanStr::from_static_bytes(b"invalid \xff UTF-8 bytes");
+    });
+}
+
+#[test]
+fn test_inline_german_str() {
+    const HELLO: InlineGermanStr = match InlineGermanStr::new("hello") {
+        Ok(s) => s,
+        Err(_) => panic!(),
+    };
+    assert_eq!(std::mem::size_of::<InlineGermanStr>(), 16);
+    assert_eq!(HELLO.as_str(), "hello");
+    assert!(matches!(
+        InlineGermanStr::new("this is too long"),
+        Err(InitError::TooLongForInline),
+    ));
+    let german = GermanStr::from(HELLO);
+    assert!(german.is_inlined());
+    assert_eq!(german, HELLO);
+}
+
+#[test]
+fn test_german_string() {
+    let mut string = GermanString::new();
+    string.push_str("hello");
+    string.push('!');
+    string.insert_str(0, "oh, ");
+    assert_eq!(string, "oh, hello!");
+    string.truncate(9);
+    assert_eq!(string.freeze().unwrap(), "oh, hello");
+
+    // Without extra capacity, the buffer is handed over as is.
+    let mut string = GermanString::with_capacity(28);
+    write!(string, "this is longer than {MAX_INLINE_BYTES} bytes").unwrap();
+    let ptr = string.as_ptr();
+    let german = string.freeze().unwrap();
+    assert_eq!(german, "this is longer than 12 bytes");
+    assert!(!german.has_shared_buffer());
+    assert_eq!(german.heap_ptr().unwrap().as_ptr().cast_const(), ptr);
+}
+
+#[test]
+fn test_german_cow() {
+    assert!(std::mem::size_of::<GermanCow>() <= 24);
+    let src = String::from("this is obviously longer than 12 bytes");
+    let mut cow = GermanCow::from(src.as_str());
+    assert!(cow.is_borrowed());
+    assert_eq!(cow, GermanCow::from(GermanStr::new(&src).unwrap()));
+    assert_eq!(cow.len(), src.len());
+    cow.to_owned_mut().unwrap();
+    assert!(cow.is_owned());
+    assert_eq!(cow.into_owned().unwrap(), src);
+}
+
+#[test]
+fn test_german_str_ref() {
+    assert_eq!(std::mem::size_of::<GermanStrRef>(), 16);
+    let src = String::from("this is obviously longer than 12 bytes");
+    let borrowed = GermanStrRef::new(&src).unwrap();
+    assert_eq!(borrowed, src.as_str());
+    assert_eq!(borrowed.as_ptr(), src.as_ptr());
+    let owned = borrowed.to_german_str();
+    assert_eq!(owned, borrowed);
+    assert_eq!(GermanStrRef::from(&owned), borrowed);
+}
+
+#[test]
+fn test_german_str_vec() {
+    let mut vec = GermanStrVec::new();
+    vec.push("short").unwrap();
+    vec.push("this is obviously longer than 12 bytes").unwrap();
+    vec.push("this is another string longer than 12 bytes").unwrap();
+    assert_eq!(vec.len(), 3);
+    assert_eq!(vec.data_len(), 38 + 43);
+    assert!(vec[1].has_shared_buffer());
+    let first = vec[1].heap_ptr().unwrap().as_ptr();
+    let second = vec[2].heap_ptr().unwrap().as_ptr();
+    assert_eq!(unsafe { first.add(38) }, second);
+
+    let clone = vec.clone();
+    drop(vec);
+    assert_eq!(
+        clone.iter_str().collect::<Vec<_>>(),
+        ["short", "this is obviously longer than 12 bytes", "this is another string longer than 12 bytes"],
+    );
+}
+
+#[test]
+fn test_german_str_dict() {
+    let long = "this is obviously longer than 12 bytes";
+    let mut dict = GermanStrDict::new();
+    assert_eq!(dict.push(long).unwrap(), 0);
+    assert_eq!(dict.push("short").unwrap(), 1);
+    assert_eq!(dict.push(long).unwrap(), 0);
+    assert_eq!(dict.codes(), [0, 1, 0]);
+    assert_eq!(dict.cardinality(), 2);
+    assert_eq!(dict.encode("short"), Some(1));
+    assert_eq!(dict.encode("missing"), None);
+    assert_eq!(dict.decode(0).unwrap(), long);
+    assert_eq!(dict.get(2).unwrap(), long);
+    assert_eq!(dict.counts(), [2, 1]);
+    assert_eq!(dict.groups(), [vec![0, 2], vec![1]]);
+    assert!(!dict.values()[0].has_shared_buffer());
+
+    let clone = dict.clone();
+    drop(dict);
+    assert_eq!(clone.to_vec(), [long, "short", long]);
+}
+
+#[test]
+fn test_interner() {
+    let mut interner = GermanStrInterner::new();
+    let short_a = interner.intern("short").unwrap();
+    let short_b = interner.intern("short").unwrap();
+    assert!(short_a.is_inlined());
+    assert_eq!(short_a, short_b);
+    assert!(interner.is_empty());
+
+    let long_a = interner.intern("this is obviously longer than 12 bytes").unwrap();
+    let long_b = interner.intern("this is obviously longer than 12 bytes").unwrap();
+    let other = interner.intern("this is another string longer than 12 bytes").unwrap();
+    assert!(long_a.has_shared_buffer());
+    assert_eq!(long_a, long_b);
+    assert_eq!(long_a.heap_ptr(), long_b.heap_ptr());
+    assert_ne!(long_a.heap_ptr(), other.heap_ptr());
+    assert_eq!(interner.len(), 2);
+
+    drop((long_a, long_b, other));
+    unsafe {
+        interner.free();
+    }
+}
+
 proptest! {
     #[test]
     fn conversion(src: String) {
@@ -88,6 +919,79 @@ proptest! {
         assert_eq!(lhs.cmp(&rhs), german_lhs.cmp(&german_rhs));
     }
 
+    #[test]
+    fn sorted_search(
+        mut strings in proptest::collection::vec("[ab\\x00]{0,14}", 0..32),
+        needle in "[ab\\x00]{0,14}",
+    ) {
+        strings.sort();
+        let german: Vec<GermanStr> = strings.iter().map(|s| GermanStr::new(s).unwrap()).collect();
+        match binary_search_german(&german, &needle) {
+            Ok(i) => assert_eq!(strings[i], needle),
+            Err(i) => assert_eq!(strings.binary_search(&needle), Err(i)),
+        }
+        let range = equal_range_by_prefix(&german, &needle);
+        let start = strings.partition_point(|s| *s < needle);
+        let len = strings[start..].iter().take_while(|s| s.starts_with(&needle)).count();
+        assert_eq!(range, start..start + len);
+    }
+
+    #[test]
+    fn longest_common_prefix(common: String, lhs: String, rhs: String) {
+        let (lhs, rhs) = (common.clone() + &lhs, common + &rhs);
+        let expected = lhs.bytes().zip(rhs.bytes()).take_while(|(l, r)| l == r).count();
+        let (german_lhs, german_rhs) = (GermanStr::new(&lhs).unwrap(), GermanStr::new(&rhs).unwrap());
+        assert_eq!(german_lhs.longest_common_prefix(&german_rhs), expected);
+        assert_eq!(german_rhs.longest_common_prefix(&german_lhs), expected);
+    }
+
+    #[test]
+    fn cmp_prefix(lhs: String, rhs: String) {
+        let german_lhs = GermanStr::new(&lhs).unwrap();
+        let german_rhs = GermanStr::new(&rhs).unwrap();
+        match german_lhs.cmp_prefix(&german_rhs) {
+            Some(ord) => assert_eq!(ord, lhs.cmp(&rhs)),
+            None => {
+                assert_eq!(lhs.as_bytes()[..4], rhs.as_bytes()[..4]);
+                assert!(lhs.len() > 4 && rhs.len() > 4);
+            }
+        }
+    }
+
+    #[test]
+    fn prefix_key(lhs: String, rhs: String) {
+        let german_lhs = GermanStr::new(&lhs).unwrap();
+        let german_rhs = GermanStr::new(&rhs).unwrap();
+        let ord = german_lhs.prefix_key().cmp(&german_rhs.prefix_key());
+        assert_eq!(Some(ord), german_lhs.cmp_prefix(&german_rhs).or(Some(std::cmp::Ordering::Equal)));
+        if ord.is_ne() || lhs.len() <= 4 {
+            assert_eq!(ord, lhs.cmp(&rhs));
+        }
+    }
+
+    #[test]
+    fn sort_key(lhs: (String, String), rhs: (String, String)) {
+        let key = |(a, b): &(String, String)| {
+            let mut key = Vec::new();
+            GermanStr::new(a).unwrap().write_sort_key(&mut key);
+            GermanStr::new(b).unwrap().write_sort_key(&mut key);
+            key
+        };
+        assert_eq!(key(&lhs).cmp(&key(&rhs)), lhs.cmp(&rhs));
+    }
+
+    #[test]
+    fn ordering_with_str(lhs: String, rhs: String) {
+        let german_lhs = GermanStr::new(&lhs).unwrap();
+        let expected = lhs.partial_cmp(&rhs);
+        assert_eq!(german_lhs.partial_cmp(rhs.as_str()), expected);
+        assert_eq!(german_lhs.partial_cmp(&rhs.as_str()), expected);
+        assert_eq!(german_lhs.partial_cmp(&rhs), expected);
+        assert_eq!(german_lhs.partial_cmp(&&rhs), expected);
+        assert_eq!(rhs.as_str().partial_cmp(&german_lhs), rhs.partial_cmp(&lhs));
+        assert_eq!(rhs.partial_cmp(&german_lhs), rhs.partial_cmp(&lhs));
+    }
+
     #[test]
     fn equality(lhs: String, rhs: String) {
         let german_lhs = GermanStr::new(&lhs).unwrap();
@@ -95,6 +999,17 @@ proptest! {
         assert_eq!(lhs == rhs, german_lhs == german_rhs);
     }
 
+    #[test]
+    fn german_str_ref(lhs: String, rhs: String) {
+        let ref_lhs = GermanStrRef::new(&lhs).unwrap();
+        let ref_rhs = GermanStrRef::new(&rhs).unwrap();
+        let german_rhs = GermanStr::new(&rhs).unwrap();
+        assert_eq!(lhs == rhs, ref_lhs == ref_rhs);
+        assert_eq!(lhs == rhs, ref_lhs == german_rhs);
+        assert_eq!(lhs.cmp(&rhs), ref_lhs.cmp(&ref_rhs));
+        assert_eq!(lhs.partial_cmp(&rhs), ref_lhs.partial_cmp(&german_rhs));
+    }
+
     #[test]
     fn clone(val: String) {
         let german = GermanStr::new(&val).unwrap();
@@ -114,41 +1029,166 @@ proptest! {
     }
 
     #[test]
-    fn prefix_bytes_slice(val: String) {
-        let german = GermanStr::new(&val).unwrap();
-        let prefix_len = val.len().min(4);
-        assert_eq!(
-            german.prefix_bytes_slice(),
-            &val.as_bytes()[..prefix_len],
-        );
+    fn inline_german_str(lhs: String, rhs: String) {
+        match (InlineGermanStr::new(&lhs), InlineGermanStr::new(&rhs)) {
+            (Ok(inline_lhs), Ok(inline_rhs)) => {
+                assert_eq!(inline_lhs, lhs.as_str());
+                assert_eq!(lhs.cmp(&rhs), inline_lhs.cmp(&inline_rhs));
+                assert_eq!(lhs == rhs, inline_lhs == inline_rhs);
+                assert_eq!(GermanStr::from(inline_lhs), GermanStr::new(&lhs).unwrap());
+            }
+            (lhs_res, rhs_res) => {
+                assert_eq!(lhs_res.is_err(), lhs.len() > MAX_INLINE_BYTES);
+                assert_eq!(rhs_res.is_err(), rhs.len() > MAX_INLINE_BYTES);
+            }
+        }
     }
 
     #[test]
-    fn prefix_bytes_array(val: String) {
+    fn index(val: String, start: usize, end: usize) {
         let german = GermanStr::new(&val).unwrap();
-        let prefix_len = val.len().min(4);
-        let mut og_array = [0; 4];
-        og_array[..prefix_len].copy_from_slice(&val.as_bytes()[..prefix_len]);
-        assert_eq!(
-            german.prefix_bytes_array(),
-            og_array,
-        );
+        let (start, end) = (start % (val.len() + 1), end % (val.len() + 1));
+        assert_eq!(german.get(start..end), val.get(start..end));
+        if let Some(slice) = val.get(start..) {
+            assert_eq!(&german[start..], slice);
+        }
+        assert_eq!(&german[..], val.as_str());
     }
 
     #[test]
-    fn suffix_bytes_slice(val: String) {
+    fn truncate(val: String, max_bytes: usize) {
         let german = GermanStr::new(&val).unwrap();
-        assert_eq!(
-            german.suffix_bytes_slice(),
-            val.as_bytes().get(4..).unwrap_or_default(),
-        );
+        let max_bytes = max_bytes % (val.len() + 2);
+        let truncated = german.truncate_to(max_bytes);
+        assert!(truncated.len() <= max_bytes);
+        assert!(val.starts_with(truncated.as_str()));
+        assert_eq!(truncated.is_inlined(), truncated.len() <= MAX_INLINE_BYTES);
+
+        let with_ellipsis = german.truncate_with_ellipsis(max_bytes);
+        assert!(with_ellipsis.len() <= max_bytes);
+        if val.len() <= max_bytes {
+            assert_eq!(with_ellipsis, val);
+        } else if max_bytes >= 3 {
+            let without = with_ellipsis.strip_suffix('…').unwrap();
+            assert!(val.starts_with(without));
+        }
     }
 
     #[test]
-    fn test_as_str(val: String) {
-        let german = GermanStr::new(&val).unwrap();
-        assert_eq!(
-            german.as_str(),
+    fn from_int(a: u8, b: i16, c: u32, d: i64, e: u128, f: i128, g: isize) {
+        assert_eq!(GermanStr::from(a), a.to_string());
+        assert_eq!(GermanStr::from(b), b.to_string());
+        assert_eq!(GermanStr::from(c), c.to_string());
+        assert_eq!(GermanStr::from(d), d.to_string());
+        assert_eq!(GermanStr::from(e), e.to_string());
+        assert_eq!(GermanStr::from(f), f.to_string());
+        assert_eq!(GermanStr::from(g), g.to_string());
+    }
+
+    #[test]
+    fn eq_str(lhs: String, rhs: String, nuls in 0..3_usize) {
+        let german = GermanStr::new(&lhs).unwrap();
+        for rhs in [rhs, lhs.clone() + &"\0".repeat(nuls)] {
+            let expected = lhs == rhs;
+            assert_eq!(german == *rhs.as_str(), expected);
+            assert_eq!(*rhs.as_str() == german, expected);
+            assert_eq!(german == rhs.as_str(), expected);
+            assert_eq!(rhs.as_str() == german, expected);
+            assert_eq!(german == rhs, expected);
+            assert_eq!(rhs == german, expected);
+            assert!(<GermanStr as PartialEq<&String>>::eq(&german, &&rhs) == expected);
+            assert!(<&String as PartialEq<GermanStr>>::eq(&&rhs, &german) == expected);
+        }
+    }
+
+    #[test]
+    fn c_str(lhs: Vec<u8>, rhs: Vec<u8>) {
+        let lhs = CString::new(lhs.into_iter().filter(|&b| b != 0).collect::<Vec<_>>()).unwrap();
+        let rhs = CString::new(rhs.into_iter().filter(|&b| b != 0).collect::<Vec<_>>()).unwrap();
+        let german_lhs = GermanCStr::from_c_str(&lhs).unwrap();
+        let german_rhs = GermanCStr::from_c_str(&rhs).unwrap();
+        assert_eq!(german_lhs.as_c_str(), lhs.as_c_str());
+        assert_eq!(german_lhs == german_rhs, lhs == rhs);
+        assert_eq!(german_lhs.cmp(&german_rhs), lhs.cmp(&rhs));
+    }
+
+    #[test]
+    fn trie(keys: Vec<String>, prefix: String) {
+        let expected: std::collections::BTreeMap<_, _> = keys.iter().map(|k| (k.clone(), k.len())).collect();
+        let trie: GermanStrTrie<_> = keys.iter().map(|k| (GermanStr::new(k).unwrap(), k.len())).collect();
+        assert_eq!(trie.len(), expected.len());
+        assert!(trie.iter().map(|(k, v)| (k.as_str(), v)).eq(expected.iter().map(|(k, v)| (k.as_str(), v))));
+        for prefix in keys.iter().map(|k| &k[..k.floor_char_boundary(5)]).chain([prefix.as_str()]) {
+            assert!(trie
+                .iter_prefix(prefix)
+                .map(|(k, v)| (k.as_str(), v))
+                .eq(expected.iter().filter(|(k, _)| k.starts_with(prefix)).map(|(k, v)| (k.as_str(), v))));
+        }
+    }
+
+    #[test]
+    fn umbra_column(strings: Vec<String>, garbage: Vec<u8>) {
+        let strings: Vec<_> = strings.iter().map(|s| GermanStr::new(s).unwrap()).collect();
+        let mut bytes = Vec::new();
+        UmbraColumn::write(&strings, &mut bytes);
+        assert_eq!(UmbraColumn::read(&bytes).unwrap().as_slice(), strings);
+        bytes.extend_from_slice(&garbage);
+        assert_eq!(UmbraColumn::read(&bytes).unwrap().as_slice(), strings);
+        let _ = UmbraColumn::read(&garbage);
+    }
+
+    #[test]
+    fn char_count(src: String) {
+        assert_eq!(GermanStr::new(&src).unwrap().char_count(), src.chars().count());
+    }
+
+    #[test]
+    fn is_ascii(src: String) {
+        let ascii: String = src.chars().filter(char::is_ascii).collect();
+        for s in [src, ascii] {
+            let german = GermanStr::new(&s).unwrap();
+            assert_eq!(german.is_ascii(), s.is_ascii());
+            assert_eq!(german.fast_char_count().is_some(), s.is_ascii() || german.is_inlined());
+            assert_eq!(german.clone().is_ascii(), s.is_ascii());
+        }
+    }
+
+    #[test]
+    fn prefix_bytes_slice(val: String) {
+        let german = GermanStr::new(&val).unwrap();
+        let prefix_len = val.len().min(4);
+        assert_eq!(
+            german.prefix_bytes_slice(),
+            &val.as_bytes()[..prefix_len],
+        );
+    }
+
+    #[test]
+    fn prefix_bytes_array(val: String) {
+        let german = GermanStr::new(&val).unwrap();
+        let prefix_len = val.len().min(4);
+        let mut og_array = [0; 4];
+        og_array[..prefix_len].copy_from_slice(&val.as_bytes()[..prefix_len]);
+        assert_eq!(
+            german.prefix_bytes_array(),
+            og_array,
+        );
+    }
+
+    #[test]
+    fn suffix_bytes_slice(val: String) {
+        let german = GermanStr::new(&val).unwrap();
+        assert_eq!(
+            german.suffix_bytes_slice(),
+            val.as_bytes().get(4..).unwrap_or_default(),
+        );
+    }
+
+    #[test]
+    fn test_as_str(val: String) {
+        let german = GermanStr::new(&val).unwrap();
+        assert_eq!(
+            german.as_str(),
             &val,
         );
     }
@@ -162,6 +1202,27 @@ proptest! {
         );
     }
 
+    #[test]
+    fn test_as_bytes(val: String, other: Vec<u8>) {
+        let german = GermanStr::new(&val).unwrap();
+        assert_eq!(german.as_bytes(), val.as_bytes());
+        assert_eq!(AsRef::<[u8]>::as_ref(&german), val.as_bytes());
+        assert!(german == *val.as_bytes());
+        assert!(val.as_bytes() == german);
+        assert_eq!(german == other.as_slice(), val.as_bytes() == other.as_slice());
+    }
+
+    #[test]
+    fn from_utf8(val: Vec<u8>) {
+        match (String::from_utf8(val.clone()), GermanStr::from_utf8(val)) {
+            (Ok(string), Ok(german)) => assert_eq!(german, string),
+            (Err(string_err), Err(german_err)) => {
+                assert_eq!(string_err.as_bytes(), german_err.as_bytes());
+            }
+            _ => panic!("String::from_utf8 and GermanStr::from_utf8 disagree"),
+        }
+    }
+
     #[test]
     fn test_len(val: String) {
         let german = GermanStr::new(&val).unwrap();
@@ -216,6 +1277,29 @@ proptest! {
         );
     }
 
+    #[test]
+    fn german_string_freeze(values: Vec<String>) {
+        let string: GermanString = values.iter().map(String::as_str).collect();
+        let german = string.freeze().unwrap();
+        assert_eq!(german, values.concat());
+    }
+
+    #[test]
+    fn german_str_vec(values: Vec<String>) {
+        let vec: GermanStrVec = values.iter().collect();
+        assert_eq!(vec.as_slice(), values.as_slice());
+    }
+
+    #[test]
+    fn german_str_dict(values: Vec<String>) {
+        let dict: GermanStrDict = values
+            .iter()
+            .map(|s| GermanStr::new(s).unwrap())
+            .collect();
+        assert_eq!(dict.to_vec(), values);
+        assert_eq!(dict.cardinality(), values.iter().collect::<std::collections::HashSet<_>>().len());
+    }
+
     #[test]
     fn build_writer(values: Vec<String>) {
         let mut writer = german_str::Writer::new();
@@ -234,6 +1318,8 @@ mod serde_tests {
     use std::collections::HashMap;
     use std::hash::Hash;
 
+    use german_str::InternedGermanStr;
+    use serde::de::DeserializeSeed;
     use serde::{Deserialize, Serialize};
 
     use super::*;
@@ -269,4 +1355,1648 @@ mod serde_tests {
             assert_eq!(parsed_vec, initial_vec);
         }
     }
+
+    #[test]
+    fn interned_seed() {
+        let json = r#""this is obviously longer than 12 bytes""#;
+        let mut interner = GermanStrInterner::new();
+        let a = InternedGermanStr(&mut interner)
+            .deserialize(&mut serde_json::Deserializer::from_str(json))
+            .unwrap();
+        let b = InternedGermanStr(&mut interner)
+            .deserialize(&mut serde_json::Deserializer::from_str(json))
+            .unwrap();
+        assert_eq!(a, "this is obviously longer than 12 bytes");
+        assert_eq!(a.heap_ptr(), b.heap_ptr());
+        assert_eq!(interner.len(), 1);
+
+        drop((a, b));
+        unsafe {
+            interner.free();
+        }
+    }
+}
+
+#[cfg(feature = "postgres-types")]
+mod postgres_tests {
+    use bytes::BytesMut;
+    use postgres_types::{FromSql, ToSql, Type};
+
+    use super::*;
+
+    #[test]
+    fn accepts_text_types() {
+        for ty in [Type::TEXT, Type::VARCHAR, Type::BPCHAR, Type::NAME] {
+            assert!(<GermanStr as ToSql>::accepts(&ty));
+            assert!(<GermanStr as FromSql>::accepts(&ty));
+        }
+        assert!(!<GermanStr as FromSql>::accepts(&Type::INT4));
+    }
+
+    proptest! {
+        #[test]
+        fn roundtrip(val: String) {
+            let german = GermanStr::new(&val).unwrap();
+            let mut buf = BytesMut::new();
+            german.to_sql(&Type::TEXT, &mut buf).unwrap();
+            let parsed = GermanStr::from_sql(&Type::TEXT, &buf).unwrap();
+            assert_eq!(parsed, val);
+        }
+    }
+}
+
+#[cfg(feature = "proptest")]
+mod proptest_tests {
+    use german_str::strategy;
+
+    use super::*;
+
+    proptest! {
+        #[test]
+        fn inline(german in strategy::inline()) {
+            assert!(german.is_inlined());
+        }
+
+        #[test]
+        fn heap(german in strategy::heap()) {
+            assert!(german.is_heap_allocated());
+        }
+
+        #[test]
+        fn boundary(german in strategy::boundary()) {
+            assert!((MAX_INLINE_BYTES - 1..=MAX_INLINE_BYTES + 1).contains(&german.len()));
+        }
+
+        #[test]
+        fn straddling_prefix(german in strategy::straddling_prefix()) {
+            assert!(!german.is_char_boundary(4));
+        }
+
+        #[test]
+        fn arbitrary_roundtrip(german: GermanStr) {
+            let copy = GermanStr::new(german.as_str()).unwrap();
+            assert_eq!(copy, german);
+        }
+    }
+}
+
+#[cfg(feature = "quickcheck")]
+mod quickcheck_tests {
+    use quickcheck::{quickcheck, Arbitrary};
+
+    use super::*;
+
+    #[test]
+    fn shrink_to_inline() {
+        let german = GermanStr::new("this is obviously longer than 12 bytes").unwrap();
+        let first = german.shrink().next().unwrap();
+        assert!(first.is_inlined());
+        assert_eq!(first, "this is obvi");
+    }
+
+    quickcheck! {
+        fn roundtrip(german: GermanStr) -> bool {
+            GermanStr::new(german.as_str()).unwrap() == german
+        }
+
+        fn shrinks_are_not_longer(german: GermanStr) -> bool {
+            german.shrink().take(16).all(|s| s.len() <= german.len())
+        }
+    }
+}
+
+#[cfg(feature = "bytes")]
+mod bytes_tests {
+    use bytes::Bytes;
+
+    use super::*;
+
+    #[test]
+    fn unique_buffer_is_reused() {
+        let src = b"this is obviously longer than 12 bytes".to_vec();
+        let ptr = src.as_ptr();
+        let german = GermanStr::try_from(Bytes::from(src)).unwrap();
+        assert_eq!(german, "this is obviously longer than 12 bytes");
+        assert_eq!(german.heap_ptr().unwrap().as_ptr().cast_const(), ptr);
+        assert!(!german.has_shared_buffer());
+
+        let bytes = Bytes::from(german);
+        assert_eq!(bytes.as_ptr(), ptr);
+    }
+
+    #[test]
+    fn shared_buffer_is_kept_alive() {
+        let bytes = Bytes::from(b"this is obviously longer than 12 bytes".to_vec());
+        let ptr = bytes.as_ptr();
+        let mut german = GermanStr::try_from(bytes.clone()).unwrap();
+        let mut other = GermanStr::try_from(bytes.slice(..)).unwrap();
+        drop(bytes);
+        assert_eq!(german.heap_ptr().unwrap().as_ptr().cast_const(), ptr);
+        assert_eq!(other.heap_ptr(), german.heap_ptr());
+        assert!(german.has_foreign_buffer() && !german.has_shared_buffer());
+        assert!(german.with_mut(|_| ()).is_none() && other.with_mut(|_| ()).is_none());
+        drop(other);
+        assert_eq!(german, "this is obviously longer than 12 bytes");
+        assert_eq!(Bytes::from(german), "this is obviously longer than 12 bytes");
+
+        let german = GermanStr::try_from(Bytes::from_static(b"a static buffer longer than 12 bytes")).unwrap();
+        assert_eq!(german, "a static buffer longer than 12 bytes");
+        assert!(german.has_foreign_buffer());
+    }
+
+    #[test]
+    fn aligned_buffer_is_copied() {
+        let german = GermanStr::new_aligned::<64>("this is obviously longer than 12 bytes").unwrap();
+        let ptr = german.heap_ptr().unwrap().as_ptr().cast_const();
+        let bytes = Bytes::from(german);
+        assert_ne!(bytes.as_ptr(), ptr);
+        assert_eq!(bytes, "this is obviously longer than 12 bytes");
+    }
+
+    #[test]
+    fn invalid_utf8() {
+        assert!(matches!(
+            GermanStr::try_from(Bytes::from_static(b"\xff")),
+            Err(InitError::InvalidUtf8(_)),
+        ));
+    }
+
+    proptest! {
+        #[test]
+        fn roundtrip(val: String) {
+            let german = GermanStr::try_from(Bytes::from(val.clone())).unwrap();
+            assert_eq!(&german, &val);
+            assert_eq!(Bytes::from(german), val.as_bytes());
+        }
+    }
+}
+
+#[cfg(feature = "memchr")]
+mod memchr_tests {
+    use super::*;
+
+    #[test]
+    fn needles() {
+        let german = GermanStr::new("this is obviously longer than 12 bytes, ünïcödé").unwrap();
+        assert_eq!(german.find_bytes('s'), Some(3));
+        assert_eq!(german.rfind_bytes('s'), Some(37));
+        assert_eq!(german.find_bytes('ï'), german.as_str().find('ï'));
+        assert_eq!(german.find_bytes(b'o'), Some(8));
+        assert_eq!(german.find_bytes(&b"than"[..]), Some(25));
+        assert!(german.contains_bytes(GermanStr::new("longer").unwrap()));
+        assert!(!german.contains_bytes("shorter"));
+        // The str methods aren't shadowed.
+        assert_eq!(german.find(char::is_whitespace), Some(4));
+        assert!(german.contains(|c: char| !c.is_ascii()));
+    }
+
+    proptest! {
+        #[test]
+        fn find(haystack: String, needle: String) {
+            let german = GermanStr::new(&haystack).unwrap();
+            assert_eq!(german.find_bytes(&needle), haystack.find(&needle));
+            assert_eq!(german.rfind_bytes(&needle), haystack.rfind(&needle));
+            assert_eq!(german.contains_bytes(&needle), haystack.contains(&needle));
+        }
+
+        #[test]
+        fn find_char(haystack: String, needle: char) {
+            let german = GermanStr::new(&haystack).unwrap();
+            assert_eq!(german.find_bytes(needle), haystack.find(needle));
+            assert_eq!(german.rfind_bytes(needle), haystack.rfind(needle));
+        }
+    }
+}
+
+#[cfg(feature = "equivalent")]
+mod equivalent_tests {
+    use equivalent::Comparable;
+    use indexmap::IndexMap;
+
+    use super::*;
+
+    #[test]
+    fn indexmap_lookups() {
+        let long = "this is obviously longer than 12 bytes";
+        let map: IndexMap<GermanStr, usize> = [
+            (GermanStr::new("short").unwrap(), 0),
+            (GermanStr::new(long).unwrap(), 1),
+        ].into_iter().collect();
+
+        assert_eq!(map.get("short"), Some(&0));
+        assert_eq!(map.get(long), Some(&1));
+        assert_eq!(map.get(&GermanStr::new(long).unwrap()), Some(&1));
+        assert_eq!(map.get(&InlineGermanStr::new("short").unwrap()), Some(&0));
+        assert_eq!(map.get(&GermanString::from(long)), Some(&1));
+        assert_eq!(map.get(&GermanCow::from(long)), Some(&1));
+        assert_eq!(map.get(&GermanCow::from("missing")), None);
+    }
+
+    proptest! {
+        #[test]
+        fn compare(lhs: String, rhs: String) {
+            let german = GermanStr::new(&rhs).unwrap();
+            let expected = lhs.cmp(&rhs);
+            assert_eq!(GermanString::from(lhs.as_str()).compare(&german), expected);
+            assert_eq!(GermanCow::from(lhs.as_str()).compare(&german), expected);
+            if let Ok(inline) = InlineGermanStr::new(&lhs) {
+                assert_eq!(inline.compare(&german), expected);
+            }
+        }
+    }
+}
+
+#[cfg(feature = "memmap2")]
+mod memmap2_tests {
+    use german_str::MmapStrings;
+
+    #[test]
+    fn open_lines() {
+        let path = std::env::temp_dir().join("german_str_open_lines.txt");
+        std::fs::write(&path, "short\r\nthis is obviously longer than 12 bytes\n\nlast line").unwrap();
+        let strings = unsafe { MmapStrings::open_lines(&path) }.unwrap();
+        assert_eq!(
+            strings.as_slice(),
+            ["short", "this is obviously longer than 12 bytes", "", "last line"],
+        );
+        assert!(strings[1].has_shared_buffer());
+        let range = strings.mapped_bytes().as_ptr_range();
+        assert!(range.contains(&strings[1].heap_ptr().unwrap().as_ptr().cast_const()));
+
+        let owned = strings[1].clone();
+        drop(strings);
+        assert_eq!(owned, "this is obviously longer than 12 bytes");
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn invalid_utf8() {
+        let path = std::env::temp_dir().join("german_str_invalid_utf8.txt");
+        std::fs::write(&path, b"valid\n\xff").unwrap();
+        let err = unsafe { MmapStrings::open_lines(&path) }.err().unwrap();
+        assert_eq!(err.kind(), std::io::ErrorKind::InvalidData);
+        std::fs::remove_file(&path).unwrap();
+    }
+}
+
+#[cfg(feature = "icu")]
+mod icu_tests {
+    use german_str::collation_order;
+    use icu_collator::{Collator, CollatorOptions};
+
+    use super::*;
+
+    #[test]
+    fn collate() {
+        let collator = Collator::try_new(&Default::default(), CollatorOptions::new()).unwrap();
+        let mut words = ["straße", "Strasse", "ärger", "zebra", "Arger"]
+            .map(|s| GermanStr::new(s).unwrap());
+        words.sort();
+        assert_eq!(words, ["Arger", "Strasse", "straße", "zebra", "ärger"]);
+        words.sort_by(collation_order(&collator));
+        assert_eq!(words, ["Arger", "ärger", "Strasse", "straße", "zebra"]);
+    }
+}
+
+#[cfg(feature = "intern")]
+mod intern_tests {
+    use super::*;
+
+    #[test]
+    fn intern() {
+        let long = "this string is only interned by this test";
+        let threads: Vec<_> = (0..4)
+            .map(|_| std::thread::spawn(move || GermanStr::intern(long).unwrap()))
+            .collect();
+        let interned: Vec<_> = threads.into_iter().map(|t| t.join().unwrap()).collect();
+        for s in &interned {
+            assert_eq!(s, long);
+            assert!(s.has_shared_buffer());
+            assert_eq!(s.heap_ptr(), interned[0].heap_ptr());
+        }
+        assert!(GermanStr::intern("short").unwrap().is_inlined());
+
+        let stats = GermanStr::intern_stats();
+        assert!(stats.strings >= 1);
+        assert!(stats.bytes >= long.len());
+        assert!(stats.hits >= 3);
+        assert!(stats.misses >= 1);
+    }
+}
+
+#[cfg(feature = "ryu")]
+mod ryu_tests {
+    use super::*;
+
+    #[test]
+    fn from_float() {
+        assert_eq!(GermanStr::from(1.5_f64), "1.5");
+        assert_eq!(GermanStr::from(1.0_f32), "1.0");
+        assert_eq!(GermanStr::from(-0.1_f64), "-0.1");
+        assert_eq!(GermanStr::from(1e20_f64), "1e20");
+        assert_eq!(GermanStr::from(f64::NAN), "NaN");
+        assert_eq!(GermanStr::from(f32::NEG_INFINITY), "-inf");
+        let long = GermanStr::from(0.1_f64 + 0.2);
+        assert_eq!(long.parse::<f64>().unwrap(), 0.1 + 0.2);
+    }
+}
+
+#[cfg(feature = "portable-atomic")]
+mod atomic_tests {
+    use german_str::AtomicGermanStr;
+
+    use super::*;
+
+    #[test]
+    fn atomic_german_str() {
+        let first = GermanStr::new("the first string, long enough to be allocated").unwrap();
+        let mut cell = AtomicGermanStr::new(first.clone());
+        let loaded = cell.load();
+        assert_eq!(loaded, first);
+        assert!(loaded.has_shared_buffer());
+        assert!(loaded.ptr_eq(&cell.load()));
+
+        let second = GermanStr::new("the second string, long enough to be allocated").unwrap();
+        assert!(cell.compare_exchange(&loaded, second.clone()).is_ok());
+        let short = GermanStr::new("short").unwrap();
+        assert_eq!(cell.compare_exchange(&loaded, short.clone()), Err(short.clone()));
+        assert_eq!(loaded, first);
+        assert_eq!(cell.load(), second);
+
+        cell.store(short.clone());
+        assert_eq!(cell.load(), short);
+        cell.store(second.clone());
+        cell.reclaim();
+        assert_eq!(cell.load(), second);
+        assert_eq!(format!("{cell:?}"), format!("{second:?}"));
+    }
+
+    #[test]
+    fn atomic_german_str_threads() {
+        let values: Vec<_> = (0..8).map(|i| format!("value number {i}, long enough to be allocated")).collect();
+        let cell = AtomicGermanStr::default();
+        std::thread::scope(|s| {
+            for value in &values {
+                let cell = &cell;
+                s.spawn(move || {
+                    for _ in 0..100 {
+                        cell.store(GermanStr::new(value).unwrap());
+                    }
+                });
+                s.spawn(|| {
+                    for _ in 0..100 {
+                        let current = cell.load();
+                        assert!(current.is_empty() || values.iter().any(|v| current == v.as_str()));
+                    }
+                });
+            }
+        });
+        assert!(values.iter().any(|v| cell.load() == v.as_str()));
+    }
+}
+
+#[cfg(feature = "wr_malloc_size_of")]
+mod malloc_size_of_tests {
+    use std::os::raw::c_void;
+
+    use wr_malloc_size_of::{MallocSizeOf, MallocSizeOfOps};
+
+    use super::*;
+
+    /// Pretends that every allocation is 100 bytes long.
+    unsafe extern "C" fn fake_size_of(_: *const c_void) -> usize {
+        100
+    }
+
+    #[test]
+    fn malloc_size_of() {
+        let mut ops = MallocSizeOfOps::new(fake_size_of, None);
+        assert_eq!(GermanStr::new("short").unwrap().size_of(&mut ops), 0);
+        let mut long = GermanStr::new("a string longer than 12 bytes").unwrap();
+        assert_eq!(long.size_of(&mut ops), 100);
+        let shared = long.leaky_shared_clone();
+        assert_eq!(shared.size_of(&mut ops), 0);
+        unsafe { shared.free() };
+
+        let vec: GermanStrVec = ["short", "a string longer than 12 bytes"].into_iter().collect();
+        assert_eq!(vec.size_of(&mut ops), 200);
+        assert_eq!(GermanStrVec::new().size_of(&mut ops), 0);
+    }
+}
+
+#[cfg(feature = "cxx")]
+mod cxx_tests {
+    use super::*;
+
+    #[test]
+    fn extern_type() {
+        fn assert_trivial<T: cxx::ExternType<Kind = cxx::kind::Trivial>>() {}
+        assert_trivial::<GermanStr>();
+    }
+}
+
+#[cfg(feature = "defmt")]
+mod defmt_tests {
+    use super::*;
+
+    #[test]
+    fn format() {
+        // Logging needs a defmt global logger, which only exists on the
+        // target: only check that GermanStr can be logged.
+        fn assert_format<T: defmt::Format>(_: &T) {}
+        assert_format(&GermanStr::new("a string longer than 12 bytes").unwrap());
+    }
+}
+
+#[cfg(feature = "ufmt")]
+mod ufmt_tests {
+    use std::convert::Infallible;
+
+    use super::*;
+    use ufmt::{uWrite, uwrite};
+
+    struct Buf(String);
+
+    impl uWrite for Buf {
+        type Error = Infallible;
+
+        fn write_str(&mut self, s: &str) -> Result<(), Infallible> {
+            self.0.push_str(s);
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn ufmt() {
+        for src in ["", "short", "a string longer than 12 bytes", "quotes \"'\" tab \t nul \0 é", "\u{301}combining e\u{301}", &"long ".repeat(30)] {
+            let german = GermanStr::new(src).unwrap();
+            let mut buf = Buf(String::new());
+            uwrite!(buf, "{} {:?}", german, german).unwrap();
+            assert_eq!(buf.0, format!("{german} {german:?}"));
+        }
+    }
+}
+
+#[cfg(feature = "heapless")]
+mod heapless_tests {
+    use super::*;
+
+    #[test]
+    fn heapless_string() {
+        let src = "a string longer than 12 bytes";
+        let fixed = heapless::String::<32>::try_from(src).unwrap();
+        let german = GermanStr::try_from(&fixed).unwrap();
+        assert_eq!(german, src);
+        assert_eq!(GermanStr::try_from(fixed).unwrap(), german);
+
+        let back = heapless::String::<32>::try_from(&german).unwrap();
+        assert_eq!(back, src);
+        let back: heapless::String<29, u8> = german.clone().try_into().unwrap();
+        assert_eq!(back, src);
+        assert!(heapless::String::<28>::try_from(german).is_err());
+        assert!(heapless::String::<8>::try_from(GermanStr::new("short").unwrap()).is_ok());
+    }
+}
+
+#[cfg(feature = "pyo3")]
+mod pyo3_tests {
+    use pyo3::types::{PyAnyMethods, PyInt, PyString};
+    use pyo3::{IntoPyObject, Python};
+
+    use super::*;
+
+    #[test]
+    fn pyo3() {
+        Python::initialize();
+        Python::attach(|py| {
+            for src in ["", "short", "a string longer than 12 bytes", "non-ASCII: ünïcödé"] {
+                let german = GermanStr::new(src).unwrap();
+                let obj = (&german).into_pyobject(py).unwrap();
+                assert_eq!(obj.extract::<String>().unwrap(), src);
+                assert_eq!(obj.extract::<GermanStr>().unwrap(), german);
+                assert_eq!(german.into_pyobject(py).unwrap().extract::<GermanStr>().unwrap(), src);
+                assert_eq!(PyString::new(py, src).extract::<GermanStr>().unwrap(), src);
+            }
+            assert!(PyInt::new(py, 1).extract::<GermanStr>().is_err());
+        });
+    }
+}
+
+#[cfg(feature = "napi")]
+mod napi_tests {
+    use napi::bindgen_prelude::{FromNapiValue, ToNapiValue, ValidateNapiValue};
+
+    use super::*;
+
+    #[test]
+    fn napi() {
+        // napi values can only be created inside a Node.js process: only
+        // check that the conversions exist.
+        fn assert_napi<T: ToNapiValue + FromNapiValue + ValidateNapiValue>() {}
+        assert_napi::<GermanStr>();
+        fn assert_ref_to_napi<'a, T: 'a>()
+        where
+            &'a T: ToNapiValue,
+        {
+        }
+        assert_ref_to_napi::<GermanStr>();
+    }
+}
+
+#[cfg(feature = "uniffi")]
+mod uniffi_tests {
+    use german_str::UniFfiTag;
+    use uniffi::{Lift, Lower};
+
+    use super::*;
+
+    #[test]
+    fn uniffi() {
+        for src in ["", "short", "a string longer than 12 bytes"] {
+            let german = GermanStr::new(src).unwrap();
+            let lowered = <GermanStr as Lower<UniFfiTag>>::lower(german.clone());
+            assert_eq!(<GermanStr as Lift<UniFfiTag>>::try_lift(lowered).unwrap(), german);
+
+            let mut buf = Vec::new();
+            <GermanStr as Lower<UniFfiTag>>::write(german.clone(), &mut buf);
+            assert_eq!(<GermanStr as Lift<UniFfiTag>>::try_read(&mut buf.as_slice()).unwrap(), german);
+        }
+    }
+}
+
+#[cfg(feature = "nom")]
+mod nom_tests {
+    use nom::bytes::complete::{tag, take_until, take_while1};
+    use nom::character::complete::{char, digit1};
+    use nom::combinator::map_res;
+    use nom::sequence::separated_pair;
+    use nom::{IResult, Parser};
+
+    use super::*;
+
+    fn key_value(input: GermanStrRef<'_>) -> IResult<GermanStrRef<'_>, (GermanStrRef<'_>, u32)> {
+        separated_pair(
+            take_while1(|c: char| c.is_alphanumeric() || c == '_'),
+            char('='),
+            map_res(digit1, |digits: GermanStrRef<'_>| digits.parse()),
+        )
+        .parse(input)
+    }
+
+    #[test]
+    fn nom() {
+        let german = GermanStr::new("a_long_key_name=1234; rest").unwrap();
+        let (rest, (key, value)) = key_value(GermanStrRef::from(&german)).unwrap();
+        assert_eq!(key, "a_long_key_name");
+        assert_eq!(key.prefix_bytes_array(), *b"a_lo");
+        assert_eq!(value, 1234);
+        assert_eq!(rest, "; rest");
+
+        let (rest, _) = tag::<_, _, ()>("; ").parse(rest).unwrap();
+        assert_eq!(rest, "rest");
+        let (rest, taken) = take_until::<_, _, ()>("st").parse(rest).unwrap();
+        assert_eq!((taken.as_str(), rest.as_str()), ("re", "st"));
+        assert!(key_value(GermanStrRef::new("=12").unwrap()).is_err());
+    }
+}
+
+#[cfg(feature = "winnow")]
+mod winnow_tests {
+    use winnow::ascii::digit1;
+    use winnow::combinator::separated_pair;
+    use winnow::error::ContextError;
+    use winnow::token::{take_until, take_while};
+    use winnow::Parser;
+
+    use super::*;
+
+    fn key_value<'a>(input: &mut GermanStrRef<'a>) -> winnow::Result<(GermanStrRef<'a>, u32)> {
+        separated_pair(
+            take_while(1.., |c: char| c.is_alphanumeric() || c == '_'),
+            '=',
+            digit1.parse_to(),
+        )
+        .parse_next(input)
+    }
+
+    #[test]
+    fn winnow() {
+        let german = GermanStr::new("a_long_key_name=1234; rest").unwrap();
+        let mut input = GermanStrRef::from(&german);
+        let (key, value) = key_value(&mut input).unwrap();
+        assert_eq!(key, "a_long_key_name");
+        assert_eq!(key.prefix_bytes_array(), *b"a_lo");
+        assert_eq!(value, 1234);
+        assert_eq!(input, "; rest");
+
+        "; ".parse_next(&mut input).map_err(|_: ContextError| ()).unwrap();
+        let taken = take_until::<_, _, ContextError>(0.., "st").parse_next(&mut input).unwrap();
+        assert_eq!((taken.as_str(), input.as_str()), ("re", "st"));
+        assert!(key_value.parse(GermanStrRef::new("=12").unwrap()).is_err());
+        assert_eq!(key_value.parse(GermanStrRef::new("k=12").unwrap()).unwrap().1, 12);
+    }
+}
+
+#[cfg(feature = "string-interner")]
+mod string_interner_tests {
+    use german_str::GermanStrBackend;
+    use string_interner::backend::Backend;
+    use string_interner::symbol::{Symbol, SymbolU16};
+    use string_interner::StringInterner;
+
+    #[test]
+    fn string_interner() {
+        let mut interner = StringInterner::<GermanStrBackend<SymbolU16>>::new();
+        let strings = ["", "short", "a string longer than 12 bytes", "short"];
+        let symbols = strings.map(|s| interner.get_or_intern(s));
+        assert_eq!(symbols[1], symbols[3]);
+        assert_eq!(interner.len(), 3);
+        for (symbol, s) in symbols.iter().zip(strings) {
+            assert_eq!(interner.resolve(*symbol), Some(s));
+        }
+        let static_symbol = interner.get_or_intern_static("a static string longer than 12 bytes");
+        assert_eq!(interner.get("a static string longer than 12 bytes"), Some(static_symbol));
+        let iterated: Vec<_> = interner.iter().map(|(_, s)| s).collect();
+        assert_eq!(iterated, ["", "short", "a string longer than 12 bytes", "a static string longer than 12 bytes"]);
+
+        let mut backend = GermanStrBackend::<SymbolU16>::default();
+        let symbol = backend.intern_static("a static string longer than 12 bytes");
+        let resolved = backend.resolve_german(symbol).unwrap();
+        assert!(resolved.has_shared_buffer());
+        assert_eq!(resolved, "a static string longer than 12 bytes");
+        assert_eq!(backend.resolve_german(SymbolU16::try_from_usize(1).unwrap()), None);
+    }
+}
+
+#[cfg(feature = "tantivy")]
+mod tantivy_tests {
+    use tantivy::collector::TopDocs;
+    use tantivy::query::TermQuery;
+    use tantivy::schema::document::{DeserializeError, DocumentDeserialize, DocumentDeserializer};
+    use tantivy::schema::{Document, Field, IndexRecordOption, OwnedValue, Schema, Value, STORED, STRING};
+    use tantivy::{Index, IndexWriter, Term};
+
+    use super::*;
+
+    struct Row(Vec<(Field, GermanStr)>);
+
+    impl Document for Row {
+        type Value<'a> = &'a GermanStr;
+        type FieldsValuesIter<'a> = std::iter::Map<std::slice::Iter<'a, (Field, GermanStr)>, fn(&(Field, GermanStr)) -> (Field, &GermanStr)>;
+
+        fn iter_fields_and_values(&self) -> Self::FieldsValuesIter<'_> {
+            self.0.iter().map(|(field, value)| (*field, value))
+        }
+    }
+
+    impl DocumentDeserialize for Row {
+        fn deserialize<'de, D: DocumentDeserializer<'de>>(mut deserializer: D) -> Result<Self, DeserializeError> {
+            let mut row = Vec::new();
+            while let Some(field_value) = deserializer.next_field()? {
+                row.push(field_value);
+            }
+            Ok(Row(row))
+        }
+    }
+
+    #[test]
+    fn tantivy() {
+        let mut schema = Schema::builder();
+        let name = schema.add_text_field("name", STRING | STORED);
+        let index = Index::create_in_ram(schema.build());
+        let mut writer: IndexWriter<Row> = index.writer_with_num_threads(1, 15_000_000).unwrap();
+        for s in ["short", "a string longer than 12 bytes"] {
+            writer.add_document(Row(vec![(name, GermanStr::new(s).unwrap())])).unwrap();
+        }
+        writer.commit().unwrap();
+
+        let searcher = index.reader().unwrap().searcher();
+        let query = TermQuery::new(Term::from_field_text(name, "a string longer than 12 bytes"), IndexRecordOption::Basic);
+        let hits = searcher.search(&query, &TopDocs::with_limit(2).order_by_score()).unwrap();
+        assert_eq!(hits.len(), 1);
+        let row: Row = searcher.doc(hits[0].1).unwrap();
+        assert_eq!(row.0, [(name, GermanStr::new("a string longer than 12 bytes").unwrap())]);
+
+        let german = GermanStr::new("short").unwrap();
+        assert_eq!(Value::as_str(&&german), Some("short"));
+        assert_eq!(OwnedValue::from(&german), OwnedValue::Str("short".to_owned()));
+    }
+}
+
+#[cfg(feature = "redis")]
+mod redis_tests {
+    use redis::{FromRedisValue, ToRedisArgs, Value};
+
+    use super::*;
+
+    #[test]
+    fn redis() {
+        for src in ["", "short", "a string longer than 12 bytes"] {
+            let german = GermanStr::new(src).unwrap();
+            assert_eq!(german.to_redis_args(), [src.as_bytes()]);
+            let bulk = Value::BulkString(src.as_bytes().to_vec());
+            assert_eq!(GermanStr::from_redis_value_ref(&bulk).unwrap(), src);
+            assert_eq!(GermanStr::from_redis_value(bulk).unwrap(), src);
+        }
+        assert_eq!(GermanStr::from_redis_value(Value::Okay).unwrap(), "OK");
+        assert_eq!(GermanStr::from_redis_value(Value::Int(42)).unwrap(), "42");
+        assert!(GermanStr::from_redis_value(Value::BulkString(vec![0xff])).is_err());
+        assert!(GermanStr::from_redis_value_ref(&Value::BulkString(vec![0xff])).is_err());
+        assert!(GermanStr::from_redis_value(Value::Nil).is_err());
+    }
+}
+
+#[cfg(feature = "bson")]
+mod bson_tests {
+    use bson::{doc, Bson, RawBsonRef, RawDocumentBuf};
+
+    use super::*;
+
+    #[test]
+    fn bson() {
+        let long = GermanStr::new("a string longer than 12 bytes").unwrap();
+        let document = doc! { "short": GermanStr::new("short").unwrap(), "long": &long, "n": 1 };
+        assert_eq!(document.get_str("long").unwrap(), "a string longer than 12 bytes");
+        assert_eq!(GermanStr::try_from(document.get("short").unwrap().clone()).unwrap(), "short");
+        assert_eq!(GermanStr::try_from(Bson::Int32(1)), Err(Bson::Int32(1)));
+
+        let raw = RawDocumentBuf::try_from(&document).unwrap();
+        assert_eq!(GermanStr::try_from(raw.get("long").unwrap().unwrap()).unwrap(), long);
+        assert!(matches!(GermanStr::try_from(raw.get("n").unwrap().unwrap()), Err(RawBsonRef::Int32(1))));
+    }
+}
+
+#[cfg(feature = "sled")]
+mod sled_tests {
+    use sled::IVec;
+
+    use super::*;
+
+    #[test]
+    fn sled() {
+        let db = sled::Config::new().temporary(true).open().unwrap();
+        let key = GermanStr::new("key").unwrap();
+        let value = GermanStr::new("a string longer than 12 bytes").unwrap();
+        db.insert(&key, &value).unwrap();
+        let stored = db.get(&key).unwrap().unwrap();
+        assert_eq!(GermanStr::try_from(&stored).unwrap(), value);
+        assert_eq!(GermanStr::try_from(stored).unwrap(), value);
+        assert_eq!(IVec::from(GermanStr::new("short").unwrap()), b"short");
+        assert!(matches!(GermanStr::try_from(IVec::from(&[0xff][..])), Err(InitError::InvalidUtf8(_))));
+    }
+}
+
+#[cfg(feature = "redb")]
+mod redb_tests {
+    use redb::backends::InMemoryBackend;
+    use redb::{Database, ReadableDatabase, ReadableTable, TableDefinition};
+
+    use super::*;
+
+    const TABLE: TableDefinition<GermanStr, GermanStr> = TableDefinition::new("strings");
+
+    #[test]
+    fn redb() {
+        let db = Database::builder().create_with_backend(InMemoryBackend::new()).unwrap();
+        let keys = ["", "b", "a string longer than 12 bytes", "a", "short", "shorter"];
+        let write = db.begin_write().unwrap();
+        {
+            let mut table = write.open_table(TABLE).unwrap();
+            for key in keys {
+                let key = GermanStr::new(key).unwrap();
+                table.insert(&key, &key).unwrap();
+            }
+        }
+        write.commit().unwrap();
+
+        let read = db.begin_read().unwrap();
+        let table = read.open_table(TABLE).unwrap();
+        let stored: Vec<_> = table.iter().unwrap().map(|entry| entry.unwrap().0.value()).collect();
+        let mut sorted = keys.map(|key| GermanStr::new(key).unwrap());
+        sorted.sort();
+        assert_eq!(stored, sorted);
+        let key = GermanStr::new("a string longer than 12 bytes").unwrap();
+        assert_eq!(table.get(&key).unwrap().unwrap().value(), key);
+        let range: Vec<_> = table.range(GermanStr::new("b").unwrap()..).unwrap().map(|entry| entry.unwrap().0.value()).collect();
+        assert_eq!(range, ["b", "short", "shorter"]);
+    }
+}
+
+#[cfg(feature = "heed")]
+mod heed_tests {
+    use german_str::{GermanStrCodec, GermanStrRefCodec};
+    use heed_traits::{BytesDecode, BytesEncode};
+
+    use super::*;
+
+    #[test]
+    fn heed() {
+        for src in ["", "short", "a string longer than 12 bytes"] {
+            let german = GermanStr::new(src).unwrap();
+            let encoded = GermanStrCodec::bytes_encode(&german).unwrap();
+            assert_eq!(&*encoded, src.as_bytes());
+            assert_eq!(GermanStrCodec::bytes_decode(&encoded).unwrap(), german);
+
+            let borrowed = GermanStrRefCodec::bytes_decode(&encoded).unwrap();
+            assert_eq!(borrowed, src);
+            assert!(std::ptr::eq(borrowed.as_bytes(), &*encoded));
+            assert_eq!(&*GermanStrRefCodec::bytes_encode(&borrowed).unwrap(), src.as_bytes());
+        }
+        assert!(GermanStrCodec::bytes_decode(&[0xff]).is_err());
+        assert!(GermanStrRefCodec::bytes_decode(&[0xff]).is_err());
+    }
+}
+
+#[cfg(feature = "http")]
+mod http_tests {
+    use http::header::{HeaderName, HeaderValue, CONTENT_TYPE};
+
+    use super::*;
+
+    #[test]
+    fn http() {
+        let value = HeaderValue::from_static("text/html; charset=utf-8");
+        let german = GermanStr::try_from(&value).unwrap();
+        assert_eq!(german, "text/html; charset=utf-8");
+        assert_eq!(HeaderValue::try_from(&german).unwrap(), value);
+        assert_eq!(HeaderValue::try_from(german).unwrap(), value);
+        let utf8 = HeaderValue::from_bytes("naïve".as_bytes()).unwrap();
+        assert_eq!(GermanStr::try_from(&utf8).unwrap(), "naïve");
+        let invalid = HeaderValue::from_bytes(&[0xff]).unwrap();
+        assert!(matches!(GermanStr::try_from(&invalid), Err(InitError::InvalidUtf8(_))));
+        assert!(HeaderValue::try_from(GermanStr::new("line\nbreak").unwrap()).is_err());
+
+        let name = GermanStr::from(&CONTENT_TYPE);
+        assert_eq!(name, "content-type");
+        assert_eq!(HeaderName::try_from(&name).unwrap(), CONTENT_TYPE);
+        assert_eq!(HeaderName::try_from(GermanStr::new("X-Request-Id").unwrap()).unwrap(), "x-request-id");
+        assert!(HeaderName::try_from(GermanStr::new("not a name").unwrap()).is_err());
+    }
+}
+
+#[cfg(feature = "async-graphql")]
+mod async_graphql_tests {
+    use async_graphql::{EmptyMutation, EmptySubscription, Object, Schema};
+
+    use super::*;
+
+    struct Query;
+
+    #[Object]
+    impl Query {
+        async fn greet(&self, name: GermanStr) -> GermanStr {
+            GermanStr::new(format!("hello, {name}")).unwrap()
+        }
+
+        async fn names(&self) -> Vec<GermanStr> {
+            vec![GermanStr::new("short").unwrap(), GermanStr::new("a string longer than 12 bytes").unwrap()]
+        }
+
+        async fn plain(&self) -> String {
+            String::from("plain")
+        }
+    }
+
+    #[test]
+    fn async_graphql() {
+        let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+        let sdl = schema.sdl();
+        assert!(sdl.contains("greet(name: String!): String!"), "{sdl}");
+        assert!(sdl.contains("names: [String!]!"), "{sdl}");
+        assert!(!sdl.contains("scalar String"), "{sdl}");
+
+        let response = futures::executor::block_on(schema.execute(r#"{ greet(name: "world") names plain }"#));
+        assert!(response.errors.is_empty(), "{:?}", response.errors);
+        assert_eq!(
+            response.data.into_json().unwrap(),
+            serde_json::json!({ "greet": "hello, world", "names": ["short", "a string longer than 12 bytes"], "plain": "plain" })
+        );
+
+        let response = futures::executor::block_on(schema.execute("{ greet(name: 1) }"));
+        assert_eq!(response.errors.len(), 1);
+    }
+}
+
+#[cfg(feature = "utoipa")]
+mod utoipa_tests {
+    use utoipa::{PartialSchema, ToSchema};
+
+    use super::*;
+
+    #[allow(dead_code)]
+    #[derive(ToSchema)]
+    struct Pet {
+        #[schema(inline)]
+        name: GermanStr,
+        owner: GermanStr,
+        tags: Vec<String>,
+    }
+
+    #[test]
+    fn utoipa() {
+        let json = |schema| serde_json::to_value(schema).unwrap();
+        assert_eq!(json(GermanStr::schema()), serde_json::json!({ "type": "string" }));
+        assert_eq!(GermanStr::name(), "GermanStr");
+
+        assert_eq!(
+            json(Pet::schema()),
+            serde_json::json!({
+                "type": "object",
+                "required": ["name", "owner", "tags"],
+                "properties": {
+                    "name": { "type": "string" },
+                    "owner": { "$ref": "#/components/schemas/GermanStr" },
+                    "tags": { "type": "array", "items": { "type": "string" } },
+                },
+            }),
+        );
+        let mut schemas = Vec::new();
+        Pet::schemas(&mut schemas);
+        let schemas: Vec<_> = schemas.into_iter().map(|(name, schema)| (name, json(schema))).collect();
+        assert_eq!(schemas, [("GermanStr".to_string(), serde_json::json!({ "type": "string" }))]);
+    }
+}
+
+#[cfg(feature = "speedy")]
+mod speedy_tests {
+    use speedy::{Endianness, Readable, Writable};
+
+    use super::*;
+
+    #[test]
+    fn speedy() {
+        for s in ["", "short", "exactly 12 b", "a string longer than 12 bytes"] {
+            let german = GermanStr::new(s).unwrap();
+            let bytes = german.write_to_vec().unwrap();
+            assert_eq!(bytes, String::from(s).write_to_vec().unwrap());
+            let read = GermanStr::read_from_buffer(&bytes).unwrap();
+            assert_eq!(read, german);
+            assert_eq!(read.is_heap_allocated(), s.len() > MAX_INLINE_BYTES);
+        }
+
+        let strings = vec![GermanStr::new("short").unwrap(), GermanStr::new("a string longer than 12 bytes").unwrap()];
+        let bytes = strings.write_to_vec_with_ctx(Endianness::BigEndian).unwrap();
+        assert_eq!(Vec::<GermanStr>::read_from_buffer_with_ctx(Endianness::BigEndian, &bytes).unwrap(), strings);
+
+        let mut invalid = 1u32.write_to_vec().unwrap();
+        invalid.push(0xff);
+        assert!(GermanStr::read_from_buffer(&invalid).is_err());
+        let mut truncated = GermanStr::new("a string longer than 12 bytes").unwrap().write_to_vec().unwrap();
+        truncated.pop();
+        assert!(GermanStr::read_from_buffer(&truncated).is_err());
+    }
+}
+
+#[cfg(feature = "musli")]
+mod musli_tests {
+    use musli::{Decode, Encode};
+
+    use super::*;
+
+    #[derive(Debug, PartialEq, Encode, Decode)]
+    struct Row {
+        id: u32,
+        name: GermanStr,
+        tags: Vec<GermanStr>,
+    }
+
+    #[test]
+    fn musli() {
+        let row = Row {
+            id: 1,
+            name: GermanStr::new("a string longer than 12 bytes").unwrap(),
+            tags: vec![GermanStr::new("short").unwrap(), GermanStr::new("").unwrap()],
+        };
+        let bytes = musli::storage::to_vec(&row).unwrap();
+        assert_eq!(musli::storage::from_slice::<Row>(&bytes).unwrap(), row);
+        let bytes = musli::wire::to_vec(&row).unwrap();
+        assert_eq!(musli::wire::from_slice::<Row>(&bytes).unwrap(), row);
+
+        let german = GermanStr::new("short").unwrap();
+        assert_eq!(musli::storage::to_vec(&german).unwrap(), musli::storage::to_vec("short").unwrap());
+        let invalid = musli::storage::to_vec(&[0xffu8][..]).unwrap();
+        assert!(musli::storage::from_slice::<GermanStr>(&invalid).is_err());
+    }
+}
+
+#[cfg(feature = "minicbor")]
+mod minicbor_tests {
+    use minicbor::{CborLen, Decode, Encode};
+
+    use super::*;
+
+    #[derive(Debug, PartialEq, Encode, Decode, CborLen)]
+    struct Reading {
+        #[n(0)]
+        sensor: GermanStr,
+        #[n(1)]
+        unit: GermanStr,
+    }
+
+    #[test]
+    fn minicbor() {
+        for s in ["", "short", "a string longer than 12 bytes"] {
+            let german = GermanStr::new(s).unwrap();
+            let bytes = minicbor::to_vec(&german).unwrap();
+            assert_eq!(bytes, minicbor::to_vec(s).unwrap());
+            assert_eq!(minicbor::len(&german), bytes.len());
+            assert_eq!(minicbor::decode::<GermanStr>(&bytes).unwrap(), german);
+        }
+
+        let reading = Reading {
+            sensor: GermanStr::new("a string longer than 12 bytes").unwrap(),
+            unit: GermanStr::new("°C").unwrap(),
+        };
+        let mut buf = [0u8; 64];
+        minicbor::encode(&reading, &mut buf[..]).unwrap();
+        assert_eq!(minicbor::decode::<Reading>(&buf).unwrap(), reading);
+
+        let bytes = minicbor::to_vec(42u32).unwrap();
+        assert!(minicbor::decode::<GermanStr>(&bytes).is_err());
+    }
+}
+
+#[cfg(feature = "prost")]
+mod prost_tests {
+    use prost::bytes::{Buf, BufMut};
+    use prost::encoding::{skip_field, DecodeContext, WireType};
+    use prost::{DecodeError, Message};
+
+    use super::*;
+
+    #[derive(Debug, Default, PartialEq)]
+    struct User {
+        name: GermanStr,
+        tags: Vec<GermanStr>,
+    }
+
+    impl Message for User {
+        fn encode_raw(&self, buf: &mut impl BufMut) {
+            if !self.name.is_empty() {
+                german_str::prost::encode(1, &self.name, buf);
+            }
+            german_str::prost::encode_repeated(2, &self.tags, buf);
+        }
+
+        fn merge_field(&mut self, tag: u32, wire_type: WireType, buf: &mut impl Buf, ctx: DecodeContext) -> Result<(), DecodeError> {
+            match tag {
+                1 => german_str::prost::merge(wire_type, &mut self.name, buf, ctx),
+                2 => german_str::prost::merge_repeated(wire_type, &mut self.tags, buf, ctx),
+                _ => skip_field(wire_type, tag, buf, ctx),
+            }
+        }
+
+        fn encoded_len(&self) -> usize {
+            let name = if self.name.is_empty() { 0 } else { german_str::prost::encoded_len(1, &self.name) };
+            name + german_str::prost::encoded_len_repeated(2, &self.tags)
+        }
+
+        fn clear(&mut self) {
+            self.name = GermanStr::default();
+            self.tags.clear();
+        }
+    }
+
+    #[derive(Clone, PartialEq, Message)]
+    struct GeneratedUser {
+        #[prost(string, tag = "1")]
+        name: String,
+        #[prost(string, repeated, tag = "2")]
+        tags: Vec<String>,
+        #[prost(uint64, tag = "3")]
+        id: u64,
+    }
+
+    #[test]
+    fn prost() {
+        let generated = GeneratedUser {
+            name: String::from("a string longer than 12 bytes"),
+            tags: vec![String::from("short"), String::new(), String::from("another long string")],
+            id: 7,
+        };
+        let bytes = generated.encode_to_vec();
+        let user = User::decode(&*bytes).unwrap();
+        assert_eq!(user.name, "a string longer than 12 bytes");
+        assert_eq!(user.tags, ["short", "", "another long string"]);
+        assert!(!user.tags[0].is_heap_allocated());
+
+        let bytes = user.encode_to_vec();
+        assert_eq!(bytes.len(), user.encoded_len());
+        assert_eq!(GeneratedUser::decode(&*bytes).unwrap(), GeneratedUser { id: 0, ..generated });
+
+        let invalid = [0x0a, 0x02, 0xc3, 0x28];
+        assert!(User::decode(&invalid[..]).is_err());
+        let truncated = [0x0a, 0x05, b'a'];
+        assert!(User::decode(&truncated[..]).is_err());
+    }
+}
+
+#[cfg(feature = "bincode")]
+mod bincode_tests {
+    use bincode::{Decode, Encode};
+
+    use super::*;
+
+    #[derive(Debug, PartialEq, Encode, Decode)]
+    struct Entry {
+        key: GermanStr,
+        values: Vec<GermanStr>,
+    }
+
+    #[test]
+    fn bincode() {
+        let config = bincode::config::standard();
+        for s in ["", "short", "exactly 12 b", "a string longer than 12 bytes"] {
+            let german = GermanStr::new(s).unwrap();
+            let bytes = bincode::encode_to_vec(&german, config).unwrap();
+            assert_eq!(bytes, bincode::encode_to_vec(s, config).unwrap());
+            let (decoded, read) = bincode::decode_from_slice::<GermanStr, _>(&bytes, config).unwrap();
+            assert_eq!((decoded, read), (german, bytes.len()));
+            let (borrowed, _) = bincode::borrow_decode_from_slice::<GermanStr, _>(&bytes, config).unwrap();
+            assert_eq!(borrowed, s);
+        }
+
+        let entry = Entry {
+            key: GermanStr::new("a string longer than 12 bytes").unwrap(),
+            values: vec![GermanStr::new("short").unwrap()],
+        };
+        let bytes = bincode::encode_to_vec(&entry, bincode::config::legacy()).unwrap();
+        assert_eq!(bincode::decode_from_slice::<Entry, _>(&bytes, bincode::config::legacy()).unwrap().0, entry);
+
+        let invalid = bincode::encode_to_vec([0xffu8].as_slice(), config).unwrap();
+        assert!(matches!(
+            bincode::decode_from_slice::<GermanStr, _>(&invalid, config),
+            Err(bincode::error::DecodeError::Utf8 { .. })
+        ));
+        let limited = bincode::config::standard().with_limit::<8>();
+        let bytes = bincode::encode_to_vec(&entry.key, config).unwrap();
+        assert!(bincode::decode_from_slice::<GermanStr, _>(&bytes, limited).is_err());
+    }
+}
+
+#[cfg(feature = "rand")]
+mod rand_tests {
+    use german_str::GermanStrDistribution;
+    use rand::distributions::{Distribution, Standard, Uniform, WeightedIndex};
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    use super::*;
+
+    #[test]
+    fn distribution() {
+        let mut rng = StdRng::seed_from_u64(0);
+        let alphanumeric = GermanStrDistribution::alphanumeric(Uniform::new_inclusive(0, 40));
+        for german in alphanumeric.sample_iter(&mut rng).take(200) {
+            assert!(german.len() <= 40);
+            assert!(german.bytes().all(|b| b.is_ascii_alphanumeric()));
+            assert_eq!(german.is_heap_allocated(), german.len() > MAX_INLINE_BYTES);
+        }
+
+        let utf8 = GermanStrDistribution::utf8(Uniform::new_inclusive(1, 8));
+        for german in utf8.sample_iter(&mut rng).take(200) {
+            assert!((1..=8).contains(&german.chars().count()));
+            assert_eq!(german.prefix_bytes_slice(), &german.as_bytes()[..german.len().min(4)]);
+        }
+
+        // Exactly 4 or 30 chars.
+        let lens = [4, 30];
+        let index = WeightedIndex::new([1, 1]).unwrap();
+        let fixed = GermanStrDistribution::new(Standard, index.map(|i| lens[i]));
+        assert!(fixed.sample_iter(&mut rng).take(50).all(|s| lens.contains(&s.chars().count())));
+
+        let first = GermanStrDistribution::alphanumeric(Uniform::new(20, 30)).sample(&mut StdRng::seed_from_u64(1));
+        let second = GermanStrDistribution::alphanumeric(Uniform::new(20, 30)).sample(&mut StdRng::seed_from_u64(1));
+        assert_eq!(first, second);
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+mod arbitrary_tests {
+    use std::collections::BTreeSet;
+
+    use arbitrary::{Arbitrary, Unstructured};
+
+    use super::*;
+
+    #[test]
+    fn arbitrary_edges() {
+        assert_eq!(GermanStr::arbitrary(&mut Unstructured::new(&[])).unwrap(), "");
+
+        let (mut lens, mut straddling, mut shared) = (BTreeSet::new(), 0, 0);
+        let mut state = 0x2545f491_u32;
+        for _ in 0..2000 {
+            let data: Vec<u8> = (0..64)
+                .map(|_| {
+                    state ^= state << 13;
+                    state ^= state >> 17;
+                    state ^= state << 5;
+                    state as u8
+                })
+                .collect();
+            let german = GermanStr::arbitrary(&mut Unstructured::new(&data)).unwrap();
+            assert_eq!(german, GermanStr::new(german.as_str()).unwrap());
+            lens.insert(german.len());
+            straddling += usize::from(german.len() > 4 && !german.is_char_boundary(4));
+            shared += usize::from(german.has_shared_buffer());
+        }
+        for len in [0, 4, MAX_INLINE_BYTES - 1, MAX_INLINE_BYTES, MAX_INLINE_BYTES + 1] {
+            assert!(lens.contains(&len), "{len}");
+        }
+        assert!(straddling > 0);
+        assert!(shared > 0);
+    }
+}
+
+#[cfg(feature = "simdutf8")]
+mod simdutf8_tests {
+    use super::*;
+
+    #[test]
+    fn simdutf8() {
+        for valid in ["", "short é", "a string longer than 12 bytes, with multi-byte chars: é, 中文, 🦀"] {
+            assert_eq!(GermanStr::from_utf8(valid.as_bytes().to_vec()).unwrap(), valid);
+        }
+
+        // simdutf8's basic API doesn't report where the error is: it comes
+        // from the fallback to core::str::from_utf8.
+        for (bytes, valid_up_to) in [(&b"ab\xffc"[..], 2), (&b"a string longer than 12 bytes\xe2\x82"[..], 29)] {
+            let e = GermanStr::from_utf8(bytes.to_vec()).unwrap_err();
+            let InitError::InvalidUtf8(utf8_error) = e.init_error() else {
+                panic!("unexpected error: {e:?}");
+            };
+            assert_eq!(utf8_error.valid_up_to(), valid_up_to);
+            assert_eq!(utf8_error, core::str::from_utf8(bytes).unwrap_err());
+            assert_eq!(e.into_bytes(), bytes);
+        }
+    }
+}
+
+#[cfg(feature = "subtle")]
+mod subtle_tests {
+    use super::*;
+    use german_str::CtGermanStr;
+    use subtle::ConstantTimeEq;
+
+    #[test]
+    fn ct_eq() {
+        let strings = ["", "a", "a\0", "inline", "inlinf", "a string longer than 12 bytes", "a string longer than 12 bytez"];
+        for a in strings {
+            for b in strings {
+                let (german_a, german_b) = (GermanStr::new(a).unwrap(), GermanStr::new(b).unwrap());
+                assert_eq!(bool::from(german_a.ct_eq(&german_b)), a == b, "{a:?} {b:?}");
+                let (ct_a, ct_b) = (CtGermanStr::from(german_a), CtGermanStr::new(b).unwrap());
+                assert_eq!(ct_a == ct_b, a == b);
+                assert_eq!(ct_a == b, a == b);
+            }
+        }
+    }
+}
+
+#[cfg(feature = "rayon")]
+mod rayon_tests {
+    use super::*;
+    use german_str::{par_dedup_german, par_sort_german};
+
+    proptest! {
+        #[test]
+        fn par_sort(strings in proptest::collection::vec("[ab\\x00]{0,20}", 0..200)) {
+            let mut german: Vec<GermanStr> = strings.iter().map(|s| GermanStr::new(s).unwrap()).collect();
+            par_sort_german(&mut german);
+            let mut sorted = strings.clone();
+            sorted.sort();
+            assert_eq!(german, sorted);
+
+            let mut german: Vec<GermanStr> = strings.iter().map(|s| GermanStr::new(s).unwrap()).collect();
+            par_dedup_german(&mut german);
+            sorted.dedup();
+            assert_eq!(german, sorted);
+        }
+    }
+}
+
+#[cfg(feature = "zeroize")]
+mod zeroize_tests {
+    use super::*;
+    use zeroize::Zeroize;
+
+    #[test]
+    fn zeroize() {
+        for src in ["", "inline", "a string longer than 12 bytes"] {
+            let mut german = GermanStr::new(src).unwrap();
+            german.zeroize();
+            assert_eq!(german, "");
+            assert!(german.is_inlined());
+        }
+
+        // Shared buffers are left to their owner.
+        let mut owner = GermanStr::new("a string longer than 12 bytes").unwrap();
+        let mut shared = owner.leaky_shared_clone();
+        shared.zeroize();
+        assert_eq!(shared, "");
+        assert_eq!(owner, "a string longer than 12 bytes");
+        unsafe { owner.free() };
+    }
+}
+
+#[cfg(feature = "metrics")]
+mod metrics_tests {
+    use super::*;
+    use german_str::AllocMetrics;
+
+    // The counters are global and other tests run concurrently, so only
+    // lower bounds can be checked.
+    #[test]
+    fn alloc_metrics() {
+        let before = AllocMetrics::snapshot();
+        let _inlined = GermanStr::new("inlined").unwrap();
+        let mut owner = GermanStr::new("a string longer than 12 bytes").unwrap();
+        let copy = owner.clone();
+        let shared = owner.leaky_shared_clone();
+        drop(copy);
+        drop(owner);
+        unsafe { shared.free() };
+        let _adopted = GermanStr::try_from(String::from("adopted, not copied")).unwrap();
+        let diff = AllocMetrics::snapshot().since(&before);
+        assert!(diff.allocs >= 3);
+        assert!(diff.allocated_bytes >= 2 * 29 + 19);
+        assert!(diff.frees >= 2);
+        assert!(diff.freed_bytes >= 2 * 29);
+        assert!(diff.shared_reuses >= 1);
+
+        let previous = AllocMetrics::reset();
+        assert!(previous.allocs >= diff.allocs);
+        assert!(AllocMetrics::snapshot().allocs <= previous.allocs);
+    }
+}
+
+#[cfg(any(feature = "get-size", feature = "deepsize"))]
+mod size_tests {
+    use super::*;
+
+    /// Returns the heap size of a string, and of a vec with the same strings.
+    fn sizes(size_of: impl Fn(&GermanStr) -> usize, vec_size_of: impl Fn(&GermanStrVec) -> usize) {
+        assert_eq!(size_of(&GermanStr::new("short").unwrap()), 0);
+        let mut long = GermanStr::new("a string longer than 12 bytes").unwrap();
+        assert_eq!(size_of(&long), 29);
+        let shared = long.leaky_shared_clone();
+        assert_eq!(size_of(&shared), 0);
+        unsafe { shared.free() };
+
+        let mut vec = GermanStrVec::with_capacity(2, 29);
+        vec.extend(["short", "a string longer than 12 bytes"]);
+        assert_eq!(vec_size_of(&vec), 2 * 16 + 29);
+    }
+
+    #[cfg(feature = "get-size")]
+    #[test]
+    fn get_size() {
+        use get_size::GetSize;
+
+        sizes(GetSize::get_heap_size, GetSize::get_heap_size);
+        assert_eq!(GermanStr::new("a string longer than 12 bytes").unwrap().get_size(), 16 + 29);
+    }
+
+    #[cfg(feature = "deepsize")]
+    #[test]
+    fn deepsize() {
+        use deepsize::DeepSizeOf;
+
+        sizes(|s| s.deep_size_of() - 16, |v| v.deep_size_of() - std::mem::size_of::<GermanStrVec>());
+    }
+}
+
+#[cfg(any(feature = "smol_str", feature = "compact_str", feature = "ecow", feature = "arcstr"))]
+mod string_crates_tests {
+    use super::*;
+
+    #[cfg(feature = "smol_str")]
+    #[test]
+    fn smol_str() {
+        use smol_str::SmolStr;
+
+        for src in ["short", "a string longer than 12 bytes"] {
+            let german = GermanStr::new(src).unwrap();
+            let other = SmolStr::from(&german);
+            assert_eq!(other.as_str(), src);
+            assert_eq!(GermanStr::try_from(&other).unwrap(), german);
+            assert_eq!(GermanStr::try_from(SmolStr::from(german.clone())).unwrap(), german);
+        }
+    }
+
+    #[cfg(feature = "compact_str")]
+    #[test]
+    fn compact_str() {
+        use compact_str::CompactString;
+
+        for src in ["short", "a string longer than 12 bytes"] {
+            let german = GermanStr::new(src).unwrap();
+            let other = CompactString::from(&german);
+            assert_eq!(other.as_str(), src);
+            assert_eq!(GermanStr::try_from(&other).unwrap(), german);
+            assert_eq!(GermanStr::try_from(CompactString::from(german.clone())).unwrap(), german);
+        }
+    }
+
+    #[cfg(feature = "ecow")]
+    #[test]
+    fn ecow() {
+        use ecow::EcoString;
+
+        for src in ["short", "a string longer than 12 bytes"] {
+            let german = GermanStr::new(src).unwrap();
+            let other = EcoString::from(&german);
+            assert_eq!(other.as_str(), src);
+            assert_eq!(GermanStr::try_from(&other).unwrap(), german);
+            assert_eq!(GermanStr::try_from(EcoString::from(german.clone())).unwrap(), german);
+        }
+    }
+
+    #[cfg(feature = "arcstr")]
+    #[test]
+    fn arcstr() {
+        use arcstr::ArcStr;
+
+        for src in ["short", "a string longer than 12 bytes"] {
+            let german = GermanStr::new(src).unwrap();
+            let other = ArcStr::from(&german);
+            assert_eq!(other.as_str(), src);
+            assert_eq!(GermanStr::try_from(&other).unwrap(), german);
+            assert_eq!(GermanStr::try_from(ArcStr::from(german.clone())).unwrap(), german);
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+mod std_tests {
+    use std::collections::HashSet;
+    use std::ffi::{OsStr, OsString};
+    use std::path::{Path, PathBuf};
+
+    use german_str::{GermanOsStr, GermanPath, GermanStrCell, GermanStrLines, OnceGermanStr};
+
+    use super::*;
+
+    #[test]
+    fn os_str() {
+        let short = GermanOsStr::new("short").unwrap();
+        assert!(short.is_inlined());
+        assert_eq!(short, OsStr::new("short"));
+        let long = GermanOsStr::new(OsString::from("a long enough os string")).unwrap();
+        assert!(long.is_heap_allocated());
+        assert_eq!(long.as_os_str(), "a long enough os string");
+        assert!(long < short);
+        assert_eq!(long.as_german_str().unwrap(), "a long enough os string");
+
+        let german = GermanStr::new("a long enough os string").unwrap();
+        assert_eq!(GermanOsStr::from(german.clone()), long);
+        assert_eq!(GermanStr::try_from(long.clone()).unwrap(), german);
+        assert_eq!(OsString::from(long.clone()), "a long enough os string");
+
+        let set: HashSet<GermanOsStr> = [short.clone(), long.clone()].into();
+        assert!(set.contains(OsStr::new("short")));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn os_str_not_utf8() {
+        use std::os::unix::ffi::OsStrExt;
+
+        for bytes in [&b"\xff"[..], b"not utf-8 \xff\xfe"] {
+            let os_str = OsStr::from_bytes(bytes);
+            let german = GermanOsStr::new(os_str).unwrap();
+            assert_eq!(german.as_bytes(), bytes);
+            assert!(german.as_german_str().is_none());
+            assert!(GermanStr::try_from(german.clone()).is_err());
+            assert_eq!(format!("{german:?}"), format!("{os_str:?}"));
+        }
+    }
+
+    #[test]
+    fn german_str_impls() {
+        let dir = std::env::temp_dir().join("german_str_std_impls_test");
+        let german = GermanStr::new(dir.to_str().unwrap()).unwrap();
+        std::fs::write(&german, "contents").unwrap();
+        assert_eq!(std::fs::read_to_string(&german).unwrap(), "contents");
+        std::fs::remove_file(&german).unwrap();
+
+        assert_eq!(PathBuf::from(german.clone()), dir);
+        assert_eq!(OsString::from(german.clone()), dir.as_os_str());
+        assert_eq!(german, *dir.as_os_str());
+        assert!(Path::new(&german).is_absolute());
+
+        let err: Box<dyn std::error::Error> = Box::new(InitError::TooLong);
+        assert!(err.source().is_none());
+    }
+
+    #[test]
+    fn path() {
+        let path = GermanPath::new("/usr/lib/libc.so").unwrap();
+        assert!(path.is_heap_allocated());
+        assert_eq!(path.file_name().unwrap(), "libc.so");
+        assert_eq!(path, Path::new("/usr//lib/libc.so"));
+        assert_ne!(path, GermanPath::new("/usr//lib/libc.so").unwrap());
+        assert_eq!(PathBuf::from(path.clone()), PathBuf::from("/usr/lib/libc.so"));
+        assert!(GermanPath::new("etc").unwrap().is_inlined());
+        assert_eq!(GermanPath::from(GermanStr::new("etc").unwrap()), Path::new("etc"));
+    }
+
+    #[test]
+    fn german_str_cell() {
+        use std::sync::Arc;
+
+        let cell = GermanStrCell::new(GermanStr::new("first value, long enough to be allocated").unwrap());
+        let first = cell.load();
+        let previous = cell.swap(GermanStr::new("second").unwrap());
+        assert!(Arc::ptr_eq(&first, &previous));
+        assert_eq!(*first, "first value, long enough to be allocated");
+        assert_eq!(format!("{cell:?}"), "\"second\"");
+
+        std::thread::scope(|s| {
+            for i in 0..4 {
+                let cell = &cell;
+                s.spawn(move || cell.store(GermanStr::from(i)));
+                s.spawn(|| assert!(cell.load().len() <= 6));
+            }
+        });
+        assert!(cell.into_inner().parse::<u8>().unwrap() < 4);
+        assert_eq!(*GermanStrCell::default().load(), "");
+    }
+
+    #[test]
+    fn once_german_str() {
+        static VALUE: OnceGermanStr = OnceGermanStr::new();
+        assert!(VALUE.get().is_none());
+        assert_eq!(format!("{VALUE:?}"), "OnceGermanStr(<uninit>)");
+        std::thread::scope(|s| {
+            for _ in 0..4 {
+                s.spawn(|| VALUE.get_or_init(|| GermanStr::new("initialized once, not inlined").unwrap()));
+            }
+        });
+        assert_eq!(VALUE.get().unwrap(), "initialized once, not inlined");
+        assert_eq!(VALUE.set(GermanStr::new("other").unwrap()), Err(GermanStr::new("other").unwrap()));
+        assert_eq!(format!("{VALUE:?}"), "OnceGermanStr(\"initialized once, not inlined\")");
+
+        let local = OnceGermanStr::from(GermanStr::new("local").unwrap());
+        assert_eq!(local.into_inner().unwrap(), "local");
+    }
+
+    #[test]
+    fn lines_read_file() {
+        let path = std::env::temp_dir().join("german_str_read_file.txt");
+        std::fs::write(&path, "short\r\nthis is obviously longer than 12 bytes\n").unwrap();
+        let lines = GermanStrLines::read_file(&path).unwrap();
+        assert_eq!(lines.as_slice(), ["short", "this is obviously longer than 12 bytes"]);
+        std::fs::write(&path, b"valid\n\xff").unwrap();
+        let err = GermanStrLines::read_file(&path).unwrap_err();
+        assert_eq!(err.kind(), std::io::ErrorKind::InvalidData);
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn foreign_buffer() {
+        use std::ptr::NonNull;
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        static RELEASED: AtomicUsize = AtomicUsize::new(0);
+        unsafe fn release(ptr: NonNull<u8>, len: usize) {
+            drop(unsafe { Box::from_raw(std::ptr::slice_from_raw_parts_mut(ptr.as_ptr(), len)) });
+            RELEASED.fetch_add(1, Ordering::Relaxed);
+        }
+        let foreign = |s: &str| NonNull::from(Box::leak(Box::<[u8]>::from(s.as_bytes()))).cast::<u8>();
+
+        let short = unsafe { GermanStr::from_foreign_buffer(foreign("short"), 5, release) }.unwrap();
+        assert!(short.is_inlined() && !short.has_foreign_buffer());
+        assert_eq!(RELEASED.load(Ordering::Relaxed), 1);
+
+        let src = "a string long enough to be allocated";
+        let ptr = foreign(src);
+        let mut long = unsafe { GermanStr::from_foreign_buffer(ptr, src.len(), release) }.unwrap();
+        assert_eq!(long, src);
+        assert_eq!(long.heap_ptr(), Some(ptr));
+        assert!(long.has_foreign_buffer() && !long.has_shared_buffer() && long.is_ascii());
+        let clone = long.clone();
+        assert!(!clone.has_foreign_buffer());
+        long.with_mut(str::make_ascii_uppercase).unwrap();
+        assert!(long.has_foreign_buffer());
+        assert_eq!(long, src.to_ascii_uppercase());
+        drop(clone);
+        drop(long);
+        assert_eq!(RELEASED.load(Ordering::Relaxed), 2);
+
+        // Shared clones release the buffer when freed.
+        let mut owner = unsafe { GermanStr::from_foreign_buffer(foreign(src), src.len(), release) }.unwrap();
+        let shared = owner.leaky_shared_clone();
+        assert!(shared.has_foreign_buffer());
+        drop(owner);
+        assert_eq!(RELEASED.load(Ordering::Relaxed), 2);
+        unsafe { shared.free() };
+        assert_eq!(RELEASED.load(Ordering::Relaxed), 3);
+
+        let invalid = NonNull::from(Box::leak(Box::<[u8]>::from(&b"invalid utf-8 \xff"[..]))).cast::<u8>();
+        let err = unsafe { GermanStr::from_foreign_buffer(invalid, 15, release) }.unwrap_err();
+        assert!(matches!(err, InitError::InvalidUtf8(_)));
+        assert_eq!(RELEASED.load(Ordering::Relaxed), 3);
+        unsafe { release(invalid, 15) };
+    }
 }